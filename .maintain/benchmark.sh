@@ -0,0 +1,26 @@
+#!/usr/bin/env bash
+# Regenerates the weights of the custom pallets on the machine it runs on. Run it on reference
+# hardware from the repository root.
+set -euo pipefail
+
+cargo build --release --package parachain-template-node --features runtime-benchmarks
+
+while read -r pallet dir; do
+	./target/release/parachain-template-node benchmark pallet \
+		--chain dev \
+		--execution wasm \
+		--wasm-execution compiled \
+		--pallet "$pallet" \
+		--extrinsic '*' \
+		--steps 50 \
+		--repeat 20 \
+		--template .maintain/frame-weight-template.hbs \
+		--output "pallets/$dir/src/weights.rs"
+done <<PALLETS
+participant_handler participant-handler
+donation_handler donation-handler
+marketplace marketplace
+pallet_amm swap
+router router
+xcm_helper xcm-helper
+PALLETS
@@ -1,10 +1,13 @@
 use cumulus_primitives_core::ParaId;
-use parachain_template_runtime::{AccountId, AuraId, Signature, EXISTENTIAL_DEPOSIT};
+use parachain_template_runtime::{
+	AccountId, AuraId, Balance, DonationPalletId, Hash, Signature, EXISTENTIAL_DEPOSIT, UNIT,
+};
+use participant_handler::{Category, NgoInfo, SellerInfo};
 use sc_chain_spec::{ChainSpecExtension, ChainSpecGroup};
 use sc_service::ChainType;
 use serde::{Deserialize, Serialize};
 use sp_core::{sr25519, Pair, Public};
-use sp_runtime::traits::{IdentifyAccount, Verify};
+use sp_runtime::traits::{AccountIdConversion, IdentifyAccount, Verify};
 
 /// Specialized `ChainSpec` for the normal parachain runtime.
 pub type ChainSpec =
@@ -54,6 +57,52 @@ where
 	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
 }
 
+/// Participants, products and donors a chain starts with.
+#[derive(Default)]
+pub struct MarketplacePreset {
+	/// Ngos approved at genesis.
+	pub ngos: Vec<(AccountId, NgoInfo<Hash>)>,
+	/// Sellers approved at genesis.
+	pub sellers: Vec<(AccountId, SellerInfo<Hash>)>,
+	/// Products listed at genesis as `(seller, category, price, quantity, cid)`.
+	pub products: Vec<(AccountId, Category, u128, u32, Hash)>,
+	/// Donors endowed with native token at genesis.
+	pub donors: Vec<(AccountId, Balance)>,
+}
+
+/// Preset for development chains: Charlie is an approved Ngo, Dave an approved Seller with a
+/// few listed products, and Eve and Ferdie are funded donors.
+pub fn dev_marketplace_preset() -> MarketplacePreset {
+	let ngo = get_account_id_from_seed::<sr25519::Public>("Charlie");
+	let seller = get_account_id_from_seed::<sr25519::Public>("Dave");
+	let ngo_info = NgoInfo {
+		categories: Category::all()
+			.to_vec()
+			.try_into()
+			.expect("Number of categories is within bounds; qed"),
+		cid: Hash::repeat_byte(1),
+	};
+	let seller_info = SellerInfo {
+		categories: vec![Category::Pharmaceutical, Category::Grocery]
+			.try_into()
+			.expect("Number of categories is within bounds; qed"),
+		cid: Hash::repeat_byte(2),
+	};
+	MarketplacePreset {
+		ngos: vec![(ngo, ngo_info)],
+		sellers: vec![(seller.clone(), seller_info)],
+		products: vec![
+			(seller.clone(), Category::Pharmaceutical, 10 * UNIT, 500, Hash::repeat_byte(10)),
+			(seller.clone(), Category::Pharmaceutical, 25 * UNIT, 100, Hash::repeat_byte(11)),
+			(seller, Category::Pharmaceutical, 40 * UNIT, 20, Hash::repeat_byte(12)),
+		],
+		donors: vec![
+			(get_account_id_from_seed::<sr25519::Public>("Eve"), 1_000_000 * UNIT),
+			(get_account_id_from_seed::<sr25519::Public>("Ferdie"), 1_000_000 * UNIT),
+		],
+	}
+}
+
 /// Generate the session keys from individual elements.
 ///
 /// The input must be a tuple of individual keys (a single arg for now since we have just one key).
@@ -102,6 +151,7 @@ pub fn development_config() -> ChainSpec {
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
 				1000.into(),
+				dev_marketplace_preset(),
 			)
 		},
 		Vec::new(),
@@ -157,6 +207,7 @@ pub fn local_testnet_config() -> ChainSpec {
 					get_account_id_from_seed::<sr25519::Public>("Ferdie//stash"),
 				],
 				1000.into(),
+				MarketplacePreset::default(),
 			)
 		},
 		// Bootnodes
@@ -181,16 +232,38 @@ fn testnet_genesis(
 	invulnerables: Vec<(AccountId, AuraId)>,
 	endowed_accounts: Vec<AccountId>,
 	id: ParaId,
+	preset: MarketplacePreset,
 ) -> parachain_template_runtime::GenesisConfig {
+	let mut balances: Vec<(AccountId, Balance)> =
+		endowed_accounts.iter().cloned().map(|k| (k, 1 << 60)).collect();
+	for (donor, amount) in preset.donors {
+		match balances.iter_mut().find(|(account, _)| *account == donor) {
+			Some((_, balance)) => *balance = balance.saturating_add(amount),
+			None => balances.push((donor, amount)),
+		}
+	}
+	// Every category is backed by a sufficient Purpose Based Token owned by the donation pallet.
+	let donation_account: AccountId = DonationPalletId::get().into_account_truncating();
+	let pbt_assets = Category::all()
+		.iter()
+		.map(|category| (category.get_id() as u128, donation_account.clone(), true, 1))
+		.collect();
+	let pbt_metadata = Category::all()
+		.iter()
+		.map(|category| {
+			let name = format!("{:?} Purpose Based Token", category).into_bytes();
+			let symbol = format!("PBT{}", category.get_id()).into_bytes();
+			(category.get_id() as u128, name, symbol, 12)
+		})
+		.collect();
+
 	parachain_template_runtime::GenesisConfig {
 		system: parachain_template_runtime::SystemConfig {
 			code: parachain_template_runtime::WASM_BINARY
 				.expect("WASM binary was not build, please build it!")
 				.to_vec(),
 		},
-		balances: parachain_template_runtime::BalancesConfig {
-			balances: endowed_accounts.iter().cloned().map(|k| (k, 1 << 60)).collect(),
-		},
+		balances: parachain_template_runtime::BalancesConfig { balances },
 		parachain_info: parachain_template_runtime::ParachainInfoConfig { parachain_id: id },
 		collator_selection: parachain_template_runtime::CollatorSelectionConfig {
 			invulnerables: invulnerables.iter().cloned().map(|(acc, _)| acc).collect(),
@@ -215,7 +288,18 @@ fn testnet_genesis(
 		aura_ext: Default::default(),
 		parachain_system: Default::default(),
 		assets: Default::default(),
-		purpose_based_tokens: Default::default(),
+		arbitration_council: Default::default(),
+		treasury: Default::default(),
+		purpose_based_tokens: parachain_template_runtime::PurposeBasedTokensConfig {
+			assets: pbt_assets,
+			metadata: pbt_metadata,
+			accounts: vec![],
+		},
+		participant_handler: parachain_template_runtime::ParticipantHandlerConfig {
+			ngos: preset.ngos,
+			sellers: preset.sellers,
+		},
+		marketplace: parachain_template_runtime::MarketplaceConfig { products: preset.products },
 		polkadot_xcm: parachain_template_runtime::PolkadotXcmConfig {
 			safe_xcm_version: Some(SAFE_XCM_VERSION),
 		},
@@ -7,7 +7,9 @@
 
 use std::sync::Arc;
 
-use parachain_template_runtime::{opaque::Block, AccountId, Balance, Index as Nonce};
+use parachain_template_runtime::{
+	opaque::Block, AccountId, Balance, BlockNumber, Hash, Index as Nonce,
+};
 
 use sc_client_api::AuxStore;
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
@@ -43,16 +45,25 @@ where
 		+ 'static,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	C::Api: marketplace_rpc::MarketplaceRuntimeApi<Block, AccountId, Hash, BlockNumber>,
+	C::Api: participant_handler_rpc::ParticipantRuntimeApi<Block, AccountId, Hash>,
+	C::Api: donation_handler_rpc::DonationRuntimeApi<Block, AccountId, Hash, BlockNumber>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use donation_handler_rpc::{DonationApiServer, Donations};
+	use marketplace_rpc::{Marketplace, MarketplaceApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use participant_handler_rpc::{ParticipantApiServer, Participants};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcExtension::new(());
 	let FullDeps { client, pool, deny_unsafe } = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Marketplace::new(client.clone()).into_rpc())?;
+	module.merge(Participants::new(client.clone()).into_rpc())?;
+	module.merge(Donations::new(client).into_rpc())?;
 	Ok(module)
 }
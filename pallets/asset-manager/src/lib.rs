@@ -56,6 +56,8 @@ pub mod pallet {
 		CannotMintNativeAsset,
 		/// Cannot Burn Native Asset
 		CannotBurnNativeAsset,
+		/// Cannot Create Native Asset
+		CannotCreateNativeAsset,
 	}
 
 	impl<T: Config> Inspect<T::AccountId> for Pallet<T> {
@@ -168,6 +170,21 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> Create<T::AccountId> for Pallet<T> {
+		fn create(
+			id: Self::AssetId,
+			admin: T::AccountId,
+			is_sufficient: bool,
+			min_balance: Self::Balance,
+		) -> DispatchResult {
+			return if id != T::NativeCurrencyId::get() {
+				T::MultiCurrency::create(id, admin, is_sufficient, min_balance.saturated_into())
+			} else {
+				fail!(Error::<T>::CannotCreateNativeAsset)
+			}
+		}
+	}
+
 	impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
 		fn mint_into(
 			asset: Self::AssetId,
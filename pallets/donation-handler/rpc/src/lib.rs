@@ -0,0 +1,226 @@
+//! RPC interface for the donation-handler pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use donation_handler::{DonationId, DonationRecord, NgoCategoryStats, SellerCategoryStats};
+pub use donation_handler_runtime_api::DonationApi as DonationRuntimeApi;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use participant_handler::Category;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+pub use support_rpc::Page;
+use support_rpc::{block_at, page_size, runtime_error};
+
+/// Donation made to an Ngo.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Donation<AccountId, Hash, BlockNumber> {
+	pub donation_id: DonationId,
+	pub donor: AccountId,
+	pub recipient: AccountId,
+	pub category: Category,
+	pub amount: NumberOrHex,
+	pub memo: Option<Bytes>,
+	/// Content Id of off-chain donation metadata on IPFS.
+	pub metadata: Option<Hash>,
+	pub block: BlockNumber,
+}
+
+impl<AccountId, Hash, BlockNumber> From<(DonationId, DonationRecord<AccountId, Hash, BlockNumber>)>
+	for Donation<AccountId, Hash, BlockNumber>
+{
+	fn from(
+		(donation_id, record): (DonationId, DonationRecord<AccountId, Hash, BlockNumber>),
+	) -> Self {
+		Self {
+			donation_id,
+			donor: record.donor,
+			recipient: record.recipient,
+			category: record.category,
+			amount: record.amount.into(),
+			memo: record.memo.map(|memo| memo.into_inner().into()),
+			metadata: record.metadata,
+			block: record.block,
+		}
+	}
+}
+
+/// PBT received and spent by an Ngo in a category.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NgoStats {
+	pub received: NumberOrHex,
+	pub spent: NumberOrHex,
+}
+
+impl From<NgoCategoryStats> for NgoStats {
+	fn from(stats: NgoCategoryStats) -> Self {
+		Self { received: stats.received.into(), spent: stats.spent.into() }
+	}
+}
+
+/// PBT sold and claimed by a seller in a category.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SellerStats {
+	pub sold: NumberOrHex,
+	pub claimed: NumberOrHex,
+}
+
+impl From<SellerCategoryStats> for SellerStats {
+	fn from(stats: SellerCategoryStats) -> Self {
+		Self { sold: stats.sold.into(), claimed: stats.claimed.into() }
+	}
+}
+
+#[rpc(client, server)]
+pub trait DonationApi<BlockHash, AccountId, Hash, BlockNumber> {
+	/// Returns the donation `donation_id`.
+	#[method(name = "donations_donation")]
+	fn donation(
+		&self,
+		donation_id: DonationId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Donation<AccountId, Hash, BlockNumber>>>;
+
+	/// Returns a page of donations, starting from the donation `from`.
+	#[method(name = "donations_donations")]
+	fn donations(
+		&self,
+		from: Option<DonationId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Donation<AccountId, Hash, BlockNumber>, DonationId>>;
+
+	/// Returns the PBT received and spent by `ngo` in `category`.
+	#[method(name = "donations_ngoStats")]
+	fn ngo_stats(
+		&self,
+		ngo: AccountId,
+		category: Category,
+		at: Option<BlockHash>,
+	) -> RpcResult<NgoStats>;
+
+	/// Returns the number of distinct donors of `ngo`.
+	#[method(name = "donations_ngoDonorCount")]
+	fn ngo_donor_count(&self, ngo: AccountId, at: Option<BlockHash>) -> RpcResult<u32>;
+
+	/// Returns the total amount donated by `donor`.
+	#[method(name = "donations_donorTotal")]
+	fn donor_total(&self, donor: AccountId, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+
+	/// Returns the PBT sold and claimed by `seller` in `category`.
+	#[method(name = "donations_sellerStats")]
+	fn seller_stats(
+		&self,
+		seller: AccountId,
+		category: Category,
+		at: Option<BlockHash>,
+	) -> RpcResult<SellerStats>;
+}
+
+/// Donation RPC methods, backed by the `DonationApi` runtime API.
+pub struct Donations<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Donations<C, Block> {
+	/// Create new `Donations` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Hash, BlockNumber>
+	DonationApiServer<<Block as BlockT>::Hash, AccountId, Hash, BlockNumber> for Donations<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: DonationRuntimeApi<Block, AccountId, Hash, BlockNumber>,
+	AccountId: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	Hash: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	BlockNumber: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+{
+	fn donation(
+		&self,
+		donation_id: DonationId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Donation<AccountId, Hash, BlockNumber>>> {
+		let record = self
+			.client
+			.runtime_api()
+			.donation(&block_at(&*self.client, at), donation_id)
+			.map_err(runtime_error)?;
+		Ok(record.map(|record| (donation_id, record).into()))
+	}
+
+	fn donations(
+		&self,
+		from: Option<DonationId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Donation<AccountId, Hash, BlockNumber>, DonationId>> {
+		let limit = page_size(limit);
+		let records = self
+			.client
+			.runtime_api()
+			.donations(&block_at(&*self.client, at), from.unwrap_or_default(), limit)
+			.map_err(runtime_error)?;
+		let next = match records.last() {
+			Some((donation_id, _)) if records.len() as u32 == limit =>
+				Some(donation_id.saturating_add(1)),
+			_ => None,
+		};
+		Ok(Page { items: records.into_iter().map(Into::into).collect(), next })
+	}
+
+	fn ngo_stats(
+		&self,
+		ngo: AccountId,
+		category: Category,
+		at: Option<Block::Hash>,
+	) -> RpcResult<NgoStats> {
+		let stats = self
+			.client
+			.runtime_api()
+			.ngo_stats(&block_at(&*self.client, at), ngo, category)
+			.map_err(runtime_error)?;
+		Ok(stats.into())
+	}
+
+	fn ngo_donor_count(&self, ngo: AccountId, at: Option<Block::Hash>) -> RpcResult<u32> {
+		self.client
+			.runtime_api()
+			.ngo_donor_count(&block_at(&*self.client, at), ngo)
+			.map_err(runtime_error)
+	}
+
+	fn donor_total(&self, donor: AccountId, at: Option<Block::Hash>) -> RpcResult<NumberOrHex> {
+		let total = self
+			.client
+			.runtime_api()
+			.donor_total(&block_at(&*self.client, at), donor)
+			.map_err(runtime_error)?;
+		Ok(total.into())
+	}
+
+	fn seller_stats(
+		&self,
+		seller: AccountId,
+		category: Category,
+		at: Option<Block::Hash>,
+	) -> RpcResult<SellerStats> {
+		let stats = self
+			.client
+			.runtime_api()
+			.seller_stats(&block_at(&*self.client, at), seller, category)
+			.map_err(runtime_error)?;
+		Ok(stats.into())
+	}
+}
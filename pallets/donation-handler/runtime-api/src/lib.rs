@@ -0,0 +1,36 @@
+//! Runtime API definition for the donation-handler pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use donation_handler::{DonationId, DonationRecord, NgoCategoryStats, SellerCategoryStats};
+use participant_handler::Category;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait DonationApi<AccountId, Hash, BlockNumber>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns the donation record stored under `donation_id`.
+		fn donation(donation_id: DonationId) -> Option<DonationRecord<AccountId, Hash, BlockNumber>>;
+
+		/// Returns up to `limit` donation records starting from `from` (inclusive).
+		fn donations(from: DonationId, limit: u32) -> Vec<(DonationId, DonationRecord<AccountId, Hash, BlockNumber>)>;
+
+		/// Returns the amounts received and spent by `ngo` in `category`.
+		fn ngo_stats(ngo: AccountId, category: Category) -> NgoCategoryStats;
+
+		/// Returns the number of distinct donors who have donated to `ngo`.
+		fn ngo_donor_count(ngo: AccountId) -> u32;
+
+		/// Returns the total amount donated by `donor`.
+		fn donor_total(donor: AccountId) -> u128;
+
+		/// Returns the amounts sold and claimed by `seller` in `category`.
+		fn seller_stats(seller: AccountId, category: Category) -> SellerCategoryStats;
+	}
+}
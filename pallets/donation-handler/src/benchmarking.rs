@@ -1,36 +1,152 @@
+//! Benchmarking setup for donation-handler
+
 use super::*;
 
 #[allow(unused)]
 use crate::Pallet as DonationHandler;
-use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
 use frame_support::{
-	sp_runtime::{traits::Hash, SaturatedConversion},
-	traits::{fungibles::Mutate, Currency},
+	sp_runtime::traits::{AccountIdConversion, Bounded, Hash, Zero},
+	traits::{
+		fungibles::{Create, Inspect, Mutate},
+		Currency, EnsureOrigin,
+	},
 };
 use frame_system::RawOrigin;
-use participant_handler::{Category, NgoInfo};
+use participant_handler::{Category, NgoInfo, SellerInfo};
+
 const SEED: u32 = 0;
+const AMOUNT: u128 = 1_000_000_000_000;
+
+type CurrencyBalanceOf<T> = <<T as pallet::Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+
+fn pallet_account<T: Config>() -> T::AccountId {
+	T::DonationPalletId::get().into_account_truncating()
+}
+
+fn funded<T: Config>(who: &T::AccountId) {
+	<T as pallet::Config>::Currency::make_free_balance_be(
+		who,
+		CurrencyBalanceOf::<T>::max_value() / 2u32.into(),
+	);
+}
+
+/// Creates the PBT of `category` the way the chain spec does, unless it already exists.
+fn ensure_pbt<T: Config>(category: Category) {
+	let id = category.get_id() as u128;
+	if T::TokenHandler::minimum_balance(id).is_zero() {
+		T::TokenHandler::create(id, pallet_account::<T>(), true, 1).unwrap();
+	}
+}
 
-//TODO: Generate weights and replace default weights
+fn active_ngo<T: Config>(name: &'static str) -> T::AccountId {
+	let ngo: T::AccountId = account(name, 0, SEED);
+	let ngo_info = NgoInfo {
+		categories: Category::all().to_vec().try_into().unwrap(),
+		cid: T::Hashing::hash_of(&ngo),
+	};
+	participant_handler::pallet::Pallet::<T>::add_ngo_to_active_list(&ngo, ngo_info);
+	ngo
+}
+
+fn active_seller<T: Config>() -> T::AccountId {
+	let seller: T::AccountId = account("seller", 0, SEED);
+	let seller_info = SellerInfo {
+		categories: Category::all().to_vec().try_into().unwrap(),
+		cid: T::Hashing::hash_of(&seller),
+	};
+	participant_handler::pallet::Pallet::<T>::add_seller_to_active_list(&seller, seller_info);
+	funded::<T>(&seller);
+	seller
+}
+
+/// Seller holding `AMOUNT` PBT of `category`.
+fn seller_with_pbt<T: Config>(category: Category) -> Result<T::AccountId, BenchmarkError> {
+	let seller = active_seller::<T>();
+	ensure_pbt::<T>(category);
+	T::TokenHandler::mint_into(category.get_id() as u128, &seller, AMOUNT)?;
+	Ok(seller)
+}
 
 benchmarks! {
+	// Worst case: the donation exceeds the recipient's cap and is redirected to the Overflow Ngo.
 	donate {
-		let b in 1 .. 1000;
-		let donor: T::AccountId = account("ngo", b, SEED);
-		let ngo: T::AccountId = account("recipient", b, SEED);
-		<T as pallet::Config>::Currency::deposit_into_existing(&donor, 1_000_000_000_000_u128.saturated_into());
-		let ngo_info = NgoInfo {
-		categories: Default::default(),
-		cid: T::Hashing::hash_of(&b)
-		};
-		participant_handler::pallet::Pallet::<T>::add_ngo_to_active_list(&ngo, ngo_info);
-	}: _(RawOrigin::Signed(donor), ngo, Category::Pharmaceutical, 1_000_000_000)
+		let category = Category::Pharmaceutical;
+		ensure_pbt::<T>(category);
+		let donor: T::AccountId = account("donor", 0, SEED);
+		funded::<T>(&donor);
+		let recipient = active_ngo::<T>("recipient");
+		let overflow = active_ngo::<T>("overflow");
+		<OverflowNgo<T>>::put(&overflow);
+		for (ngo, cap) in [(&recipient, AMOUNT - 1), (&overflow, AMOUNT)] {
+			<FundingTargets<T>>::insert(ngo, category, u128::MAX);
+			<DonationCaps<T>>::insert(ngo, category, cap);
+		}
+		let memo: Memo = sp_std::vec![0u8; MAX_MEMO_LENGTH as usize].try_into().unwrap();
+		let metadata = T::Hashing::hash_of(&donor);
+	}: _(RawOrigin::Signed(donor), recipient, category, AMOUNT, Some(memo), Some(metadata))
+	verify {
+		assert_eq!(<NgoStats<T>>::get(&overflow, category).received, AMOUNT);
+	}
 
 	claim_token {
-		let b in 1 .. 1000;
-		let seller: T::AccountId = account("seller", b, SEED);
 		let category = Category::Pharmaceutical;
-		<T as pallet::Config>::Currency::deposit_into_existing(&seller, 1_000_000_000_000_u128.saturated_into());
-		<T as pallet::Config>::TokenHandler::mint_into(category.get_id() as u128, &seller, 1_000_u128.saturated_into());
-	}: _(RawOrigin::Signed(seller), Category::Pharmaceutical, 100)
+		let seller = seller_with_pbt::<T>(category)?;
+		let payout_id = <NextPayoutId<T>>::get();
+	}: _(RawOrigin::Signed(seller), category, AMOUNT)
+	verify {
+		assert!(<PendingPayouts<T>>::contains_key(payout_id));
+	}
+
+	withdraw_payout {
+		let category = Category::Pharmaceutical;
+		let seller = seller_with_pbt::<T>(category)?;
+		funded::<T>(&pallet_account::<T>());
+		let (payout_id, unlock_at) = DonationHandler::<T>::do_claim(&seller, &category, AMOUNT)?;
+		frame_system::Pallet::<T>::set_block_number(unlock_at);
+	}: _(RawOrigin::Signed(seller), payout_id)
+	verify {
+		assert!(!<PendingPayouts<T>>::contains_key(payout_id));
+	}
+
+	cancel_payout {
+		let category = Category::Pharmaceutical;
+		let seller = seller_with_pbt::<T>(category)?;
+		let (payout_id, _) = DonationHandler::<T>::do_claim(&seller, &category, AMOUNT)?;
+		let refund_to: T::AccountId = account("ngo", 0, SEED);
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, payout_id, refund_to)
+	verify {
+		assert!(!<PendingPayouts<T>>::contains_key(payout_id));
+	}
+
+	set_donation_cap {
+		let ngo = active_ngo::<T>("ngo");
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, ngo.clone(), Category::Pharmaceutical, Some(AMOUNT))
+	verify {
+		assert_eq!(<DonationCaps<T>>::get(&ngo, Category::Pharmaceutical), Some(AMOUNT));
+	}
+
+	set_funding_target {
+		let ngo = active_ngo::<T>("ngo");
+	}: _(RawOrigin::Signed(ngo.clone()), Category::Pharmaceutical, Some(AMOUNT))
+	verify {
+		assert_eq!(<FundingTargets<T>>::get(&ngo, Category::Pharmaceutical), Some(AMOUNT));
+	}
+
+	set_overflow_ngo {
+		let ngo = active_ngo::<T>("ngo");
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, Some(ngo.clone()))
+	verify {
+		assert_eq!(<OverflowNgo<T>>::get(), Some(ngo));
+	}
+
+	impl_benchmark_test_suite!(DonationHandler, crate::mock::new_test_ext(), crate::mock::Test);
 }
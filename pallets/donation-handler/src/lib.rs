@@ -10,12 +10,18 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
 		pallet_prelude::*,
-		sp_runtime::{traits::AccountIdConversion, SaturatedConversion},
+		sp_runtime::{
+			traits::{AccountIdConversion, CheckedDiv, Saturating, Zero},
+			SaturatedConversion,
+		},
 		traits::{
 			fungibles::{Create, Inspect, Mutate, Transfer},
 			Currency, ExistenceRequirement,
@@ -23,10 +29,76 @@ pub mod pallet {
 		transactional, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
+	use crate::WeightInfo;
 	use participant_handler::Category;
+	use sp_std::vec::Vec;
+
+	/// Identifier of a donation.
+	pub type DonationId = u64;
+
+	/// Maximum length of a donation memo, in bytes.
+	pub const MAX_MEMO_LENGTH: u32 = 128;
+
+	/// Short free-form dedication attached to a donation.
+	pub type Memo = BoundedVec<u8, ConstU32<MAX_MEMO_LENGTH>>;
+
+	#[derive(Encode, Decode, Clone, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct DonationRecord<AccountId, Hash, BlockNumber> {
+		pub donor: AccountId,
+		pub recipient: AccountId,
+		pub category: Category,
+		pub amount: u128,
+		/// Dedication or campaign reference supplied by the donor.
+		pub memo: Option<Memo>,
+		/// Content Id of off-chain donation metadata (e.g. a pledge document) on IPFS.
+		pub metadata: Option<Hash>,
+		/// Block in which the donation was made.
+		pub block: BlockNumber,
+	}
+
+	#[derive(Encode, Decode, Clone, Debug, Default, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct NgoCategoryStats {
+		/// Total PBT received through donations.
+		pub received: u128,
+		/// Total PBT spent on purchases.
+		pub spent: u128,
+	}
+
+	#[derive(Encode, Decode, Clone, Debug, Default, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct SellerCategoryStats {
+		/// Total PBT received from sales.
+		pub sold: u128,
+		/// Total PBT claimed for Native Token.
+		pub claimed: u128,
+	}
+
+	#[derive(Encode, Decode, Clone, Debug, Default, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct PeriodUsage<BlockNumber> {
+		/// Index of the cap period, i.e. block number divided by the cap period length.
+		pub period: BlockNumber,
+		/// PBT received during `period`.
+		pub received: u128,
+	}
+
+	/// Identifier of a pending seller payout.
+	pub type PayoutId = u64;
+
+	#[derive(Encode, Decode, Clone, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct PendingPayout<AccountId, BlockNumber> {
+		pub seller: AccountId,
+		pub category: Category,
+		/// PBT held back by the pallet until the payout unlocks.
+		pub amount: u128,
+		/// Block from which the seller can withdraw the payout.
+		pub unlock_at: BlockNumber,
+	}
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -42,22 +114,217 @@ pub mod pallet {
 		/// Donation Handler Pallet Id
 		#[pallet::constant]
 		type DonationPalletId: Get<PalletId>;
+		/// Length of the period over which donation caps apply, in blocks
+		#[pallet::constant]
+		type CapPeriod: Get<Self::BlockNumber>;
+		/// Number of blocks seller proceeds are held back before they can be withdrawn
+		#[pallet::constant]
+		type ClaimHoldBackPeriod: Get<Self::BlockNumber>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
+	/// Donation Records
+	#[pallet::storage]
+	#[pallet::getter(fn get_donation)]
+	pub type Donations<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		DonationId,
+		DonationRecord<T::AccountId, T::Hash, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Identifier assigned to the next donation
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_donation_id)]
+	pub type NextDonationId<T: Config> = StorageValue<_, DonationId, ValueQuery>;
+
+	/// Donation and Purchase totals by Ngo and Category
+	#[pallet::storage]
+	#[pallet::getter(fn get_ngo_stats)]
+	pub type NgoStats<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Category,
+		NgoCategoryStats,
+		ValueQuery,
+	>;
+
+	/// Total amount donated by each Donor
+	#[pallet::storage]
+	#[pallet::getter(fn get_donor_total)]
+	pub type DonorTotals<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+
+	/// Donors who have donated to a Ngo
+	#[pallet::storage]
+	pub type NgoDonors<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Number of distinct Donors per Ngo
+	#[pallet::storage]
+	#[pallet::getter(fn get_ngo_donor_count)]
+	pub type NgoDonorCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Sale and Claim totals by Seller and Category
+	#[pallet::storage]
+	#[pallet::getter(fn get_seller_stats)]
+	pub type SellerStats<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Category,
+		SellerCategoryStats,
+		ValueQuery,
+	>;
+
+	/// Maximum PBT a Ngo can receive per Category per cap period
+	#[pallet::storage]
+	#[pallet::getter(fn get_donation_cap)]
+	pub type DonationCaps<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Category,
+		u128,
+		OptionQuery,
+	>;
+
+	/// PBT received by a Ngo per Category in the current cap period
+	#[pallet::storage]
+	#[pallet::getter(fn get_period_usage)]
+	pub type PeriodReceived<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Category,
+		PeriodUsage<T::BlockNumber>,
+		ValueQuery,
+	>;
+
+	/// Funding Target published by a Ngo per Category
+	#[pallet::storage]
+	#[pallet::getter(fn get_funding_target)]
+	pub type FundingTargets<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		Category,
+		u128,
+		OptionQuery,
+	>;
+
+	/// Ngo receiving donations redirected from Ngos over their cap
+	#[pallet::storage]
+	#[pallet::getter(fn get_overflow_ngo)]
+	pub type OverflowNgo<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// Seller Payouts waiting for their hold-back period to pass
+	#[pallet::storage]
+	#[pallet::getter(fn get_pending_payout)]
+	pub type PendingPayouts<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		PayoutId,
+		PendingPayout<T::AccountId, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Pending Payouts by Seller
+	#[pallet::storage]
+	pub type SellerPayouts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		PayoutId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Identifier assigned to the next payout
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_payout_id)]
+	pub type NextPayoutId<T: Config> = StorageValue<_, PayoutId, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Token Donated
-		/// parameters. [donor, recipient, amount, category]
+		/// parameters. [donation_id, donor, recipient, amount, category, memo, metadata]
 		TokenDonated {
+			donation_id: DonationId,
 			donor: T::AccountId,
 			recipient: T::AccountId,
 			amount: u128,
 			category: Category,
+			memo: Option<Memo>,
+			metadata: Option<T::Hash>,
+		},
+		/// Payout Scheduled
+		/// parameters. [payout_id, seller, category, amount, unlock_at]
+		PayoutScheduled {
+			payout_id: PayoutId,
+			seller: T::AccountId,
+			category: Category,
+			amount: u128,
+			unlock_at: T::BlockNumber,
+		},
+		/// Payout Cancelled
+		/// parameters. [payout_id, seller, refund_to, amount]
+		PayoutCancelled {
+			payout_id: PayoutId,
+			seller: T::AccountId,
+			refund_to: T::AccountId,
+			amount: u128,
+		},
+		/// Part of a Payout refunded
+		/// parameters. [payout_id, seller, refund_to, amount]
+		PayoutRefunded {
+			payout_id: PayoutId,
+			seller: T::AccountId,
+			refund_to: T::AccountId,
+			amount: u128,
 		},
 		/// Tokens Claimed
-		/// parameters. [seller, category, amount]
-		TokensClaimed { seller: T::AccountId, category: Category, amount: u128 },
+		/// parameters. [payout_id, seller, category, amount]
+		TokensClaimed {
+			payout_id: PayoutId,
+			seller: T::AccountId,
+			category: Category,
+			amount: u128,
+		},
+		/// Donation Redirected to Overflow Ngo
+		/// parameters. [donation_id, requested_recipient, recipient]
+		DonationRedirected {
+			donation_id: DonationId,
+			requested_recipient: T::AccountId,
+			recipient: T::AccountId,
+		},
+		/// Donation Cap Updated
+		/// parameters. [ngo, category, cap]
+		DonationCapUpdated { ngo: T::AccountId, category: Category, cap: Option<u128> },
+		/// Funding Target Updated
+		/// parameters. [ngo, category, target]
+		FundingTargetUpdated { ngo: T::AccountId, category: Category, target: Option<u128> },
+		/// Overflow Ngo Updated
+		/// parameters. [ngo]
+		OverflowNgoUpdated { ngo: Option<T::AccountId> },
 	}
 
 	// Errors inform users that something went wrong.
@@ -67,6 +334,16 @@ pub mod pallet {
 		RecipientNotValid,
 		/// Caller Not Valid
 		CallerNotValid,
+		/// Donation exceeds the Ngo's cap for the current period
+		DonationCapExceeded,
+		/// Ngo has reached its funding target for the Category
+		FundingTargetReached,
+		/// Payout Not Found
+		PayoutNotFound,
+		/// Payout is still in its hold-back period
+		PayoutStillLocked,
+		/// Seller has more pending Payouts than expected
+		TooManyPayouts,
 	}
 
 	#[pallet::call]
@@ -77,53 +354,203 @@ pub mod pallet {
 		/// * `recipient`: Recipient who will get SFT.
 		/// * `category`: Category.
 		/// * `amount`: Donation Amount.
+		/// * `memo`: Optional dedication or campaign reference.
+		/// * `metadata`: Optional Content Id of donation metadata on IPFS.
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(<T as Config>::WeightInfo::donate())]
 		pub fn donate(
 			origin: OriginFor<T>,
 			recipient: T::AccountId,
 			category: Category,
 			amount: u128,
+			memo: Option<Memo>,
+			metadata: Option<T::Hash>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::do_donate(&who, &recipient, amount, &category)?;
+			let beneficiary = Self::do_donate(&who, &recipient, amount, &category)?;
+			let donation_id =
+				Self::record_donation(&who, &beneficiary, category, amount, memo.clone(), metadata);
+			if beneficiary != recipient {
+				Self::deposit_event(Event::DonationRedirected {
+					donation_id,
+					requested_recipient: recipient,
+					recipient: beneficiary.clone(),
+				});
+			}
 			// Emit an event.
-			Self::deposit_event(Event::TokenDonated { donor: who, recipient, amount, category });
+			Self::deposit_event(Event::TokenDonated {
+				donation_id,
+				donor: who,
+				recipient: beneficiary,
+				amount,
+				category,
+				memo,
+				metadata,
+			});
 			Ok(())
 		}
 
-		/// Seller can claim Native Token by burning equal amount of SFT.
+		/// Seller can claim Native Token for an equal amount of SFT.
+		/// The SFT is held back by the pallet and a pending payout is created which can be
+		/// withdrawn once `ClaimHoldBackPeriod` has passed.
 		///
 		/// # Parameters
 		/// * `category`: variant of SFT
 		/// * `amount`: Expected Amount
 		#[pallet::call_index(1)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_token())]
 		pub fn claim_token(
 			origin: OriginFor<T>,
 			category: Category,
 			amount: u128,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::do_claim(&who, &category, amount)?;
-			Self::deposit_event(Event::TokensClaimed { seller: who, category, amount });
+			let (payout_id, unlock_at) = Self::do_claim(&who, &category, amount)?;
+			Self::deposit_event(Event::PayoutScheduled {
+				payout_id,
+				seller: who,
+				category,
+				amount,
+				unlock_at,
+			});
+			Ok(())
+		}
+
+		/// Seller can withdraw Native Token of an unlocked payout, burning the held SFT.
+		///
+		/// # Parameters
+		/// * `payout_id`: Payout Id.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_payout())]
+		pub fn withdraw_payout(origin: OriginFor<T>, payout_id: PayoutId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let payout = Self::do_withdraw_payout(&who, payout_id)?;
+			Self::deposit_event(Event::TokensClaimed {
+				payout_id,
+				seller: who,
+				category: payout.category,
+				amount: payout.amount,
+			});
+			Ok(())
+		}
+
+		/// Cancel a pending payout and return the held SFT to `refund_to`.
+		/// Only Governance can call it.
+		///
+		/// # Parameters
+		/// * `payout_id`: Payout Id.
+		/// * `refund_to`: Account receiving the held SFT.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_payout())]
+		pub fn cancel_payout(
+			origin: OriginFor<T>,
+			payout_id: PayoutId,
+			refund_to: T::AccountId,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			Self::do_cancel_payout(payout_id, &refund_to)
+		}
+
+		/// Set the maximum amount a Ngo can receive in a Category per cap period.
+		/// Only Governance can call it.
+		///
+		/// # Parameters
+		/// * `ngo`: Ngo to cap.
+		/// * `category`: Category the cap applies to.
+		/// * `cap`: Maximum amount per period, `None` removes the cap.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_donation_cap())]
+		pub fn set_donation_cap(
+			origin: OriginFor<T>,
+			ngo: T::AccountId,
+			category: Category,
+			cap: Option<u128>,
+		) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			match cap {
+				Some(cap) => <DonationCaps<T>>::insert(&ngo, category, cap),
+				None => <DonationCaps<T>>::remove(&ngo, category),
+			}
+			Self::deposit_event(Event::DonationCapUpdated { ngo, category, cap });
+			Ok(())
+		}
+
+		/// Ngo can publish a Funding Target after which it stops accepting donations.
+		///
+		/// # Parameters
+		/// * `category`: Category the target applies to.
+		/// * `target`: Total amount to raise, `None` removes the target.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_funding_target())]
+		pub fn set_funding_target(
+			origin: OriginFor<T>,
+			category: Category,
+			target: Option<u128>,
+		) -> DispatchResult {
+			let ngo = ensure_signed(origin)?;
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_active_ngo(&ngo),
+				Error::<T>::CallerNotValid
+			);
+			match target {
+				Some(target) => <FundingTargets<T>>::insert(&ngo, category, target),
+				None => <FundingTargets<T>>::remove(&ngo, category),
+			}
+			Self::deposit_event(Event::FundingTargetUpdated { ngo, category, target });
+			Ok(())
+		}
+
+		/// Set the Ngo that receives donations exceeding another Ngo's cap.
+		/// Only Governance can call it.
+		///
+		/// # Parameters
+		/// * `ngo`: Overflow Ngo, `None` disables redirection.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_overflow_ngo())]
+		pub fn set_overflow_ngo(origin: OriginFor<T>, ngo: Option<T::AccountId>) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			if let Some(ngo) = &ngo {
+				ensure!(
+					participant_handler::pallet::Pallet::<T>::is_active_ngo(ngo),
+					Error::<T>::RecipientNotValid
+				);
+			}
+			<OverflowNgo<T>>::set(ngo.clone());
+			Self::deposit_event(Event::OverflowNgoUpdated { ngo });
 			Ok(())
 		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Donates `amount` to `recipient`, or to the Overflow Ngo if the donation exceeds
+		/// `recipient`'s cap. Returns the Ngo that received the donation.
 		#[transactional]
 		pub fn do_donate(
 			donor: &T::AccountId,
 			recipient: &T::AccountId,
 			amount: u128,
 			category: &Category,
-		) -> DispatchResult {
+		) -> Result<T::AccountId, DispatchError> {
 			ensure!(
 				participant_handler::pallet::Pallet::<T>::is_active_ngo(recipient),
 				Error::<T>::RecipientNotValid
 			);
 			// TODO: Also check if NGO can accept in given Category
+			Self::ensure_below_funding_target(recipient, category)?;
+			let beneficiary = match Self::ensure_within_cap(recipient, category, amount) {
+				Ok(()) => recipient.clone(),
+				Err(err) => {
+					let overflow_ngo = <OverflowNgo<T>>::get()
+						.filter(|ngo| {
+							ngo != recipient &&
+								participant_handler::pallet::Pallet::<T>::is_active_ngo(ngo)
+						})
+						.ok_or(err)?;
+					Self::ensure_below_funding_target(&overflow_ngo, category)?;
+					Self::ensure_within_cap(&overflow_ngo, category, amount)?;
+					overflow_ngo
+				},
+			};
 			<T as Config>::Currency::transfer(
 				donor,
 				&Self::get_pallet_account(),
@@ -132,27 +559,206 @@ pub mod pallet {
 			)?;
 			T::TokenHandler::mint_into(
 				category.get_id() as u128,
-				recipient,
+				&beneficiary,
 				amount.saturated_into(),
 			)?;
+			Self::note_donation(donor, &beneficiary, category, amount);
+			Ok(beneficiary)
+		}
+
+		fn ensure_below_funding_target(ngo: &T::AccountId, category: &Category) -> DispatchResult {
+			if let Some(target) = <FundingTargets<T>>::get(ngo, category) {
+				ensure!(
+					<NgoStats<T>>::get(ngo, category).received < target,
+					Error::<T>::FundingTargetReached
+				);
+			}
 			Ok(())
 		}
 
+		fn ensure_within_cap(
+			ngo: &T::AccountId,
+			category: &Category,
+			amount: u128,
+		) -> DispatchResult {
+			if let Some(cap) = <DonationCaps<T>>::get(ngo, category) {
+				let received = Self::received_in_current_period(ngo, category);
+				ensure!(received.saturating_add(amount) <= cap, Error::<T>::DonationCapExceeded);
+			}
+			Ok(())
+		}
+
+		fn current_period() -> T::BlockNumber {
+			frame_system::Pallet::<T>::block_number()
+				.checked_div(&T::CapPeriod::get())
+				.unwrap_or_else(Zero::zero)
+		}
+
+		/// Returns the amount `ngo` has received in `category` during the current cap period.
+		pub fn received_in_current_period(ngo: &T::AccountId, category: &Category) -> u128 {
+			let usage = <PeriodReceived<T>>::get(ngo, category);
+			if usage.period == Self::current_period() {
+				usage.received
+			} else {
+				0
+			}
+		}
+
 		#[transactional]
 		pub fn do_claim(
 			recipient: &T::AccountId,
 			category: &Category,
 			amount: u128,
-		) -> DispatchResult {
+		) -> Result<(PayoutId, T::BlockNumber), DispatchError> {
 			ensure!(
 				participant_handler::pallet::Pallet::<T>::is_active_seller(recipient),
 				Error::<T>::CallerNotValid
 			);
-			T::TokenHandler::burn_from(
+			T::TokenHandler::transfer(
 				category.get_id() as u128,
 				recipient,
+				&Self::get_pallet_account(),
 				amount.saturated_into(),
+				false,
+			)?;
+			let unlock_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::ClaimHoldBackPeriod::get());
+			let payout_id = <NextPayoutId<T>>::mutate(|next_id| {
+				let payout_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				payout_id
+			});
+			let payout =
+				PendingPayout { seller: recipient.clone(), category: *category, amount, unlock_at };
+			<PendingPayouts<T>>::insert(payout_id, payout);
+			<SellerPayouts<T>>::insert(recipient, payout_id, ());
+			Ok((payout_id, unlock_at))
+		}
+
+		#[transactional]
+		pub fn do_withdraw_payout(
+			seller: &T::AccountId,
+			payout_id: PayoutId,
+		) -> Result<PendingPayout<T::AccountId, T::BlockNumber>, DispatchError> {
+			let payout = <PendingPayouts<T>>::get(payout_id).ok_or(Error::<T>::PayoutNotFound)?;
+			ensure!(payout.seller == *seller, Error::<T>::CallerNotValid);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= payout.unlock_at,
+				Error::<T>::PayoutStillLocked
+			);
+			T::TokenHandler::burn_from(
+				payout.category.get_id() as u128,
+				&Self::get_pallet_account(),
+				payout.amount.saturated_into(),
 			)?;
+			<T as Config>::Currency::transfer(
+				&Self::get_pallet_account(),
+				seller,
+				payout.amount.saturated_into(),
+				ExistenceRequirement::KeepAlive,
+			)?;
+			<PendingPayouts<T>>::remove(payout_id);
+			<SellerPayouts<T>>::remove(seller, payout_id);
+			<SellerStats<T>>::mutate(seller, payout.category, |stats| {
+				stats.claimed = stats.claimed.saturating_add(payout.amount)
+			});
+			Ok(payout)
+		}
+
+		/// Cancels a pending payout, returning the held SFT to `refund_to`.
+		/// Used by Governance and by dispute resolution in other pallets.
+		#[transactional]
+		pub fn do_cancel_payout(payout_id: PayoutId, refund_to: &T::AccountId) -> DispatchResult {
+			let payout = <PendingPayouts<T>>::take(payout_id).ok_or(Error::<T>::PayoutNotFound)?;
+			T::TokenHandler::transfer(
+				payout.category.get_id() as u128,
+				&Self::get_pallet_account(),
+				refund_to,
+				payout.amount.saturated_into(),
+				false,
+			)?;
+			<SellerPayouts<T>>::remove(&payout.seller, payout_id);
+			Self::deposit_event(Event::PayoutCancelled {
+				payout_id,
+				seller: payout.seller,
+				refund_to: refund_to.clone(),
+				amount: payout.amount,
+			});
+			Ok(())
+		}
+
+		/// Pending payouts of `seller`, failing if there are more than `max_payouts`.
+		/// Used with `do_refund_from_payouts` by product recalls in other pallets.
+		pub fn seller_pending_payouts(
+			seller: &T::AccountId,
+			max_payouts: u32,
+		) -> Result<Vec<(PayoutId, PendingPayout<T::AccountId, T::BlockNumber>)>, DispatchError> {
+			let payout_ids: Vec<PayoutId> = <SellerPayouts<T>>::iter_key_prefix(seller)
+				.take((max_payouts as usize).saturating_add(1))
+				.collect();
+			ensure!(payout_ids.len() <= max_payouts as usize, Error::<T>::TooManyPayouts);
+			Ok(payout_ids
+				.into_iter()
+				.filter_map(|payout_id| {
+					<PendingPayouts<T>>::get(payout_id).map(|payout| (payout_id, payout))
+				})
+				.collect())
+		}
+
+		/// Refunds up to `amount` of the held SFT of `payouts` in `category` to `refund_to`,
+		/// returning the amount refunded. `payouts` is kept in step with storage, emptied
+		/// payouts are dropped from it.
+		#[transactional]
+		pub fn do_refund_from_payouts(
+			payouts: &mut Vec<(PayoutId, PendingPayout<T::AccountId, T::BlockNumber>)>,
+			category: &Category,
+			amount: u128,
+			refund_to: &T::AccountId,
+		) -> Result<u128, DispatchError> {
+			let mut remaining = amount;
+			for (payout_id, payout) in
+				payouts.iter_mut().filter(|(_, payout)| payout.category == *category)
+			{
+				if remaining.is_zero() {
+					break
+				}
+				let refunded = remaining.min(payout.amount);
+				T::TokenHandler::transfer(
+					category.get_id() as u128,
+					&Self::get_pallet_account(),
+					refund_to,
+					refunded.saturated_into(),
+					false,
+				)?;
+				remaining = remaining.saturating_sub(refunded);
+				payout.amount = payout.amount.saturating_sub(refunded);
+				if payout.amount.is_zero() {
+					<PendingPayouts<T>>::remove(*payout_id);
+					<SellerPayouts<T>>::remove(&payout.seller, *payout_id);
+				} else {
+					<PendingPayouts<T>>::insert(*payout_id, &*payout);
+				}
+				Self::deposit_event(Event::PayoutRefunded {
+					payout_id: *payout_id,
+					seller: payout.seller.clone(),
+					refund_to: refund_to.clone(),
+					amount: refunded,
+				});
+			}
+			payouts.retain(|(_, payout)| !payout.amount.is_zero());
+			Ok(amount.saturating_sub(remaining))
+		}
+
+		/// Burns `amount` of `holder`'s SFT in `category` and pays the Native Token backing it to
+		/// `recipient`. Used to pay out commission taken by other pallets.
+		#[transactional]
+		pub fn do_redeem(
+			holder: &T::AccountId,
+			category: &Category,
+			amount: u128,
+			recipient: &T::AccountId,
+		) -> DispatchResult {
+			T::TokenHandler::burn_from(category.get_id() as u128, holder, amount.saturated_into())?;
 			<T as Config>::Currency::transfer(
 				&Self::get_pallet_account(),
 				recipient,
@@ -162,6 +768,29 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Takes `amount` of Native Token from `payer` back into the pallet and mints the SFT it
+		/// backs to `recipient`, reversing `do_redeem`.
+		#[transactional]
+		pub fn do_reissue(
+			payer: &T::AccountId,
+			category: &Category,
+			amount: u128,
+			recipient: &T::AccountId,
+		) -> DispatchResult {
+			<T as Config>::Currency::transfer(
+				payer,
+				&Self::get_pallet_account(),
+				amount.saturated_into(),
+				ExistenceRequirement::KeepAlive,
+			)?;
+			T::TokenHandler::mint_into(
+				category.get_id() as u128,
+				recipient,
+				amount.saturated_into(),
+			)?;
+			Ok(())
+		}
+
 		#[transactional]
 		pub fn do_transfer_token(
 			source: &T::AccountId,
@@ -185,9 +814,86 @@ pub mod pallet {
 				amount.saturated_into(),
 				true,
 			)?;
+			Self::note_purchase(source, recipient, category, amount);
 			Ok(())
 		}
 
+		fn note_donation(
+			donor: &T::AccountId,
+			recipient: &T::AccountId,
+			category: &Category,
+			amount: u128,
+		) {
+			<NgoStats<T>>::mutate(recipient, category, |stats| {
+				stats.received = stats.received.saturating_add(amount)
+			});
+			let period = Self::current_period();
+			<PeriodReceived<T>>::mutate(recipient, category, |usage| {
+				if usage.period != period {
+					*usage = PeriodUsage { period, received: 0 };
+				}
+				usage.received = usage.received.saturating_add(amount);
+			});
+			<DonorTotals<T>>::mutate(donor, |total| *total = total.saturating_add(amount));
+			if !<NgoDonors<T>>::contains_key(recipient, donor) {
+				<NgoDonors<T>>::insert(recipient, donor, ());
+				<NgoDonorCount<T>>::mutate(recipient, |count| *count = count.saturating_add(1));
+			}
+		}
+
+		/// Records a purchase of `amount` PBT made by `ngo` from `seller`.
+		pub fn note_purchase(
+			ngo: &T::AccountId,
+			seller: &T::AccountId,
+			category: &Category,
+			amount: u128,
+		) {
+			<NgoStats<T>>::mutate(ngo, category, |stats| {
+				stats.spent = stats.spent.saturating_add(amount)
+			});
+			<SellerStats<T>>::mutate(seller, category, |stats| {
+				stats.sold = stats.sold.saturating_add(amount)
+			});
+		}
+
+		fn record_donation(
+			donor: &T::AccountId,
+			recipient: &T::AccountId,
+			category: Category,
+			amount: u128,
+			memo: Option<Memo>,
+			metadata: Option<T::Hash>,
+		) -> DonationId {
+			let donation_id = <NextDonationId<T>>::mutate(|next_id| {
+				let donation_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				donation_id
+			});
+			let record = DonationRecord {
+				donor: donor.clone(),
+				recipient: recipient.clone(),
+				category,
+				amount,
+				memo,
+				metadata,
+				block: frame_system::Pallet::<T>::block_number(),
+			};
+			<Donations<T>>::insert(donation_id, record);
+			donation_id
+		}
+
+		/// Returns up to `limit` donation records starting from `from` (inclusive).
+		pub fn donations(
+			from: DonationId,
+			limit: u32,
+		) -> Vec<(DonationId, DonationRecord<T::AccountId, T::Hash, T::BlockNumber>)> {
+			let next_id = <NextDonationId<T>>::get();
+			(from..next_id)
+				.filter_map(|id| <Donations<T>>::get(id).map(|record| (id, record)))
+				.take(limit as usize)
+				.collect()
+		}
+
 		fn get_pallet_account() -> T::AccountId {
 			T::DonationPalletId::get().into_account_truncating()
 		}
@@ -133,11 +133,19 @@ parameter_types! {
 	pub const DonationPalletId: PalletId = PalletId(*b"py/nopls");
 }
 
+parameter_types! {
+	pub const CapPeriod: u64 = 100;
+	pub const ClaimHoldBackPeriod: u64 = 10;
+}
+
 impl donation_handler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type TokenHandler = TokenHandler;
-	type Currency = ();
+	type Currency = Balances;
 	type DonationPalletId = DonationPalletId;
+	type CapPeriod = CapPeriod;
+	type ClaimHoldBackPeriod = ClaimHoldBackPeriod;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -151,6 +159,7 @@ impl participant_handler::Config for Test {
 	type SellerStakingAmount = SellerStakingAmount;
 	type Currency = Balances;
 	type GovernanceOrigin = EnsureSigned<Self::AccountId>;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,5 +1,13 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations, mock::*, DonationRecord, Donations, DonorTotals, Error, Event, Memo,
+	NextDonationId, NgoCategoryStats, NgoDonorCount, NgoStats, PendingPayout, PendingPayouts,
+	SellerCategoryStats, SellerStats,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	sp_runtime::traits::AccountIdConversion,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
 use participant_handler::{Category, NgoInfo, SellerInfo};
 use sp_core::H256;
 
@@ -15,7 +23,9 @@ fn test_donate_with_valid_arguments_returns_ok() {
 			RuntimeOrigin::signed(donor),
 			ngo,
 			Category::Pharmaceutical,
-			1_000_000_000_000
+			1_000_000_000_000,
+			None,
+			None
 		));
 	})
 }
@@ -32,17 +42,243 @@ fn test_donate_with_wrong_ngo_returns_error() {
 				RuntimeOrigin::signed(donor),
 				ngo,
 				Category::Pharmaceutical,
-				1_000_000_000_000
+				1_000_000_000_000,
+				None,
+				None
 			),
 			Error::<Test>::RecipientNotValid
 		);
 	})
 }
 
+#[test]
+fn test_donate_with_memo_and_metadata_stores_donation_record() {
+	new_test_ext().execute_with(|| {
+		create_assets();
+		System::set_block_number(1);
+		let ngo: u64 = 1;
+		let donor: u64 = 2;
+		Balances::set_balance(RuntimeOrigin::root(), 2, 1_000_000_000_000_000, 0);
+		add_ngo(ngo);
+		let memo: Memo = b"In memory of Ada".to_vec().try_into().unwrap();
+		let metadata = H256([7; 32]);
+		assert_ok!(DonationHandler::donate(
+			RuntimeOrigin::signed(donor),
+			ngo,
+			Category::Pharmaceutical,
+			1_000_000_000_000,
+			Some(memo.clone()),
+			Some(metadata)
+		));
+		assert_eq!(
+			<Donations<Test>>::get(0),
+			Some(DonationRecord {
+				donor,
+				recipient: ngo,
+				category: Category::Pharmaceutical,
+				amount: 1_000_000_000_000,
+				memo: Some(memo.clone()),
+				metadata: Some(metadata),
+				block: 1,
+			})
+		);
+		assert_eq!(<NextDonationId<Test>>::get(), 1);
+		System::assert_last_event(
+			Event::TokenDonated {
+				donation_id: 0,
+				donor,
+				recipient: ngo,
+				amount: 1_000_000_000_000,
+				category: Category::Pharmaceutical,
+				memo: Some(memo),
+				metadata: Some(metadata),
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_failed_donation_does_not_store_record() {
+	new_test_ext().execute_with(|| {
+		create_assets();
+		let ngo: u64 = 1;
+		let donor: u64 = 2;
+		Balances::set_balance(RuntimeOrigin::root(), 2, 1_000_000_000_000_000, 0);
+		assert_noop!(
+			DonationHandler::donate(
+				RuntimeOrigin::signed(donor),
+				ngo,
+				Category::Pharmaceutical,
+				1_000_000_000_000,
+				None,
+				Some(H256([7; 32]))
+			),
+			Error::<Test>::RecipientNotValid
+		);
+		assert!(<Donations<Test>>::get(0).is_none());
+	})
+}
+
+#[test]
+fn test_donate_updates_ngo_and_donor_stats() {
+	new_test_ext().execute_with(|| {
+		create_assets();
+		let ngo: u64 = 1;
+		let donor: u64 = 2;
+		Balances::set_balance(RuntimeOrigin::root(), 2, 1_000_000_000_000_000, 0);
+		add_ngo(ngo);
+		for _ in 0..2 {
+			assert_ok!(DonationHandler::donate(
+				RuntimeOrigin::signed(donor),
+				ngo,
+				Category::Pharmaceutical,
+				1_000_000_000_000,
+				None,
+				None
+			));
+		}
+		assert_eq!(
+			<NgoStats<Test>>::get(ngo, Category::Pharmaceutical),
+			NgoCategoryStats { received: 2_000_000_000_000, spent: 0 }
+		);
+		assert_eq!(<NgoStats<Test>>::get(ngo, Category::Grocery), NgoCategoryStats::default());
+		assert_eq!(<DonorTotals<Test>>::get(donor), 2_000_000_000_000);
+		assert_eq!(<NgoDonorCount<Test>>::get(ngo), 1);
+	})
+}
+
+#[test]
+fn test_transfer_token_updates_purchase_stats() {
+	new_test_ext().execute_with(|| {
+		create_assets();
+		let ngo: u64 = 2;
+		let seller: u64 = 3;
+		add_ngo(ngo);
+		add_seller(seller);
+		assert_ok!(TokenHandler::mint(
+			RuntimeOrigin::signed(1),
+			codec::Compact(Category::Pharmaceutical.get_id() as u128),
+			ngo,
+			1_000
+		));
+		assert_ok!(DonationHandler::do_transfer_token(
+			&ngo,
+			&seller,
+			&Category::Pharmaceutical,
+			400
+		));
+		assert_eq!(
+			<NgoStats<Test>>::get(ngo, Category::Pharmaceutical),
+			NgoCategoryStats { received: 0, spent: 400 }
+		);
+		assert_eq!(
+			<SellerStats<Test>>::get(seller, Category::Pharmaceutical),
+			SellerCategoryStats { sold: 400, claimed: 0 }
+		);
+	})
+}
+
+#[test]
+fn test_donate_over_cap_without_overflow_ngo_returns_error() {
+	new_test_ext().execute_with(|| {
+		create_assets();
+		let ngo: u64 = 1;
+		let donor: u64 = 2;
+		Balances::set_balance(RuntimeOrigin::root(), 2, 1_000_000_000_000_000, 0);
+		add_ngo(ngo);
+		assert_ok!(DonationHandler::set_donation_cap(
+			RuntimeOrigin::signed(0),
+			ngo,
+			Category::Pharmaceutical,
+			Some(1_500_000_000_000)
+		));
+		assert_ok!(donate(donor, ngo, 1_000_000_000_000));
+		assert_noop!(donate(donor, ngo, 1_000_000_000_000), Error::<Test>::DonationCapExceeded);
+	})
+}
+
+#[test]
+fn test_donate_over_cap_redirects_to_overflow_ngo() {
+	new_test_ext().execute_with(|| {
+		create_assets();
+		let ngo: u64 = 1;
+		let donor: u64 = 2;
+		let overflow_ngo: u64 = 3;
+		Balances::set_balance(RuntimeOrigin::root(), 2, 1_000_000_000_000_000, 0);
+		add_ngo(ngo);
+		add_ngo(overflow_ngo);
+		assert_ok!(DonationHandler::set_donation_cap(
+			RuntimeOrigin::signed(0),
+			ngo,
+			Category::Pharmaceutical,
+			Some(1_000_000_000_000)
+		));
+		assert_ok!(DonationHandler::set_overflow_ngo(RuntimeOrigin::signed(0), Some(overflow_ngo)));
+		assert_ok!(donate(donor, ngo, 1_000_000_000_000));
+		assert_ok!(donate(donor, ngo, 1_000_000_000_000));
+		assert_eq!(
+			<NgoStats<Test>>::get(ngo, Category::Pharmaceutical).received,
+			1_000_000_000_000
+		);
+		assert_eq!(
+			<NgoStats<Test>>::get(overflow_ngo, Category::Pharmaceutical).received,
+			1_000_000_000_000
+		);
+		System::assert_has_event(
+			Event::DonationRedirected {
+				donation_id: 1,
+				requested_recipient: ngo,
+				recipient: overflow_ngo,
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn test_donation_cap_resets_in_next_period() {
+	new_test_ext().execute_with(|| {
+		create_assets();
+		let ngo: u64 = 1;
+		let donor: u64 = 2;
+		Balances::set_balance(RuntimeOrigin::root(), 2, 1_000_000_000_000_000, 0);
+		add_ngo(ngo);
+		assert_ok!(DonationHandler::set_donation_cap(
+			RuntimeOrigin::signed(0),
+			ngo,
+			Category::Pharmaceutical,
+			Some(1_000_000_000_000)
+		));
+		assert_ok!(donate(donor, ngo, 1_000_000_000_000));
+		System::set_block_number(CapPeriod::get());
+		assert_ok!(donate(donor, ngo, 1_000_000_000_000));
+	})
+}
+
+#[test]
+fn test_donate_after_funding_target_reached_returns_error() {
+	new_test_ext().execute_with(|| {
+		create_assets();
+		let ngo: u64 = 1;
+		let donor: u64 = 2;
+		Balances::set_balance(RuntimeOrigin::root(), 2, 1_000_000_000_000_000, 0);
+		add_ngo(ngo);
+		assert_ok!(DonationHandler::set_funding_target(
+			RuntimeOrigin::signed(ngo),
+			Category::Pharmaceutical,
+			Some(1_000_000_000_000)
+		));
+		assert_ok!(donate(donor, ngo, 1_000_000_000_000));
+		assert_noop!(donate(donor, ngo, 1_000_000_000_000), Error::<Test>::FundingTargetReached);
+	})
+}
+
 #[test]
 fn test_claim_token_with_valid_args_returns_ok() {
 	new_test_ext().execute_with(|| {
 		create_assets();
+		System::set_block_number(1);
 		let seller = 1;
 		Balances::set_balance(RuntimeOrigin::root(), seller, 1_000_000_000_000_000, 0);
 		add_seller(seller);
@@ -57,6 +293,117 @@ fn test_claim_token_with_valid_args_returns_ok() {
 			Category::Pharmaceutical,
 			100
 		));
+		let unlock_at = 1 + ClaimHoldBackPeriod::get();
+		assert_eq!(
+			<PendingPayouts<Test>>::get(0),
+			Some(PendingPayout {
+				seller,
+				category: Category::Pharmaceutical,
+				amount: 100,
+				unlock_at
+			})
+		);
+		assert_eq!(TokenHandler::balance(Category::Pharmaceutical.get_id() as u128, seller), 900);
+		System::assert_last_event(
+			Event::PayoutScheduled {
+				payout_id: 0,
+				seller,
+				category: Category::Pharmaceutical,
+				amount: 100,
+				unlock_at,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn test_withdraw_payout_before_unlock_returns_error() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let seller = claim_for_new_seller(100);
+		assert_noop!(
+			DonationHandler::withdraw_payout(RuntimeOrigin::signed(seller), 0),
+			Error::<Test>::PayoutStillLocked
+		);
+	});
+}
+
+#[test]
+fn test_withdraw_payout_after_unlock_pays_native_token() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let seller = claim_for_new_seller(100);
+		let pallet_account: u64 = DonationPalletId::get().into_account_truncating();
+		Balances::set_balance(RuntimeOrigin::root(), pallet_account, 1_000_000_000_000_000, 0);
+		let native_before = Balances::free_balance(seller);
+		System::set_block_number(1 + ClaimHoldBackPeriod::get());
+		assert_ok!(DonationHandler::withdraw_payout(RuntimeOrigin::signed(seller), 0));
+		assert_eq!(Balances::free_balance(seller), native_before + 100);
+		assert!(<PendingPayouts<Test>>::get(0).is_none());
+		assert_eq!(<SellerStats<Test>>::get(seller, Category::Pharmaceutical).claimed, 100);
+	});
+}
+
+#[test]
+fn test_cancel_payout_refunds_held_tokens() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let seller = claim_for_new_seller(100);
+		let ngo: u64 = 4;
+		assert_ok!(DonationHandler::cancel_payout(RuntimeOrigin::signed(0), 0, ngo));
+		assert_eq!(TokenHandler::balance(Category::Pharmaceutical.get_id() as u128, ngo), 100);
+		assert!(<PendingPayouts<Test>>::get(0).is_none());
+		System::set_block_number(1 + ClaimHoldBackPeriod::get());
+		assert_noop!(
+			DonationHandler::withdraw_payout(RuntimeOrigin::signed(seller), 0),
+			Error::<Test>::PayoutNotFound
+		);
+	});
+}
+
+#[test]
+fn test_refund_from_payouts_takes_from_pending_payouts_in_category() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let seller = claim_for_new_seller(100);
+		let ngo: u64 = 4;
+		assert_noop!(
+			DonationHandler::seller_pending_payouts(&seller, 0),
+			Error::<Test>::TooManyPayouts
+		);
+		let mut payouts = DonationHandler::seller_pending_payouts(&seller, 1).unwrap();
+		assert_eq!(
+			DonationHandler::do_refund_from_payouts(&mut payouts, &Category::Clothing, 40, &ngo),
+			Ok(0)
+		);
+		assert_eq!(
+			DonationHandler::do_refund_from_payouts(
+				&mut payouts,
+				&Category::Pharmaceutical,
+				40,
+				&ngo
+			),
+			Ok(40)
+		);
+		assert_eq!(TokenHandler::balance(Category::Pharmaceutical.get_id() as u128, ngo), 40);
+		assert_eq!(<PendingPayouts<Test>>::get(0).unwrap().amount, 60);
+		assert_eq!(payouts[0].1.amount, 60);
+		System::assert_last_event(
+			Event::PayoutRefunded { payout_id: 0, seller, refund_to: ngo, amount: 40 }.into(),
+		);
+
+		assert_eq!(
+			DonationHandler::do_refund_from_payouts(
+				&mut payouts,
+				&Category::Pharmaceutical,
+				100,
+				&ngo
+			),
+			Ok(60)
+		);
+		assert!(<PendingPayouts<Test>>::get(0).is_none());
+		assert!(payouts.is_empty());
 	});
 }
 
@@ -77,13 +424,52 @@ fn test_claim_token_wrong_seller_returns_error() {
 	})
 }
 
+#[test]
+fn test_migrate_to_v1_records_storage_version() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<DonationHandler>();
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(DonationHandler::on_chain_storage_version(), 1);
+	})
+}
+
+fn donate(donor: u64, ngo: u64, amount: u128) -> frame_support::dispatch::DispatchResult {
+	DonationHandler::donate(
+		RuntimeOrigin::signed(donor),
+		ngo,
+		Category::Pharmaceutical,
+		amount,
+		None,
+		None,
+	)
+}
+
+fn claim_for_new_seller(amount: u128) -> u64 {
+	create_assets();
+	let seller = 3;
+	Balances::set_balance(RuntimeOrigin::root(), seller, 1_000_000_000_000_000, 0);
+	add_seller(seller);
+	assert_ok!(TokenHandler::mint(
+		RuntimeOrigin::signed(1),
+		codec::Compact(Category::Pharmaceutical.get_id() as u128),
+		seller,
+		1_000
+	));
+	assert_ok!(DonationHandler::claim_token(
+		RuntimeOrigin::signed(seller),
+		Category::Pharmaceutical,
+		amount
+	));
+	seller
+}
+
 fn add_ngo(ngo: u64) {
 	let ngo_info = NgoInfo { categories: Default::default(), cid: H256::zero() };
 	ParticipantHandler::add_ngo_to_active_list(&ngo, ngo_info);
 }
 
 fn add_seller(seller: u64) {
-	let seller_info = SellerInfo { category: Default::default(), cid: H256::default() };
+	let seller_info = SellerInfo { categories: Default::default(), cid: H256::default() };
 	ParticipantHandler::add_seller_to_active_list(&seller, seller_info);
 }
 
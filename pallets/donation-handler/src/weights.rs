@@ -0,0 +1,169 @@
+//! PLACEHOLDER weights for donation_handler, hand-written and not produced by the benchmark CLI.
+//!
+//! Do not ship these. Replace this file with the output of `.maintain/benchmark.sh` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for donation_handler.
+pub trait WeightInfo {
+	fn donate() -> Weight;
+	fn claim_token() -> Weight;
+	fn withdraw_payout() -> Weight;
+	fn cancel_payout() -> Weight;
+	fn set_donation_cap() -> Weight;
+	fn set_funding_target() -> Weight;
+	fn set_overflow_ngo() -> Weight;
+}
+
+/// Weights for donation_handler using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: ParticipantHandler NgoActiveList (r:2 w:0)
+	// Storage: DonationHandler FundingTargets (r:2 w:0)
+	// Storage: DonationHandler NgoStats (r:2 w:1)
+	// Storage: DonationHandler DonationCaps (r:2 w:0)
+	// Storage: DonationHandler PeriodReceived (r:2 w:1)
+	// Storage: DonationHandler OverflowNgo (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:1 w:1)
+	// Storage: DonationHandler DonorTotals (r:1 w:1)
+	// Storage: DonationHandler NgoDonors (r:1 w:1)
+	// Storage: DonationHandler NgoDonorCount (r:1 w:1)
+	// Storage: DonationHandler NextDonationId (r:1 w:1)
+	// Storage: DonationHandler Donations (r:0 w:1)
+	fn donate() -> Weight {
+		Weight::from_ref_time(112_000_000)
+			.saturating_add(T::DbWeight::get().reads(19))
+			.saturating_add(T::DbWeight::get().writes(11))
+	}
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: DonationHandler NextPayoutId (r:1 w:1)
+	// Storage: DonationHandler PendingPayouts (r:0 w:1)
+	// Storage: DonationHandler SellerPayouts (r:0 w:1)
+	fn claim_token() -> Weight {
+		Weight::from_ref_time(64_000_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	// Storage: DonationHandler PendingPayouts (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DonationHandler SellerStats (r:1 w:1)
+	// Storage: DonationHandler SellerPayouts (r:0 w:1)
+	fn withdraw_payout() -> Weight {
+		Weight::from_ref_time(78_000_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	// Storage: DonationHandler PendingPayouts (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: DonationHandler SellerPayouts (r:0 w:1)
+	fn cancel_payout() -> Weight {
+		Weight::from_ref_time(52_000_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: DonationHandler DonationCaps (r:0 w:1)
+	fn set_donation_cap() -> Weight {
+		Weight::from_ref_time(16_000_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: DonationHandler FundingTargets (r:0 w:1)
+	fn set_funding_target() -> Weight {
+		Weight::from_ref_time(20_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: DonationHandler OverflowNgo (r:0 w:1)
+	fn set_overflow_ngo() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: ParticipantHandler NgoActiveList (r:2 w:0)
+	// Storage: DonationHandler FundingTargets (r:2 w:0)
+	// Storage: DonationHandler NgoStats (r:2 w:1)
+	// Storage: DonationHandler DonationCaps (r:2 w:0)
+	// Storage: DonationHandler PeriodReceived (r:2 w:1)
+	// Storage: DonationHandler OverflowNgo (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:1 w:1)
+	// Storage: DonationHandler DonorTotals (r:1 w:1)
+	// Storage: DonationHandler NgoDonors (r:1 w:1)
+	// Storage: DonationHandler NgoDonorCount (r:1 w:1)
+	// Storage: DonationHandler NextDonationId (r:1 w:1)
+	// Storage: DonationHandler Donations (r:0 w:1)
+	fn donate() -> Weight {
+		Weight::from_ref_time(112_000_000)
+			.saturating_add(RocksDbWeight::get().reads(19))
+			.saturating_add(RocksDbWeight::get().writes(11))
+	}
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: DonationHandler NextPayoutId (r:1 w:1)
+	// Storage: DonationHandler PendingPayouts (r:0 w:1)
+	// Storage: DonationHandler SellerPayouts (r:0 w:1)
+	fn claim_token() -> Weight {
+		Weight::from_ref_time(64_000_000)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(6))
+	}
+	// Storage: DonationHandler PendingPayouts (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: DonationHandler SellerStats (r:1 w:1)
+	// Storage: DonationHandler SellerPayouts (r:0 w:1)
+	fn withdraw_payout() -> Weight {
+		Weight::from_ref_time(78_000_000)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(7))
+	}
+	// Storage: DonationHandler PendingPayouts (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: DonationHandler SellerPayouts (r:0 w:1)
+	fn cancel_payout() -> Weight {
+		Weight::from_ref_time(52_000_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	// Storage: DonationHandler DonationCaps (r:0 w:1)
+	fn set_donation_cap() -> Weight {
+		Weight::from_ref_time(16_000_000)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: DonationHandler FundingTargets (r:0 w:1)
+	fn set_funding_target() -> Weight {
+		Weight::from_ref_time(20_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: DonationHandler OverflowNgo (r:0 w:1)
+	fn set_overflow_ngo() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
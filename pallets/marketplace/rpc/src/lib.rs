@@ -0,0 +1,293 @@
+//! RPC interface for the marketplace pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use marketplace::{OrderId, OrderSource, OrderStatus, ProductInfo, Status};
+pub use marketplace_runtime_api::MarketplaceApi as MarketplaceRuntimeApi;
+use participant_handler::Category;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_rpc::number::NumberOrHex;
+use sp_runtime::traits::Block as BlockT;
+pub use support_rpc::Page;
+use support_rpc::{block_at, into_page, page_size, runtime_error};
+
+/// Listed product.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Product<AccountId, Hash> {
+	pub pid: Hash,
+	pub owner: AccountId,
+	pub category: Category,
+	pub accepted_categories: Vec<Category>,
+	/// Price of a single unit, in `price_asset` if set or in PBT otherwise.
+	pub price: NumberOrHex,
+	pub price_asset: Option<NumberOrHex>,
+	pub quantity: u32,
+	pub status: Status,
+	pub cid: Hash,
+}
+
+impl<AccountId, Hash> From<(Hash, ProductInfo<AccountId, Hash>)> for Product<AccountId, Hash> {
+	fn from((pid, product): (Hash, ProductInfo<AccountId, Hash>)) -> Self {
+		Self {
+			pid,
+			owner: product.owner,
+			category: product.category,
+			accepted_categories: product.accepted_categories.into_inner(),
+			price: product.price.into(),
+			price_asset: product.price_asset.map(Into::into),
+			quantity: product.quantity,
+			status: product.status,
+			cid: product.cid,
+		}
+	}
+}
+
+/// Order placed by a buyer.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Order<AccountId, Hash, BlockNumber> {
+	pub order_id: OrderId,
+	pub source: OrderSource<Hash>,
+	pub buyer: AccountId,
+	pub seller: AccountId,
+	pub quantity: u32,
+	/// Total PBT held in escrow.
+	pub amount: NumberOrHex,
+	/// Split of `amount` across PBT categories.
+	pub payment: Vec<(Category, NumberOrHex)>,
+	pub status: OrderStatus,
+	pub tracking: Option<Hash>,
+	pub placed_at: BlockNumber,
+	pub shipped_at: Option<BlockNumber>,
+}
+
+impl<AccountId, Hash, BlockNumber> From<(OrderId, marketplace::Order<AccountId, Hash, BlockNumber>)>
+	for Order<AccountId, Hash, BlockNumber>
+{
+	fn from(
+		(order_id, order): (OrderId, marketplace::Order<AccountId, Hash, BlockNumber>),
+	) -> Self {
+		Self {
+			order_id,
+			source: order.source,
+			buyer: order.buyer,
+			seller: order.seller,
+			quantity: order.quantity,
+			amount: order.amount.into(),
+			payment: order
+				.payment
+				.into_iter()
+				.map(|(category, part)| (category, part.into()))
+				.collect(),
+			status: order.status,
+			tracking: order.tracking,
+			placed_at: order.placed_at,
+			shipped_at: order.shipped_at,
+		}
+	}
+}
+
+#[rpc(client, server)]
+pub trait MarketplaceApi<BlockHash, AccountId, Hash, BlockNumber> {
+	/// Returns the product `pid`.
+	#[method(name = "marketplace_product")]
+	fn product(
+		&self,
+		pid: Hash,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Product<AccountId, Hash>>>;
+
+	/// Returns a page of open listings in `category`.
+	#[method(name = "marketplace_productsByCategory")]
+	fn products_by_category(
+		&self,
+		category: Category,
+		start_after: Option<Hash>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Product<AccountId, Hash>, Hash>>;
+
+	/// Returns a page of products listed by `seller`.
+	#[method(name = "marketplace_productsBySeller")]
+	fn products_by_seller(
+		&self,
+		seller: AccountId,
+		start_after: Option<Hash>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Product<AccountId, Hash>, Hash>>;
+
+	/// Returns a page of products bought by `buyer`, delisted ones included.
+	#[method(name = "marketplace_productsByBuyer")]
+	fn products_by_buyer(
+		&self,
+		buyer: AccountId,
+		start_after: Option<Hash>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Product<AccountId, Hash>, Hash>>;
+
+	/// Returns the order `order_id`.
+	#[method(name = "marketplace_order")]
+	fn order(
+		&self,
+		order_id: OrderId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<Order<AccountId, Hash, BlockNumber>>>;
+
+	/// Returns a page of the purchase history of `buyer`.
+	#[method(name = "marketplace_ordersByBuyer")]
+	fn orders_by_buyer(
+		&self,
+		buyer: AccountId,
+		start_after: Option<OrderId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Order<AccountId, Hash, BlockNumber>, OrderId>>;
+
+	/// Returns a page of orders received by `seller`.
+	#[method(name = "marketplace_ordersBySeller")]
+	fn orders_by_seller(
+		&self,
+		seller: AccountId,
+		start_after: Option<OrderId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Order<AccountId, Hash, BlockNumber>, OrderId>>;
+}
+
+/// Marketplace RPC methods, backed by the `MarketplaceApi` runtime API.
+pub struct Marketplace<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Marketplace<C, Block> {
+	/// Create new `Marketplace` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Hash, BlockNumber>
+	MarketplaceApiServer<<Block as BlockT>::Hash, AccountId, Hash, BlockNumber>
+	for Marketplace<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: MarketplaceRuntimeApi<Block, AccountId, Hash, BlockNumber>,
+	AccountId: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+	Hash: Codec + Clone + Send + Sync + 'static + Serialize + DeserializeOwned,
+	BlockNumber: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+{
+	fn product(
+		&self,
+		pid: Hash,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Product<AccountId, Hash>>> {
+		let product = self
+			.client
+			.runtime_api()
+			.product(&block_at(&*self.client, at), pid.clone())
+			.map_err(runtime_error)?;
+		Ok(product.map(|product| (pid, product).into()))
+	}
+
+	fn products_by_category(
+		&self,
+		category: Category,
+		start_after: Option<Hash>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Product<AccountId, Hash>, Hash>> {
+		let limit = page_size(limit);
+		let products = self
+			.client
+			.runtime_api()
+			.products_by_category(&block_at(&*self.client, at), category, start_after, limit)
+			.map_err(runtime_error)?;
+		Ok(into_page(products, limit))
+	}
+
+	fn products_by_seller(
+		&self,
+		seller: AccountId,
+		start_after: Option<Hash>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Product<AccountId, Hash>, Hash>> {
+		let limit = page_size(limit);
+		let products = self
+			.client
+			.runtime_api()
+			.products_by_seller(&block_at(&*self.client, at), seller, start_after, limit)
+			.map_err(runtime_error)?;
+		Ok(into_page(products, limit))
+	}
+
+	fn products_by_buyer(
+		&self,
+		buyer: AccountId,
+		start_after: Option<Hash>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Product<AccountId, Hash>, Hash>> {
+		let limit = page_size(limit);
+		let products = self
+			.client
+			.runtime_api()
+			.products_by_buyer(&block_at(&*self.client, at), buyer, start_after, limit)
+			.map_err(runtime_error)?;
+		Ok(into_page(products, limit))
+	}
+
+	fn order(
+		&self,
+		order_id: OrderId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<Order<AccountId, Hash, BlockNumber>>> {
+		let order = self
+			.client
+			.runtime_api()
+			.order(&block_at(&*self.client, at), order_id)
+			.map_err(runtime_error)?;
+		Ok(order.map(|order| (order_id, order).into()))
+	}
+
+	fn orders_by_buyer(
+		&self,
+		buyer: AccountId,
+		start_after: Option<OrderId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Order<AccountId, Hash, BlockNumber>, OrderId>> {
+		let limit = page_size(limit);
+		let orders = self
+			.client
+			.runtime_api()
+			.orders_by_buyer(&block_at(&*self.client, at), buyer, start_after, limit)
+			.map_err(runtime_error)?;
+		Ok(into_page(orders, limit))
+	}
+
+	fn orders_by_seller(
+		&self,
+		seller: AccountId,
+		start_after: Option<OrderId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Order<AccountId, Hash, BlockNumber>, OrderId>> {
+		let limit = page_size(limit);
+		let orders = self
+			.client
+			.runtime_api()
+			.orders_by_seller(&block_at(&*self.client, at), seller, start_after, limit)
+			.map_err(runtime_error)?;
+		Ok(into_page(orders, limit))
+	}
+}
@@ -0,0 +1,40 @@
+//! Runtime API definition for the marketplace pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use marketplace::{Order, OrderId, ProductInfo};
+use participant_handler::Category;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait MarketplaceApi<AccountId, Hash, BlockNumber>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// Returns the product stored under `pid`.
+		fn product(pid: Hash) -> Option<ProductInfo<AccountId, Hash>>;
+
+		/// Returns up to `limit` open listings in `category`, starting after `start_after`.
+		fn products_by_category(category: Category, start_after: Option<Hash>, limit: u32) -> Vec<(Hash, ProductInfo<AccountId, Hash>)>;
+
+		/// Returns up to `limit` products listed by `seller`, starting after `start_after`.
+		fn products_by_seller(seller: AccountId, start_after: Option<Hash>, limit: u32) -> Vec<(Hash, ProductInfo<AccountId, Hash>)>;
+
+		/// Returns up to `limit` products bought by `buyer`, delisted ones included, starting after
+		/// `start_after`.
+		fn products_by_buyer(buyer: AccountId, start_after: Option<Hash>, limit: u32) -> Vec<(Hash, ProductInfo<AccountId, Hash>)>;
+
+		/// Returns the order stored under `order_id`.
+		fn order(order_id: OrderId) -> Option<Order<AccountId, Hash, BlockNumber>>;
+
+		/// Returns up to `limit` orders placed by `buyer`, starting after `start_after`.
+		fn orders_by_buyer(buyer: AccountId, start_after: Option<OrderId>, limit: u32) -> Vec<(OrderId, Order<AccountId, Hash, BlockNumber>)>;
+
+		/// Returns up to `limit` orders received by `seller`, starting after `start_after`.
+		fn orders_by_seller(seller: AccountId, start_after: Option<OrderId>, limit: u32) -> Vec<(OrderId, Order<AccountId, Hash, BlockNumber>)>;
+	}
+}
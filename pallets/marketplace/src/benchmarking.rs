@@ -1,41 +1,793 @@
+//! Benchmarking setup for marketplace
+
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as DonationHandler;
-use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use crate::Pallet as Marketplace;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
 use frame_support::{
-	sp_runtime::{traits::Hash, SaturatedConversion},
-	traits::{fungibles::Mutate, Currency},
+	sp_runtime::{
+		traits::{AccountIdConversion, Bounded, Hash, Zero},
+		Permill, SaturatedConversion,
+	},
+	sp_std::{vec, vec::Vec},
+	traits::{
+		fungibles::{Create, Inspect, Mutate},
+		Currency, EnsureOrigin, Get, Hooks,
+	},
+	BoundedVec,
 };
 use frame_system::RawOrigin;
 use participant_handler::{Category, NgoInfo, SellerInfo};
+
 const SEED: u32 = 0;
+/// Price of a single unit, split evenly across every PBT category
+const PRICE: u128 = 1_000_000;
+const QUANTITY: u32 = 1_000;
+/// PBT of each category minted to every Ngo
+const BALANCE: u128 = 1_000_000_000_000_000;
+/// Taken off the price by every discount rule
+const DISCOUNT: u128 = 4;
+/// Reference asset products are priced in
+const PRICE_ASSET: u128 = 1_000;
+/// Most pending payouts of a seller recalls are benchmarked with
+const MAX_PAYOUTS: u32 = 100;
+
+fn block<T: Config>(n: u32) -> T::BlockNumber {
+	n.into()
+}
+
+/// Creates the PBT of every category the way the chain spec does, unless it already exists.
+fn ensure_pbts<T: Config>() {
+	let admin: T::AccountId = <T as Config>::DonationPalletId::get().into_account_truncating();
+	for category in Category::all() {
+		let id = category.get_id() as u128;
+		if <T as Config>::TokenHandler::minimum_balance(id).is_zero() {
+			<T as Config>::TokenHandler::create(id, admin.clone(), true, 1).unwrap();
+		}
+	}
+}
+
+fn all_categories() -> AcceptedCategories {
+	Category::all().to_vec().try_into().unwrap()
+}
+
+/// Payment of `amount` split across every PBT category
+fn split_payment(amount: u128) -> Payment {
+	let categories = Category::all();
+	let part = amount / categories.len() as u128;
+	let mut parts: Vec<(Category, u128)> =
+		categories.iter().map(|category| (*category, part)).collect();
+	parts[0].1 += amount - part * categories.len() as u128;
+	parts.try_into().unwrap()
+}
+
+fn active_seller<T: Config>(index: u32) -> T::AccountId {
+	let seller: T::AccountId = account("seller", index, SEED);
+	let seller_info = SellerInfo {
+		categories: Category::all().to_vec().try_into().unwrap(),
+		cid: T::Hashing::hash_of(&seller),
+	};
+	participant_handler::pallet::Pallet::<T>::add_seller_to_active_list(&seller, seller_info);
+	seller
+}
+
+/// Active Ngo holding `BALANCE` PBT of every category.
+fn active_ngo<T: Config>(index: u32) -> Result<T::AccountId, BenchmarkError> {
+	let ngo: T::AccountId = account("ngo", index, SEED);
+	let ngo_info = NgoInfo {
+		categories: Category::all().to_vec().try_into().unwrap(),
+		cid: T::Hashing::hash_of(&ngo),
+	};
+	participant_handler::pallet::Pallet::<T>::add_ngo_to_active_list(&ngo, ngo_info);
+	ensure_pbts::<T>();
+	for category in Category::all() {
+		<T as Config>::TokenHandler::mint_into(category.get_id() as u128, &ngo, BALANCE)?;
+	}
+	Ok(ngo)
+}
+
+/// Product of `seller` accepting every PBT category.
+fn listed<T: Config>(seller: &T::AccountId) -> Result<T::Hash, BenchmarkError> {
+	let cid = T::Hashing::hash_of(seller);
+	let pid = Marketplace::<T>::do_list_product(
+		seller,
+		Category::Pharmaceutical,
+		all_categories(),
+		PRICE,
+		QUANTITY,
+		cid,
+	)?;
+	Ok(pid)
+}
+
+/// Order of a single unit of `pid`, paid in every PBT category.
+fn placed<T: Config>(ngo: &T::AccountId, pid: T::Hash) -> Result<OrderId, BenchmarkError> {
+	let (order_id, ..) = Marketplace::<T>::do_buy(ngo, pid, 1, split_payment(PRICE), None, None)?;
+	Ok(order_id)
+}
+
+/// `count` pending payouts of `seller`, each of `PRICE`.
+fn pending_payouts<T: Config>(seller: &T::AccountId, count: u32) -> Result<(), BenchmarkError> {
+	ensure_pbts::<T>();
+	let category = Category::Pharmaceutical;
+	<T as Config>::TokenHandler::mint_into(category.get_id() as u128, seller, BALANCE)?;
+	for _ in 0..count {
+		donation_handler::Pallet::<T>::do_claim(seller, &category, PRICE)?;
+	}
+	Ok(())
+}
 
-//TODO: Generate weights and replace default weights
+/// Order placed and disputed by the buyer, with a response of the seller.
+fn disputed<T: Config>() -> Result<OrderId, BenchmarkError> {
+	let seller = active_seller::<T>(0);
+	<T as participant_handler::Config>::Currency::make_free_balance_be(
+		&seller,
+		(2 * BALANCE).saturated_into(),
+	);
+	participant_handler::Pallet::<T>::reserve_seller_stake(&seller)?;
+	let ngo = active_ngo::<T>(0)?;
+	let order_id = placed::<T>(&ngo, listed::<T>(&seller)?)?;
+	let evidence = T::Hashing::hash_of(&order_id);
+	Marketplace::<T>::do_open_dispute(&ngo, order_id, evidence)?;
+	Marketplace::<T>::do_respond_to_dispute(&seller, order_id, evidence)?;
+	Ok(order_id)
+}
+
+/// Commission charged in every category, with every discount tier to go through, and the
+/// Native Token it is redeemed for.
+fn commission_schedule<T: Config>() {
+	let donation_account: T::AccountId =
+		<T as Config>::DonationPalletId::get().into_account_truncating();
+	<T as Config>::Currency::make_free_balance_be(&donation_account, BALANCE.saturated_into());
+	<T as Config>::Currency::make_free_balance_be(
+		&T::FeeReceiver::get(),
+		<T as Config>::Currency::minimum_balance(),
+	);
+	for category in Category::all() {
+		<CommissionRates<T>>::insert(category, Permill::from_percent(1));
+	}
+	let tiers: Vec<CommissionTier> = (0..MAX_COMMISSION_TIERS)
+		.map(|tier| CommissionTier {
+			min_volume: tier as u128,
+			min_score: 0,
+			discount: Permill::from_percent(tier),
+		})
+		.collect();
+	<CommissionDiscountTiers<T>>::put(CommissionTiers::try_from(tiers).unwrap());
+}
+
+/// Discount of `DISCOUNT` that only `buyer`, last of the allowed Ngos, can redeem.
+fn discount_rule<T: Config>(
+	buyer: &T::AccountId,
+) -> DiscountRule<T::AccountId, T::Hash, T::BlockNumber> {
+	let mut ngos: Vec<T::AccountId> =
+		(1..MAX_DISCOUNT_NGOS).map(|index| account("other", index, SEED)).collect();
+	ngos.push(buyer.clone());
+	DiscountRule {
+		kind: DiscountKind::FixedAmount(DISCOUNT),
+		coupon: None,
+		ngos: ngos.try_into().unwrap(),
+		categories: all_categories(),
+		starts_at: Zero::zero(),
+		ends_at: T::BlockNumber::max_value(),
+		max_redemptions: Some(u32::MAX),
+	}
+}
+
+fn tender<T: Config>(
+	ngo: &T::AccountId,
+	sealed: bool,
+) -> Tender<T::AccountId, T::Hash, T::BlockNumber> {
+	Tender {
+		ngo: ngo.clone(),
+		category: Category::Pharmaceutical,
+		quantity: QUANTITY,
+		max_price: PRICE,
+		deadline: frame_system::Pallet::<T>::block_number() + block::<T>(10),
+		spec: T::Hashing::hash_of(ngo),
+		sealed,
+		status: TenderStatus::Open,
+		bids: 0,
+	}
+}
+
+/// Fills `tender_id` up to `MAX_TENDER_BIDS` with bids from other sellers.
+fn fill_bids<T: Config>(tender_id: TenderId) {
+	let bids = <Tenders<T>>::get(tender_id).unwrap().bids;
+	for i in bids..MAX_TENDER_BIDS {
+		let bidder: T::AccountId = account("bidder", i, SEED);
+		<Bids<T>>::insert(tender_id, bidder, Bid { price: Some(PRICE), commitment: None });
+	}
+	<Tenders<T>>::mutate(tender_id, |tender| tender.as_mut().unwrap().bids = MAX_TENDER_BIDS);
+}
+
+fn auction<T: Config>(
+	owner: &T::AccountId,
+	kind: AuctionKind,
+) -> Auction<T::AccountId, T::Hash, T::BlockNumber> {
+	let start = frame_system::Pallet::<T>::block_number();
+	Auction {
+		owner: owner.clone(),
+		category: Category::Pharmaceutical,
+		quantity: QUANTITY,
+		kind,
+		cid: T::Hashing::hash_of(owner),
+		start,
+		end: start + block::<T>(10),
+		highest_bid: None,
+		status: AuctionStatus::Open,
+	}
+}
+
+fn english() -> AuctionKind {
+	AuctionKind::English { reserve_price: PRICE, min_increment: 1 }
+}
+
+/// Tiers halving the unit price from two units committed.
+fn price_tiers() -> PriceTiers {
+	vec![
+		PriceTier { min_quantity: 1, price: PRICE },
+		PriceTier { min_quantity: 2, price: PRICE / 2 },
+	]
+	.try_into()
+	.unwrap()
+}
+
+/// Group Buy of `owner` with two units committed by each of `participants` Ngos.
+fn group_buy<T: Config>(
+	owner: &T::AccountId,
+	participants: u32,
+) -> Result<GroupBuyId, BenchmarkError> {
+	let group_buy_id = Marketplace::<T>::do_create_group_buy(GroupBuy {
+		owner: owner.clone(),
+		category: Category::Pharmaceutical,
+		tiers: price_tiers(),
+		max_quantity: u32::MAX,
+		committed: 0,
+		participants: 0,
+		cid: T::Hashing::hash_of(owner),
+		deadline: frame_system::Pallet::<T>::block_number() + block::<T>(10),
+		status: GroupBuyStatus::Open,
+	})?;
+	for index in 0..participants {
+		let ngo = active_ngo::<T>(index)?;
+		Marketplace::<T>::do_commit_group_buy(&ngo, group_buy_id, 2)?;
+	}
+	Ok(group_buy_id)
+}
+
+/// Supply Contract proposed by `ngo` for a Product of `seller`, delivered every 10 blocks.
+fn proposed<T: Config>(
+	ngo: &T::AccountId,
+	seller: &T::AccountId,
+) -> Result<ContractId, BenchmarkError> {
+	let pid = listed::<T>(seller)?;
+	let (contract_id, _) =
+		Marketplace::<T>::do_propose_supply_contract(ngo, pid, 1, PRICE, block::<T>(10))?;
+	Ok(contract_id)
+}
 
 benchmarks! {
 	list_product {
-		let b in 1 .. 1000;
-		let seller: T::AccountId = account("seller", b, SEED);
-		// Add seller to Active List
-		let seller_info = SellerInfo {
-		category: Default::default(),
-		cid: T::Hashing::hash_of(&b)
-		};
-		participant_handler::pallet::Pallet::<T>::add_seller_to_active_list(&seller, seller_info);
-		let category = Category::Pharmaceutical;
-		let price = 1_000_000_000_u128;
-		let cid: T::Hash = T::Hashing::hash_of(&b);
-	}: _(RawOrigin::Signed(seller), category, price, cid)
+		let seller = active_seller::<T>(0);
+		let cid = T::Hashing::hash_of(&seller);
+	}: _(RawOrigin::Signed(seller.clone()), Category::Pharmaceutical, all_categories(), PRICE, QUANTITY, cid)
+	verify {
+		assert_eq!(<ProductsBySeller<T>>::iter_prefix(&seller).count(), 1);
+	}
 
+	// Worst case: the reference price is refreshed, every discount is checked and the payment
+	// is split across every category.
 	buy {
-		let b in 1 .. 1000;
-		let ngo: T::AccountId = account("recipient", b, SEED);
-		let ngo_info = NgoInfo {
-		categories: Default::default(),
-		cid: T::Hashing::hash_of(&b)
-		};
-		participant_handler::pallet::Pallet::<T>::add_ngo_to_active_list(&ngo, ngo_info);
-		let pid: T::Hash = T::Hashing::hash_of(&b);
-	}: _(RawOrigin::Signed(ngo), pid)
+		let d in 0 .. MAX_DISCOUNTS_PER_PRODUCT;
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let pid = listed::<T>(&seller)?;
+		T::BenchmarkHelper::create_price_source(PRICE_ASSET);
+		frame_system::Pallet::<T>::set_block_number(block::<T>(1));
+		Marketplace::<T>::do_update_reference_price(PRICE_ASSET)?;
+		Marketplace::<T>::do_update_listing(&seller, pid, |product| {
+			product.price_asset = Some(PRICE_ASSET)
+		})?;
+		for _ in 0..d {
+			Marketplace::<T>::do_add_discount(&seller, pid, discount_rule::<T>(&ngo))?;
+		}
+		frame_system::Pallet::<T>::set_block_number(
+			block::<T>(2) + T::TwapPeriod::get(),
+		);
+		let amount = if d.is_zero() { PRICE } else { PRICE - DISCOUNT };
+	}: _(RawOrigin::Signed(ngo), pid, 1, split_payment(amount), None, None)
+	verify {
+		assert!(<Orders<T>>::contains_key(0));
+	}
+
+	update_price {
+		let seller = active_seller::<T>(0);
+		let pid = listed::<T>(&seller)?;
+	}: _(RawOrigin::Signed(seller), pid, PRICE * 2)
+	verify {
+		assert_eq!(<ProductMap<T>>::get(pid).unwrap().price, PRICE * 2);
+	}
+
+	update_metadata {
+		let seller = active_seller::<T>(0);
+		let pid = listed::<T>(&seller)?;
+		let cid = T::Hashing::hash_of(&pid);
+	}: _(RawOrigin::Signed(seller), pid, cid)
+	verify {
+		assert_eq!(<ProductMap<T>>::get(pid).unwrap().cid, cid);
+	}
+
+	// Worst case: the product has been bought, its order settled, and is kept as delisted.
+	delist {
+		let d in 0 .. MAX_DISCOUNTS_PER_PRODUCT;
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let pid = listed::<T>(&seller)?;
+		let order_id = placed::<T>(&ngo, pid)?;
+		Marketplace::<T>::do_confirm_delivery(&ngo, order_id)?;
+		Marketplace::<T>::do_settle(order_id)?;
+		for _ in 0..d {
+			Marketplace::<T>::do_add_discount(&seller, pid, discount_rule::<T>(&ngo))?;
+		}
+	}: _(RawOrigin::Signed(seller), pid)
+	verify {
+		assert_eq!(<ProductMap<T>>::get(pid).unwrap().status, Status::Delisted);
+		assert_eq!(<Discounts<T>>::iter_prefix(pid).count(), 0);
+	}
+
+	restock {
+		let seller = active_seller::<T>(0);
+		let pid = listed::<T>(&seller)?;
+	}: _(RawOrigin::Signed(seller), pid, QUANTITY)
+	verify {
+		assert_eq!(<ProductMap<T>>::get(pid).unwrap().quantity, QUANTITY * 2);
+	}
+
+	mark_shipped {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let order_id = placed::<T>(&ngo, listed::<T>(&seller)?)?;
+		let tracking = T::Hashing::hash_of(&order_id);
+	}: _(RawOrigin::Signed(seller), order_id, tracking)
+	verify {
+		assert_eq!(<Orders<T>>::get(order_id).unwrap().status, OrderStatus::Shipped);
+	}
+
+	confirm_delivery {
+		commission_schedule::<T>();
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let order_id = placed::<T>(&ngo, listed::<T>(&seller)?)?;
+	}: _(RawOrigin::Signed(ngo), order_id)
+	verify {
+		assert_eq!(<Orders<T>>::get(order_id).unwrap().status, OrderStatus::Settled);
+	}
+
+	settle_order {
+		commission_schedule::<T>();
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let order_id = placed::<T>(&ngo, listed::<T>(&seller)?)?;
+		Marketplace::<T>::do_mark_shipped(&seller, order_id, T::Hashing::hash_of(&order_id))?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::DeliveryTimeout::get(),
+		);
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), order_id)
+	verify {
+		assert_eq!(<Orders<T>>::get(order_id).unwrap().status, OrderStatus::Settled);
+	}
+
+	open_dispute {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let order_id = placed::<T>(&ngo, listed::<T>(&seller)?)?;
+		let evidence = T::Hashing::hash_of(&order_id);
+	}: _(RawOrigin::Signed(ngo), order_id, evidence)
+	verify {
+		assert!(<Disputes<T>>::contains_key(order_id));
+	}
+
+	respond_to_dispute {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let order_id = placed::<T>(&ngo, listed::<T>(&seller)?)?;
+		let evidence = T::Hashing::hash_of(&order_id);
+		Marketplace::<T>::do_open_dispute(&ngo, order_id, evidence)?;
+	}: _(RawOrigin::Signed(seller), order_id, evidence)
+	verify {
+		assert_eq!(<Disputes<T>>::get(order_id).unwrap().response, Some(evidence));
+	}
+
+	// Worst case: the escrow is split between both parties and the seller is slashed.
+	resolve_dispute {
+		commission_schedule::<T>();
+		let order_id = disputed::<T>()?;
+		let origin = T::ArbitratorOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, order_id, Resolution::PartialRefund(PRICE / 2), Some(PRICE))
+	verify {
+		assert!(!<Disputes<T>>::contains_key(order_id));
+	}
+
+	// The seller responded, but the Arbitrator left the dispute unresolved.
+	expire_dispute {
+		let order_id = disputed::<T>()?;
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::DisputeTimeout::get(),
+		);
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), order_id)
+	verify {
+		assert!(!<Disputes<T>>::contains_key(order_id));
+		assert_eq!(<Orders<T>>::get(order_id).unwrap().status, OrderStatus::Refunded);
+	}
+
+	post_tender {
+		let ngo = active_ngo::<T>(0)?;
+		let deadline = frame_system::Pallet::<T>::block_number() + block::<T>(10);
+		let spec = T::Hashing::hash_of(&ngo);
+	}: _(RawOrigin::Signed(ngo), Category::Pharmaceutical, QUANTITY, PRICE, deadline, spec, true)
+	verify {
+		assert!(<Tenders<T>>::contains_key(0));
+	}
+
+	submit_bid {
+		let ngo = active_ngo::<T>(0)?;
+		let seller = active_seller::<T>(0);
+		let tender_id = Marketplace::<T>::do_post_tender(tender::<T>(&ngo, false))?;
+	}: _(RawOrigin::Signed(seller.clone()), tender_id, PRICE)
+	verify {
+		assert!(<Bids<T>>::contains_key(tender_id, &seller));
+	}
+
+	submit_sealed_bid {
+		let ngo = active_ngo::<T>(0)?;
+		let seller = active_seller::<T>(0);
+		let tender_id = Marketplace::<T>::do_post_tender(tender::<T>(&ngo, true))?;
+		let commitment = T::Hashing::hash_of(&seller);
+	}: _(RawOrigin::Signed(seller.clone()), tender_id, commitment)
+	verify {
+		assert!(<Bids<T>>::contains_key(tender_id, &seller));
+	}
+
+	reveal_bid {
+		let ngo = active_ngo::<T>(0)?;
+		let seller = active_seller::<T>(0);
+		let tender = tender::<T>(&ngo, true);
+		let deadline = tender.deadline;
+		let tender_id = Marketplace::<T>::do_post_tender(tender)?;
+		let salt = T::Hashing::hash_of(&tender_id);
+		let commitment = T::Hashing::hash_of(&(&seller, PRICE, salt));
+		Marketplace::<T>::submit_sealed_bid(
+			RawOrigin::Signed(seller.clone()).into(),
+			tender_id,
+			commitment,
+		)?;
+		frame_system::Pallet::<T>::set_block_number(deadline + block::<T>(1));
+	}: _(RawOrigin::Signed(seller.clone()), tender_id, PRICE, salt)
+	verify {
+		assert_eq!(<Bids<T>>::get(tender_id, &seller).unwrap().price, Some(PRICE));
+	}
+
+	award_tender {
+		let ngo = active_ngo::<T>(0)?;
+		let seller = active_seller::<T>(0);
+		let tender_id = Marketplace::<T>::do_post_tender(tender::<T>(&ngo, false))?;
+		Marketplace::<T>::submit_bid(RawOrigin::Signed(seller.clone()).into(), tender_id, PRICE)?;
+		fill_bids::<T>(tender_id);
+	}: _(RawOrigin::Signed(ngo), tender_id, seller)
+	verify {
+		assert_eq!(<Tenders<T>>::get(tender_id).unwrap().status, TenderStatus::Awarded);
+		assert_eq!(<Bids<T>>::iter_prefix(tender_id).count(), 0);
+	}
+
+	cancel_tender {
+		let ngo = active_ngo::<T>(0)?;
+		let tender_id = Marketplace::<T>::do_post_tender(tender::<T>(&ngo, false))?;
+		fill_bids::<T>(tender_id);
+	}: _(RawOrigin::Signed(ngo), tender_id)
+	verify {
+		assert_eq!(<Tenders<T>>::get(tender_id).unwrap().status, TenderStatus::Cancelled);
+		assert_eq!(<Bids<T>>::iter_prefix(tender_id).count(), 0);
+	}
+
+	// Worst case: the rating suspends the seller.
+	rate_order {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let order_id = placed::<T>(&ngo, listed::<T>(&seller)?)?;
+		Marketplace::<T>::do_confirm_delivery(&ngo, order_id)?;
+		Marketplace::<T>::do_settle(order_id)?;
+		let mut reputation = Reputation::default();
+		for _ in 0..T::MinRatingsForSuspension::get() {
+			reputation.add_rating(MIN_SCORE);
+		}
+		<SellerReputation<T>>::insert(&seller, reputation);
+		let review = Some(T::Hashing::hash_of(&order_id));
+	}: _(RawOrigin::Signed(ngo), order_id, MIN_SCORE, Some(MIN_SCORE), review)
+	verify {
+		assert!(<Ratings<T>>::contains_key(order_id));
+	}
+
+	buy_many {
+		let n in 1 .. MAX_CART_SIZE;
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let mut items = Vec::new();
+		for _ in 0..n {
+			items.push((listed::<T>(&seller)?, 1));
+		}
+		let items: Cart<T::Hash> = items.try_into().unwrap();
+	}: _(RawOrigin::Signed(ngo), items, u128::MAX)
+	verify {
+		assert_eq!(<NextOrderId<T>>::get(), n as OrderId);
+	}
+
+	set_price_asset {
+		let seller = active_seller::<T>(0);
+		let pid = listed::<T>(&seller)?;
+		T::BenchmarkHelper::create_price_source(PRICE_ASSET);
+	}: _(RawOrigin::Signed(seller), pid, Some(PRICE_ASSET))
+	verify {
+		assert_eq!(<ProductMap<T>>::get(pid).unwrap().price_asset, Some(PRICE_ASSET));
+	}
+
+	update_reference_price {
+		T::BenchmarkHelper::create_price_source(PRICE_ASSET);
+		frame_system::Pallet::<T>::set_block_number(block::<T>(1));
+		Marketplace::<T>::do_update_reference_price(PRICE_ASSET)?;
+		frame_system::Pallet::<T>::set_block_number(block::<T>(2) + T::TwapPeriod::get());
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), PRICE_ASSET)
+	verify {
+		assert!(<PriceObservations<T>>::get(PRICE_ASSET).unwrap().average.is_some());
+	}
+
+	// Worst case: a seller creates an Auction ending with the most Auctions of a block.
+	create_auction {
+		let seller = active_seller::<T>(0);
+		let duration = block::<T>(10);
+		let end = frame_system::Pallet::<T>::block_number() + duration;
+		let ending: Vec<AuctionId> =
+			(0..T::MaxAuctionsPerBlock::get().saturating_sub(1) as AuctionId).collect();
+		<AuctionsEndingAt<T>>::insert(end, BoundedVec::try_from(ending).unwrap());
+		let cid = T::Hashing::hash_of(&seller);
+	}: _(RawOrigin::Signed(seller), Category::Pharmaceutical, QUANTITY, english(), duration, cid)
+	verify {
+		assert!(<Auctions<T>>::contains_key(0));
+	}
+
+	// Worst case: the bid wins a Dutch Auction, which is settled right away.
+	bid_auction {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let kind = AuctionKind::Dutch { start_price: PRICE * 2, floor_price: PRICE };
+		let auction_id = Marketplace::<T>::do_create_auction(auction::<T>(&seller, kind))?;
+	}: _(RawOrigin::Signed(ngo), auction_id, PRICE * 2)
+	verify {
+		assert_eq!(<Auctions<T>>::get(auction_id).unwrap().status, AuctionStatus::Settled);
+	}
+
+	settle_auction {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let auction = auction::<T>(&seller, english());
+		let end = auction.end;
+		let auction_id = Marketplace::<T>::do_create_auction(auction)?;
+		Marketplace::<T>::do_bid_auction(&ngo, auction_id, PRICE)?;
+		frame_system::Pallet::<T>::set_block_number(end);
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), auction_id)
+	verify {
+		assert_eq!(<Auctions<T>>::get(auction_id).unwrap().status, AuctionStatus::Settled);
+	}
+
+	cancel_auction {
+		let seller = active_seller::<T>(0);
+		let auction_id = Marketplace::<T>::do_create_auction(auction::<T>(&seller, english()))?;
+	}: _(RawOrigin::Signed(seller), auction_id)
+	verify {
+		assert_eq!(<Auctions<T>>::get(auction_id).unwrap().status, AuctionStatus::Cancelled);
+	}
+
+	set_expiry {
+		let seller = active_seller::<T>(0);
+		let pid = listed::<T>(&seller)?;
+		let expires_at = frame_system::Pallet::<T>::block_number() + block::<T>(10);
+	}: _(RawOrigin::Signed(seller), pid, Some(expires_at))
+	verify {
+		assert_eq!(<ProductExpiry<T>>::get(pid), Some(expires_at));
+	}
+
+	recall_product {
+		let o in 0 .. QUANTITY;
+		let p in 0 .. MAX_PAYOUTS;
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let pid = listed::<T>(&seller)?;
+		for _ in 0..o {
+			placed::<T>(&ngo, pid)?;
+		}
+		pending_payouts::<T>(&seller, p)?;
+		let reason = T::Hashing::hash_of(&pid);
+		let origin = T::RecallOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, pid, reason, true, o, p)
+	verify {
+		assert_eq!(<ProductMap<T>>::get(pid).unwrap().status, Status::Recalled);
+	}
+
+	set_commission_rate {
+		let origin = T::CommissionOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		let rate = Permill::from_percent(1);
+	}: _<T::RuntimeOrigin>(origin, Category::Pharmaceutical, rate)
+	verify {
+		assert_eq!(<CommissionRates<T>>::get(Category::Pharmaceutical), rate);
+	}
+
+	set_commission_tiers {
+		let tiers: CommissionTiers = (0..MAX_COMMISSION_TIERS)
+			.map(|tier| CommissionTier {
+				min_volume: tier as u128,
+				min_score: 0,
+				discount: Permill::from_percent(tier),
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let origin = T::CommissionOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, tiers.clone())
+	verify {
+		assert_eq!(<CommissionDiscountTiers<T>>::get(), tiers);
+	}
+
+	create_group_buy {
+		let seller = active_seller::<T>(0);
+		let tiers: PriceTiers = (0..MAX_PRICE_TIERS)
+			.map(|tier| PriceTier { min_quantity: tier + 1, price: PRICE - tier as u128 })
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let cid = T::Hashing::hash_of(&seller);
+	}: _(RawOrigin::Signed(seller), Category::Pharmaceutical, tiers, QUANTITY, block::<T>(10), cid)
+	verify {
+		assert!(<GroupBuys<T>>::contains_key(0));
+	}
+
+	commit_group_buy {
+		let seller = active_seller::<T>(0);
+		let group_buy_id = group_buy::<T>(&seller, 0)?;
+		let ngo = active_ngo::<T>(0)?;
+	}: _(RawOrigin::Signed(ngo.clone()), group_buy_id, 2)
+	verify {
+		assert!(<GroupCommitments<T>>::contains_key(group_buy_id, &ngo));
+	}
+
+	// Worst case: the cheaper tier is reached, every Ngo is refunded the difference.
+	close_group_buy {
+		let p in 1 .. T::MaxGroupBuyParticipants::get();
+		let seller = active_seller::<T>(0);
+		let group_buy_id = group_buy::<T>(&seller, p)?;
+		let deadline = <GroupBuys<T>>::get(group_buy_id).unwrap().deadline;
+		frame_system::Pallet::<T>::set_block_number(deadline);
+		let caller: T::AccountId = account("caller", 0, SEED);
+	}: _(RawOrigin::Signed(caller), group_buy_id)
+	verify {
+		assert_eq!(<GroupBuys<T>>::get(group_buy_id).unwrap().status, GroupBuyStatus::Executed);
+	}
+
+	cancel_group_buy {
+		let p in 0 .. T::MaxGroupBuyParticipants::get();
+		let seller = active_seller::<T>(0);
+		let group_buy_id = group_buy::<T>(&seller, p)?;
+	}: _(RawOrigin::Signed(seller), group_buy_id)
+	verify {
+		assert_eq!(<GroupBuys<T>>::get(group_buy_id).unwrap().status, GroupBuyStatus::Cancelled);
+	}
+
+	propose_supply_contract {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let pid = listed::<T>(&seller)?;
+	}: _(RawOrigin::Signed(ngo), pid, 1, PRICE, block::<T>(10))
+	verify {
+		assert!(<SupplyContracts<T>>::contains_key(0));
+	}
+
+	// Worst case: the first delivery is due with the most deliveries of a block.
+	accept_supply_contract {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let contract_id = proposed::<T>(&ngo, &seller)?;
+		let due = frame_system::Pallet::<T>::block_number() + block::<T>(10);
+		let filler: Vec<ContractId> = (1..T::MaxContractsPerBlock::get() as ContractId).collect();
+		<ContractsDueAt<T>>::insert(due, BoundedVec::try_from(filler).unwrap());
+	}: _(RawOrigin::Signed(seller), contract_id)
+	verify {
+		assert_eq!(
+			<SupplyContracts<T>>::get(contract_id).unwrap().status,
+			ContractStatus::Active
+		);
+	}
+
+	terminate_supply_contract {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let contract_id = proposed::<T>(&ngo, &seller)?;
+		Marketplace::<T>::do_accept_supply_contract(&seller, contract_id)?;
+	}: _(RawOrigin::Signed(ngo), contract_id)
+	verify {
+		assert!(<SupplyContracts<T>>::get(contract_id).unwrap().ends_at.is_some());
+	}
+
+	add_discount {
+		let d in 0 .. MAX_DISCOUNTS_PER_PRODUCT - 1;
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let pid = listed::<T>(&seller)?;
+		for _ in 0..d {
+			Marketplace::<T>::do_add_discount(&seller, pid, discount_rule::<T>(&ngo))?;
+		}
+	}: _(RawOrigin::Signed(seller), pid, discount_rule::<T>(&ngo))
+	verify {
+		assert_eq!(<Discounts<T>>::iter_prefix(pid).count() as u32, d + 1);
+	}
+
+	remove_discount {
+		let seller = active_seller::<T>(0);
+		let ngo = active_ngo::<T>(0)?;
+		let pid = listed::<T>(&seller)?;
+		let discount_id = Marketplace::<T>::do_add_discount(&seller, pid, discount_rule::<T>(&ngo))?;
+	}: _(RawOrigin::Signed(seller), pid, discount_id)
+	verify {
+		assert!(!<Discounts<T>>::contains_key(pid, discount_id));
+	}
+
+	// Worst case: every Auction has a bid and no Supply Contract delivery was shipped, each
+	// contract starts its next cycle after every block it could spill over to but the last.
+	on_initialize {
+		let a in 0 .. T::MaxAuctionsPerBlock::get();
+		let c in 0 .. T::MaxContractsPerBlock::get();
+		let ngo = active_ngo::<T>(0)?;
+		let owner = active_seller::<T>(0);
+		let mut now = frame_system::Pallet::<T>::block_number();
+		for _ in 0..a {
+			let auction = auction::<T>(&owner, english());
+			now = auction.end;
+			let auction_id = Marketplace::<T>::do_create_auction(auction)?;
+			Marketplace::<T>::do_bid_auction(&ngo, auction_id, PRICE)?;
+		}
+		let mut period = None;
+		for index in 0..c {
+			let seller = active_seller::<T>(index);
+			let contract_id = proposed::<T>(&ngo, &seller)?;
+			Marketplace::<T>::do_accept_supply_contract(&seller, contract_id)?;
+			let contract = <SupplyContracts<T>>::get(contract_id).unwrap();
+			now = contract.next_due;
+			period = Some(contract.period);
+		}
+		if let Some(period) = period {
+			let full: Vec<ContractId> = (0..T::MaxContractsPerBlock::get())
+				.map(|i| ContractId::MAX - i as ContractId)
+				.collect();
+			let full = BoundedVec::<_, T::MaxContractsPerBlock>::try_from(full).unwrap();
+			for offset in 0..MAX_DUE_SPILLOVER {
+				<ContractsDueAt<T>>::insert(now + period + block::<T>(offset), full.clone());
+			}
+		}
+		frame_system::Pallet::<T>::set_block_number(now);
+	}: {
+		Marketplace::<T>::on_initialize(now);
+	}
+	verify {
+		assert!(<AuctionsEndingAt<T>>::get(now).is_empty());
+		assert!(<ContractsDueAt<T>>::get(now).is_empty());
+	}
+
+	impl_benchmark_test_suite!(Marketplace, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -10,14 +10,23 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
+	use crate::WeightInfo;
+	use donation_handler::{PayoutId, PendingPayout};
 	use frame_support::{
 		pallet_prelude::*,
 		sp_runtime::{
-			traits::Hash
+			traits::{
+				AccountIdConversion, CheckedAdd, CheckedDiv, CheckedMul, Hash, Saturating, Zero,
+			},
+			FixedPointNumber, FixedU128, Perbill, Permill, SaturatedConversion,
 		},
+		sp_std::vec::Vec,
 		traits::{
 			fungibles::{Create, Inspect, Mutate, Transfer},
 			Currency, ExistenceRequirement,
@@ -26,35 +35,487 @@ pub mod pallet {
 	};
 	use frame_system::pallet_prelude::*;
 	use participant_handler::Category;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use support::AMM;
 
-	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo)]
+	/// Most PBT categories a product accepts or a purchase is split across
+	pub const MAX_PAYMENT_CATEGORIES: u32 = 4;
+
+	pub type AcceptedCategories = BoundedVec<Category, ConstU32<MAX_PAYMENT_CATEGORIES>>;
+
+	/// PBT amount paid in each Category
+	pub type Payment = BoundedVec<(Category, u128), ConstU32<MAX_PAYMENT_CATEGORIES>>;
+
+	/// Most products that can be bought in a single checkout
+	pub const MAX_CART_SIZE: u32 = 50;
+
+	/// Products and quantities bought together in a single checkout
+	pub type Cart<Hash> = BoundedVec<(Hash, u32), ConstU32<MAX_CART_SIZE>>;
+
+	/// Most discount tiers of the commission schedule
+	pub const MAX_COMMISSION_TIERS: u32 = 8;
+
+	/// Commission discount of sellers with enough settled volume and a good reputation
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct CommissionTier {
+		/// PBT a seller must have settled across all categories
+		pub min_volume: u128,
+		/// Reputation score a seller must reach, see [`Reputation::score`]
+		pub min_score: u32,
+		/// Share of the commission waived
+		pub discount: Permill,
+	}
+
+	pub type CommissionTiers = BoundedVec<CommissionTier, ConstU32<MAX_COMMISSION_TIERS>>;
+
+	#[derive(Encode, Decode, Clone, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
 	pub struct ProductInfo<AccountId, Hash> {
 		pub category: Category,
+		/// Categories whose PBT is accepted as payment, always includes `category`
+		pub accepted_categories: AcceptedCategories,
+		/// Price of a single unit, in `price_asset` if set or in PBT otherwise
 		pub price: u128,
+		/// Reference asset the price is denominated in
+		pub price_asset: Option<u128>,
+		/// Units still available for sale
+		pub quantity: u32,
 		pub status: Status,
 		pub owner: AccountId,
 		pub cid: Hash,
 	}
 
 	impl<AccountId, Hash> ProductInfo<AccountId, Hash> {
-		pub fn new(category: Category, price: u128, owner: AccountId, cid: Hash) -> Self {
-			Self { category, price, status: Status::OpenForSell, owner, cid }
+		pub fn new(
+			category: Category,
+			accepted_categories: AcceptedCategories,
+			price: u128,
+			quantity: u32,
+			owner: AccountId,
+			cid: Hash,
+		) -> Self {
+			Self {
+				category,
+				accepted_categories,
+				price,
+				price_asset: None,
+				quantity,
+				status: Status::OpenForSell,
+				owner,
+				cid,
+			}
+		}
+
+		/// Take `quantity` units out of stock, closing the listing once it runs out.
+		pub fn take_stock(&mut self, quantity: u32) {
+			self.quantity = self.quantity.saturating_sub(quantity);
+			if self.quantity.is_zero() {
+				self.status = Status::Sold;
+			}
 		}
 
-		pub fn update_info(&mut self, buyer: AccountId) {
-			self.status = Status::Sold;
-			self.owner = buyer;
+		/// Add `quantity` units to stock, reopening the listing if it was sold out.
+		pub fn add_stock(&mut self, quantity: u32) {
+			self.quantity = self.quantity.saturating_add(quantity);
+			self.status = Status::OpenForSell;
 		}
 	}
 
-	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo)]
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	pub enum Status {
 		Sold,
 		OpenForSell,
+		/// Withdrawn by its seller or governance, it can no longer be bought
+		Recalled,
+		/// Delisted by its seller after being bought, kept for its Orders
+		Delisted,
+	}
+
+	pub type OrderId = u64;
+
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum OrderStatus {
+		/// PBT is held in escrow, waiting for the seller to ship
+		Placed,
+		/// Seller has shipped the order
+		Shipped,
+		/// Buyer has confirmed the delivery
+		Delivered,
+		/// Escrowed PBT has been released to the seller
+		Settled,
+		/// Buyer has opened a dispute, escrowed PBT is frozen until it is resolved
+		Disputed,
+		/// Escrowed PBT has been returned to the buyer
+		Refunded,
+	}
+
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct Dispute<Hash, BlockNumber> {
+		/// Content Id of the buyer's evidence on IPFS
+		pub evidence: Hash,
+		/// Content Id of the seller's response on IPFS
+		pub response: Option<Hash>,
+		pub opened_at: BlockNumber,
+	}
+
+	pub type TenderId = u64;
+
+	/// Most sellers bidding on a single Tender
+	pub const MAX_TENDER_BIDS: u32 = 100;
+
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub enum TenderStatus {
+		/// Accepting bids, or waiting to be awarded
+		Open,
+		/// Awarded to a seller, an Order has been placed
+		Awarded,
+		/// Cancelled by the Ngo
+		Cancelled,
+	}
+
+	/// Request for quote posted by an Ngo
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct Tender<AccountId, Hash, BlockNumber> {
+		pub ngo: AccountId,
+		pub category: Category,
+		pub quantity: u32,
+		/// Highest acceptable price of a single unit
+		pub max_price: u128,
+		/// Last block at which bids are accepted
+		pub deadline: BlockNumber,
+		/// Content Id of the specification on IPFS
+		pub spec: Hash,
+		/// Bids are submitted as commitments and revealed after the deadline
+		pub sealed: bool,
+		pub status: TenderStatus,
+		/// Number of sellers who have bid
+		pub bids: u32,
+	}
+
+	/// Bid on a Tender, a sealed bid only carries its price once revealed
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct Bid<Hash> {
+		/// Price of a single unit
+		pub price: Option<u128>,
+		/// Hash of `(seller, price, salt)` for sealed bids
+		pub commitment: Option<Hash>,
+	}
+
+	pub type AuctionId = u64;
+
+	/// How the price of an Auction is discovered, prices are for the whole lot
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub enum AuctionKind {
+		/// Ascending bids, the highest bid wins when the Auction ends
+		English {
+			/// Lowest acceptable first bid
+			reserve_price: u128,
+			/// Lowest raise over the highest bid
+			min_increment: u128,
+		},
+		/// Price falls linearly from `start_price` to `floor_price`, the first bid wins
+		Dutch { start_price: u128, floor_price: u128 },
+	}
+
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub enum AuctionStatus {
+		/// Accepting bids until the end block
+		Open,
+		/// Won by a bidder, an Order has been placed
+		Settled,
+		/// Ended without bids
+		Unsold,
+		/// Cancelled by the owner before any bid
+		Cancelled,
+	}
+
+	/// Lot auctioned by a seller or by an Ngo reselling surplus
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct Auction<AccountId, Hash, BlockNumber> {
+		pub owner: AccountId,
+		pub category: Category,
+		pub quantity: u32,
+		pub kind: AuctionKind,
+		/// Content Id of the lot details on IPFS
+		pub cid: Hash,
+		pub start: BlockNumber,
+		/// Block at which bidding closes and the Auction is settled
+		pub end: BlockNumber,
+		/// Highest bidder and their bid, held in escrow
+		pub highest_bid: Option<(AccountId, u128)>,
+		pub status: AuctionStatus,
+	}
+
+	pub type GroupBuyId = u64;
+
+	/// Most volume tiers of a Group Buy
+	pub const MAX_PRICE_TIERS: u32 = 8;
+
+	/// Unit price of a Group Buy once at least `min_quantity` units are committed
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct PriceTier {
+		pub min_quantity: u32,
+		pub price: u128,
+	}
+
+	/// Tiers ordered by increasing quantity and decreasing price, the first one is the
+	/// threshold a Group Buy must reach to execute
+	pub type PriceTiers = BoundedVec<PriceTier, ConstU32<MAX_PRICE_TIERS>>;
+
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub enum GroupBuyStatus {
+		/// Accepting commitments until the deadline
+		Open,
+		/// Threshold reached, an Order has been placed for each Ngo's share
+		Executed,
+		/// Threshold not reached by the deadline, commitments have been refunded
+		Failed,
+		/// Cancelled by the seller, commitments have been refunded
+		Cancelled,
+	}
+
+	/// Volume-tier offer of a seller that Ngos pool their orders into
+	#[derive(Encode, Decode, Clone, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct GroupBuy<AccountId, Hash, BlockNumber> {
+		pub owner: AccountId,
+		pub category: Category,
+		pub tiers: PriceTiers,
+		/// Most units the seller can deliver
+		pub max_quantity: u32,
+		/// Units committed so far
+		pub committed: u32,
+		/// Ngos that committed so far
+		pub participants: u32,
+		/// Content Id of the offer details on IPFS
+		pub cid: Hash,
+		/// Block from which commitments are closed and the Group Buy can be executed
+		pub deadline: BlockNumber,
+		pub status: GroupBuyStatus,
+	}
+
+	/// Share of a Group Buy committed by an Ngo
+	#[derive(
+		Encode, Decode, Clone, Copy, Debug, Default, MaxEncodedLen, TypeInfo, PartialEq, Eq,
+	)]
+	pub struct GroupCommitment {
+		pub quantity: u32,
+		/// PBT held in escrow, at the price of the first tier
+		pub amount: u128,
+		/// Order placed for this share once the Group Buy is executed
+		pub order_id: Option<OrderId>,
+	}
+
+	pub type ContractId = u64;
+
+	/// Most blocks a Supply Contract delivery is pushed back when its due block is full
+	pub const MAX_DUE_SPILLOVER: u32 = 10;
+
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub enum ContractStatus {
+		/// Proposed by the Ngo, waiting for the seller to accept
+		Proposed,
+		/// A delivery is due every period
+		Active,
+		/// No further delivery is due
+		Terminated,
+	}
+
+	/// Standing agreement of a seller to deliver a Product to an Ngo every period
+	#[derive(Encode, Decode, Clone, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct SupplyContract<AccountId, Hash, BlockNumber> {
+		pub ngo: AccountId,
+		pub seller: AccountId,
+		pub pid: Hash,
+		pub category: Category,
+		/// Units per delivery
+		pub quantity: u32,
+		/// Agreed price of a single unit, in PBT of `category`
+		pub price: u128,
+		/// Blocks between deliveries
+		pub period: BlockNumber,
+		pub status: ContractStatus,
+		/// Order of the current cycle, its PBT is held in escrow
+		pub current_order: Option<OrderId>,
+		/// Block at which the current delivery is due and the next cycle starts
+		pub next_due: BlockNumber,
+		/// Block from which no new cycle starts, set once either party gives notice
+		pub ends_at: Option<BlockNumber>,
+		/// Cycles whose order was not shipped by its due block
+		pub missed: u32,
+	}
+
+	pub type DiscountId = u64;
+
+	/// Most discount rules of a single Product
+	pub const MAX_DISCOUNTS_PER_PRODUCT: u32 = 8;
+
+	/// Most Ngos a discount can be restricted to
+	pub const MAX_DISCOUNT_NGOS: u32 = 32;
+
+	/// Longest coupon code
+	pub const MAX_COUPON_LENGTH: u32 = 32;
+
+	pub type CouponCode = BoundedVec<u8, ConstU32<MAX_COUPON_LENGTH>>;
+
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub enum DiscountKind {
+		/// Share of the total price taken off
+		Percentage(Permill),
+		/// PBT taken off the total price
+		FixedAmount(u128),
+	}
+
+	/// Discount a seller offers on a Product to some or all Ngos
+	#[derive(Encode, Decode, Clone, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct DiscountRule<AccountId, Hash, BlockNumber> {
+		pub kind: DiscountKind,
+		/// Hash of the coupon code a buyer has to present, `None` applies it automatically.
+		/// Codes are sent in the clear, so they are public once redeemed and a coupon has to be
+		/// restricted to some Ngos or Ngo categories.
+		pub coupon: Option<Hash>,
+		/// Ngos allowed to redeem it, empty allows every Ngo
+		pub ngos: BoundedVec<AccountId, ConstU32<MAX_DISCOUNT_NGOS>>,
+		/// Ngo categories allowed to redeem it, empty allows every Ngo
+		pub categories: AcceptedCategories,
+		/// First block it can be redeemed in
+		pub starts_at: BlockNumber,
+		/// Block from which it can no longer be redeemed
+		pub ends_at: BlockNumber,
+		pub max_redemptions: Option<u32>,
+	}
+
+	impl DiscountKind {
+		/// PBT taken off `amount`
+		pub fn discount(&self, amount: u128) -> u128 {
+			match self {
+				DiscountKind::Percentage(share) => share.mul_floor(amount),
+				DiscountKind::FixedAmount(off) => (*off).min(amount),
+			}
+		}
+	}
+
+	/// Discount taken off the price of an Order
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct AppliedDiscount {
+		pub discount_id: DiscountId,
+		pub amount: u128,
+	}
+
+	/// Snapshot of the cumulative price of a reference asset in native tokens
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct PriceObservation<BlockNumber> {
+		/// Cumulative price as the inner value of a `FixedU128`
+		pub cumulative: u128,
+		pub block: BlockNumber,
+		/// Time weighted average price between the previous and this snapshot, as the inner
+		/// value of a `FixedU128`
+		pub average: Option<u128>,
+	}
+
+	/// Lowest and highest score of a rating
+	pub const MIN_SCORE: u8 = 1;
+	pub const MAX_SCORE: u8 = 5;
+
+	/// Rating left by the buyer of a completed Order
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct Rating<Hash> {
+		pub seller_score: u8,
+		/// Only Orders bought from a listing can rate the Product
+		pub product_score: Option<u8>,
+		/// Content Id of the written review on IPFS
+		pub review: Option<Hash>,
 	}
 
+	/// Aggregated ratings of a seller or a product
+	#[derive(
+		Encode, Decode, Clone, Copy, Debug, Default, MaxEncodedLen, TypeInfo, PartialEq, Eq,
+	)]
+	pub struct Reputation {
+		pub ratings: u32,
+		pub total: u64,
+		/// Average score multiplied by 100, e.g. 450 is an average of 4.5
+		pub score: u32,
+	}
+
+	impl Reputation {
+		pub fn add_rating(&mut self, score: u8) {
+			self.ratings = self.ratings.saturating_add(1);
+			self.total = self.total.saturating_add(score as u64);
+			self.score = (self.total.saturating_mul(100) / self.ratings as u64) as u32;
+		}
+	}
+
+	/// Decision of an arbitrator on a disputed Order
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub enum Resolution {
+		/// Return all escrowed PBT to the buyer
+		Refund,
+		/// Return the given amount to the buyer and release the rest to the seller
+		PartialRefund(u128),
+		/// Release all escrowed PBT to the seller
+		Release,
+	}
+
+	/// Where an Order originated from
+	#[derive(Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	pub enum OrderSource<Hash> {
+		/// Bought from the listed Product with the given id
+		Listing(Hash),
+		/// Awarded from the Tender with the given id
+		Tender(TenderId),
+		/// Won in the Auction with the given id
+		Auction(AuctionId),
+		/// Share of the Group Buy with the given id
+		GroupBuy(GroupBuyId),
+		/// Cycle of the Supply Contract with the given id
+		SupplyContract(ContractId),
+	}
+
+	#[derive(Encode, Decode, Clone, Debug, MaxEncodedLen, TypeInfo, PartialEq, Eq)]
+	pub struct Order<AccountId, Hash, BlockNumber> {
+		pub source: OrderSource<Hash>,
+		pub buyer: AccountId,
+		pub seller: AccountId,
+		pub quantity: u32,
+		/// Total PBT held in escrow for this order
+		pub amount: u128,
+		/// Split of `amount` across PBT categories
+		pub payment: Payment,
+		pub status: OrderStatus,
+		/// Content Id of the shipment tracking details on IPFS
+		pub tracking: Option<Hash>,
+		pub placed_at: BlockNumber,
+		pub shipped_at: Option<BlockNumber>,
+	}
+
+	/// Order placed by a purchase, the stock left and the discount applied
+	pub type PurchaseOf<T> = (
+		OrderId,
+		Order<
+			<T as frame_system::Config>::AccountId,
+			<T as frame_system::Config>::Hash,
+			<T as frame_system::Config>::BlockNumber,
+		>,
+		u32,
+		Option<AppliedDiscount>,
+	);
+
+	/// Sets up state the benchmarks cannot create through this pallet
+	#[cfg(feature = "runtime-benchmarks")]
+	pub trait BenchmarkHelper {
+		/// Open an AMM pool pricing `asset` against the native token
+		fn create_price_source(asset: u128);
+	}
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -74,6 +535,55 @@ pub mod pallet {
 		/// Donation Handler Pallet Id
 		#[pallet::constant]
 		type DonationPalletId: Get<PalletId>;
+		/// Marketplace Pallet Id, its account holds PBT of open orders in escrow
+		#[pallet::constant]
+		type MarketplacePalletId: Get<PalletId>;
+		/// Blocks after shipment after which an unconfirmed order can be settled
+		#[pallet::constant]
+		type DeliveryTimeout: Get<Self::BlockNumber>;
+		/// Origin allowed to resolve disputes
+		type ArbitratorOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Origin allowed to recall any Product
+		type RecallOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Blocks after which an unresolved dispute can be expired by anyone
+		#[pallet::constant]
+		type DisputeTimeout: Get<Self::BlockNumber>;
+		/// Ratings a seller needs before it can be suspended for a poor reputation
+		#[pallet::constant]
+		type MinRatingsForSuspension: Get<u32>;
+		/// Reputation score below which a seller is suspended, see [`Reputation::score`]
+		#[pallet::constant]
+		type SuspensionScoreThreshold: Get<u32>;
+		/// AMM whose pools price reference assets against the native token
+		type AMM: AMM<Self::AccountId, u128, u128, Self::BlockNumber>;
+		/// Native Currency Id, PBT is pegged 1:1 to it
+		#[pallet::constant]
+		type NativeCurrencyId: Get<u128>;
+		/// Shortest window of the time weighted average price of a reference asset
+		#[pallet::constant]
+		type TwapPeriod: Get<Self::BlockNumber>;
+		/// Most Auctions ending in the same block
+		#[pallet::constant]
+		type MaxAuctionsPerBlock: Get<u32>;
+		/// Account receiving the commission taken on settled Orders, redeemed for Native Token
+		#[pallet::constant]
+		type FeeReceiver: Get<Self::AccountId>;
+		/// Origin allowed to update the commission schedule
+		type CommissionOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Most Ngos committing to a single Group Buy
+		#[pallet::constant]
+		type MaxGroupBuyParticipants: Get<u32>;
+		/// Most Supply Contract deliveries due in the same block
+		#[pallet::constant]
+		type MaxContractsPerBlock: Get<u32>;
+		/// Blocks between a party giving notice and the end of a Supply Contract
+		#[pallet::constant]
+		type ContractNoticePeriod: Get<Self::BlockNumber>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+		/// Creates the AMM pools the benchmarks price reference assets with
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
 	}
 
 	/// Product List
@@ -87,15 +597,546 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Nonce hashed into every product id so that identical listings get unique ids
+	#[pallet::storage]
+	#[pallet::getter(fn get_product_nonce)]
+	pub(super) type ProductNonce<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Orders
+	#[pallet::storage]
+	#[pallet::getter(fn get_order)]
+	pub(super) type Orders<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		OrderId,
+		Order<T::AccountId, T::Hash, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_order_id)]
+	pub(super) type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	/// Orders placed by a buyer
+	#[pallet::storage]
+	pub(super) type OrdersByBuyer<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		OrderId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Orders received by a seller
+	#[pallet::storage]
+	pub(super) type OrdersBySeller<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		OrderId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Disputes on Orders
+	#[pallet::storage]
+	#[pallet::getter(fn get_dispute)]
+	pub(super) type Disputes<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderId, Dispute<T::Hash, T::BlockNumber>, OptionQuery>;
+
+	/// Tenders
+	#[pallet::storage]
+	#[pallet::getter(fn get_tender)]
+	pub(super) type Tenders<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		TenderId,
+		Tender<T::AccountId, T::Hash, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_tender_id)]
+	pub(super) type NextTenderId<T: Config> = StorageValue<_, TenderId, ValueQuery>;
+
+	/// Bids on Tenders
+	#[pallet::storage]
+	#[pallet::getter(fn get_bid)]
+	pub(super) type Bids<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		TenderId,
+		Blake2_128Concat,
+		T::AccountId,
+		Bid<T::Hash>,
+		OptionQuery,
+	>;
+
+	/// Ratings of completed Orders
+	#[pallet::storage]
+	#[pallet::getter(fn get_rating)]
+	pub(super) type Ratings<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderId, Rating<T::Hash>, OptionQuery>;
+
+	/// Reputation of sellers
+	#[pallet::storage]
+	#[pallet::getter(fn get_seller_reputation)]
+	pub(super) type SellerReputation<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Reputation, ValueQuery>;
+
+	/// Reputation of products
+	#[pallet::storage]
+	#[pallet::getter(fn get_product_reputation)]
+	pub(super) type ProductReputation<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, Reputation, ValueQuery>;
+
+	/// Latest price snapshot of each reference asset
+	#[pallet::storage]
+	#[pallet::getter(fn get_price_observation)]
+	pub(super) type PriceObservations<T: Config> =
+		StorageMap<_, Blake2_128Concat, u128, PriceObservation<T::BlockNumber>, OptionQuery>;
+
+	/// Open listings by their primary category
+	#[pallet::storage]
+	pub(super) type ProductsByCategory<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, Category, Blake2_128Concat, T::Hash, (), OptionQuery>;
+
+	/// Listed products by seller
+	#[pallet::storage]
+	pub(super) type ProductsBySeller<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::Hash,
+		(),
+		OptionQuery,
+	>;
+
+	/// Products bought by each buyer, kept after delisting as purchase history
+	#[pallet::storage]
+	pub(super) type ProductsByBuyer<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::Hash,
+		(),
+		OptionQuery,
+	>;
+
+	/// Orders placed for each listed product
+	#[pallet::storage]
+	pub(super) type OrdersByProduct<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::Hash, Blake2_128Concat, OrderId, (), OptionQuery>;
+
+	/// Number of Orders of each listed product still holding PBT in escrow
+	#[pallet::storage]
+	#[pallet::getter(fn get_open_orders)]
+	pub(super) type OpenOrdersByProduct<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, u32, ValueQuery>;
+
+	/// Block from which a product can no longer be bought
+	#[pallet::storage]
+	#[pallet::getter(fn get_product_expiry)]
+	pub(super) type ProductExpiry<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::Hash, T::BlockNumber, OptionQuery>;
+
+	/// Auctions
+	#[pallet::storage]
+	#[pallet::getter(fn get_auction)]
+	pub(super) type Auctions<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		AuctionId,
+		Auction<T::AccountId, T::Hash, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_auction_id)]
+	pub(super) type NextAuctionId<T: Config> = StorageValue<_, AuctionId, ValueQuery>;
+
+	/// Auctions to settle at the start of each block
+	#[pallet::storage]
+	#[pallet::getter(fn get_auctions_ending_at)]
+	pub(super) type AuctionsEndingAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<AuctionId, T::MaxAuctionsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Share of each settled payment taken as commission, per PBT category
+	#[pallet::storage]
+	#[pallet::getter(fn get_commission_rate)]
+	pub(super) type CommissionRates<T: Config> =
+		StorageMap<_, Blake2_128Concat, Category, Permill, ValueQuery>;
+
+	/// Commission discount tiers, the largest discount a seller qualifies for applies
+	#[pallet::storage]
+	#[pallet::getter(fn get_commission_tiers)]
+	pub(super) type CommissionDiscountTiers<T: Config> =
+		StorageValue<_, CommissionTiers, ValueQuery>;
+
+	/// Commission taken on each settled Order, per PBT category, kept to reverse it on recall
+	#[pallet::storage]
+	#[pallet::getter(fn get_order_commission)]
+	pub(super) type OrderCommissions<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderId, Payment, OptionQuery>;
+
+	/// PBT settled to each seller across all categories
+	#[pallet::storage]
+	#[pallet::getter(fn get_seller_volume)]
+	pub(super) type SellerVolume<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+
+	/// Group Buys
+	#[pallet::storage]
+	#[pallet::getter(fn get_group_buy)]
+	pub(super) type GroupBuys<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		GroupBuyId,
+		GroupBuy<T::AccountId, T::Hash, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_group_buy_id)]
+	pub(super) type NextGroupBuyId<T: Config> = StorageValue<_, GroupBuyId, ValueQuery>;
+
+	/// Share committed by each Ngo to a Group Buy
+	#[pallet::storage]
+	#[pallet::getter(fn get_group_commitment)]
+	pub(super) type GroupCommitments<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		GroupBuyId,
+		Blake2_128Concat,
+		T::AccountId,
+		GroupCommitment,
+		OptionQuery,
+	>;
+
+	/// Supply Contracts
+	#[pallet::storage]
+	#[pallet::getter(fn get_supply_contract)]
+	pub(super) type SupplyContracts<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		ContractId,
+		SupplyContract<T::AccountId, T::Hash, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_contract_id)]
+	pub(super) type NextContractId<T: Config> = StorageValue<_, ContractId, ValueQuery>;
+
+	/// Supply Contracts whose delivery is due at the start of each block
+	#[pallet::storage]
+	#[pallet::getter(fn get_contracts_due_at)]
+	pub(super) type ContractsDueAt<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		BoundedVec<ContractId, T::MaxContractsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Supply Contract deliveries missed by each seller
+	#[pallet::storage]
+	#[pallet::getter(fn get_missed_deliveries)]
+	pub(super) type MissedDeliveries<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Discount rules of each Product
+	#[pallet::storage]
+	#[pallet::getter(fn get_discount)]
+	pub(super) type Discounts<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::Hash,
+		Blake2_128Concat,
+		DiscountId,
+		DiscountRule<T::AccountId, T::Hash, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn get_next_discount_id)]
+	pub(super) type NextDiscountId<T: Config> = StorageValue<_, DiscountId, ValueQuery>;
+
+	/// Times each discount rule has been redeemed
+	#[pallet::storage]
+	#[pallet::getter(fn get_discount_redemptions)]
+	pub(super) type DiscountRedemptions<T: Config> =
+		StorageMap<_, Blake2_128Concat, DiscountId, u32, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Products listed at genesis as `(seller, category, price, quantity, cid)`.
+		pub products: Vec<(T::AccountId, Category, u128, u32, T::Hash)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { products: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (seller, category, price, quantity, cid) in &self.products {
+				Pallet::<T>::do_list_product(
+					seller,
+					*category,
+					Default::default(),
+					*price,
+					*quantity,
+					*cid,
+				)
+				.expect("Genesis products must be listed by active sellers");
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Product Listed
-		/// parameters. [donor, recipient, amount, category]
-		ProductListed { seller: T::AccountId, category: Category },
-		/// Product Bought
-		/// parameters. [buyer, pid]
-		ProductBought { buyer: T::AccountId, pid: T::Hash },
+		/// parameters. [seller, pid, category, quantity]
+		ProductListed { seller: T::AccountId, pid: T::Hash, category: Category, quantity: u32 },
+		/// Product Bought, PBT is held in escrow until the order is settled
+		/// parameters. [order_id, buyer, seller, pid, quantity, amount, payment, discount]
+		OrderPlaced {
+			order_id: OrderId,
+			buyer: T::AccountId,
+			seller: T::AccountId,
+			pid: T::Hash,
+			quantity: u32,
+			amount: u128,
+			payment: Payment,
+			discount: Option<AppliedDiscount>,
+		},
+		/// Order Shipped
+		/// parameters. [order_id, tracking]
+		OrderShipped { order_id: OrderId, tracking: T::Hash },
+		/// Order Delivery Confirmed by buyer
+		/// parameters. [order_id]
+		OrderDelivered { order_id: OrderId },
+		/// Order Settled, escrowed PBT released to seller
+		/// parameters. [order_id, seller, amount]
+		OrderSettled { order_id: OrderId, seller: T::AccountId, amount: u128 },
+		/// Dispute Opened
+		/// parameters. [order_id, evidence]
+		DisputeOpened { order_id: OrderId, evidence: T::Hash },
+		/// Seller Responded to Dispute
+		/// parameters. [order_id, response]
+		DisputeResponded { order_id: OrderId, response: T::Hash },
+		/// Tender Posted
+		/// parameters. [tender_id, ngo, category, quantity, max_price, deadline, sealed]
+		TenderPosted {
+			tender_id: TenderId,
+			ngo: T::AccountId,
+			category: Category,
+			quantity: u32,
+			max_price: u128,
+			deadline: T::BlockNumber,
+			sealed: bool,
+		},
+		/// Bid Submitted, price is empty for sealed bids
+		/// parameters. [tender_id, seller, price]
+		BidSubmitted { tender_id: TenderId, seller: T::AccountId, price: Option<u128> },
+		/// Sealed Bid Revealed
+		/// parameters. [tender_id, seller, price]
+		BidRevealed { tender_id: TenderId, seller: T::AccountId, price: u128 },
+		/// Tender Awarded
+		/// parameters. [tender_id, seller, order_id, amount]
+		TenderAwarded { tender_id: TenderId, seller: T::AccountId, order_id: OrderId, amount: u128 },
+		/// Tender Cancelled
+		/// parameters. [tender_id]
+		TenderCancelled { tender_id: TenderId },
+		/// Cart Checked Out, one Order is placed per product
+		/// parameters. [buyer, order_ids, total]
+		CartCheckedOut { buyer: T::AccountId, order_ids: Vec<OrderId>, total: u128 },
+		/// Order Rated
+		/// parameters. [order_id, seller, seller_score, product_score, review]
+		OrderRated {
+			order_id: OrderId,
+			seller: T::AccountId,
+			seller_score: u8,
+			product_score: Option<u8>,
+			review: Option<T::Hash>,
+		},
+		/// Dispute Resolved
+		/// parameters. [order_id, resolution, refunded, released]
+		DisputeResolved {
+			order_id: OrderId,
+			resolution: Resolution,
+			refunded: u128,
+			released: u128,
+		},
+		/// Product Stock Updated
+		/// parameters. [pid, remaining]
+		StockUpdated { pid: T::Hash, remaining: u32 },
+		/// Product Sold Out, listing is closed
+		/// parameters. [pid]
+		ProductSoldOut { pid: T::Hash },
+		/// Product Restocked
+		/// parameters. [pid, added, remaining]
+		ProductRestocked { pid: T::Hash, added: u32, remaining: u32 },
+		/// Product Price Updated
+		/// parameters. [pid, price]
+		ProductPriceUpdated { pid: T::Hash, price: u128 },
+		/// Product Metadata Updated
+		/// parameters. [pid, cid]
+		ProductMetadataUpdated { pid: T::Hash, cid: T::Hash },
+		/// Product Delisted
+		/// parameters. [pid]
+		ProductDelisted { pid: T::Hash },
+		/// Product Price Asset Updated
+		/// parameters. [pid, asset]
+		ProductPriceAssetUpdated { pid: T::Hash, asset: Option<u128> },
+		/// Reference Price Updated, average is empty for the first snapshot
+		/// parameters. [asset, average]
+		ReferencePriceUpdated { asset: u128, average: Option<u128> },
+		/// Auction Created
+		/// parameters. [auction_id, owner, category, quantity, kind, end]
+		AuctionCreated {
+			auction_id: AuctionId,
+			owner: T::AccountId,
+			category: Category,
+			quantity: u32,
+			kind: AuctionKind,
+			end: T::BlockNumber,
+		},
+		/// Auction Bid Placed
+		/// parameters. [auction_id, bidder, amount]
+		AuctionBidPlaced { auction_id: AuctionId, bidder: T::AccountId, amount: u128 },
+		/// Outbid bidder refunded
+		/// parameters. [auction_id, bidder, amount]
+		AuctionBidRefunded { auction_id: AuctionId, bidder: T::AccountId, amount: u128 },
+		/// Auction Settled, an Order is placed for the winner
+		/// parameters. [auction_id, winner, order_id, amount]
+		AuctionSettled {
+			auction_id: AuctionId,
+			winner: T::AccountId,
+			order_id: OrderId,
+			amount: u128,
+		},
+		/// Auction ended without bids
+		/// parameters. [auction_id]
+		AuctionUnsold { auction_id: AuctionId },
+		/// Auction Cancelled
+		/// parameters. [auction_id]
+		AuctionCancelled { auction_id: AuctionId },
+		/// Product Expiry Updated
+		/// parameters. [pid, expires_at]
+		ProductExpiryUpdated { pid: T::Hash, expires_at: Option<T::BlockNumber> },
+		/// Product Recalled, buyers are notified once per Order
+		/// parameters. [pid, reason, refund]
+		ProductRecalled { pid: T::Hash, reason: T::Hash, refund: bool },
+		/// Buyer notified of a recalled Product
+		/// parameters. [pid, order_id, buyer]
+		RecallNotified { pid: T::Hash, order_id: OrderId, buyer: T::AccountId },
+		/// Order of a recalled Product refunded
+		/// parameters. [order_id, buyer, amount]
+		RecallRefunded { order_id: OrderId, buyer: T::AccountId, amount: u128 },
+		/// Commission Rate Updated
+		/// parameters. [category, rate]
+		CommissionRateUpdated { category: Category, rate: Permill },
+		/// Commission Discount Tiers Updated
+		/// parameters. [tiers]
+		CommissionTiersUpdated { tiers: CommissionTiers },
+		/// Commission taken from a settled payment
+		/// parameters. [order_id, seller, category, amount]
+		CommissionCharged {
+			order_id: OrderId,
+			seller: T::AccountId,
+			category: Category,
+			amount: u128,
+		},
+		/// Group Buy Created
+		/// parameters. [group_buy_id, owner, category, deadline]
+		GroupBuyCreated {
+			group_buy_id: GroupBuyId,
+			owner: T::AccountId,
+			category: Category,
+			deadline: T::BlockNumber,
+		},
+		/// Ngo committed to a Group Buy, `amount` is held in escrow
+		/// parameters. [group_buy_id, ngo, quantity, amount]
+		GroupBuyCommitted {
+			group_buy_id: GroupBuyId,
+			ngo: T::AccountId,
+			quantity: u32,
+			amount: u128,
+		},
+		/// Group Buy Executed at the unit price of the tier reached
+		/// parameters. [group_buy_id, quantity, price]
+		GroupBuyExecuted { group_buy_id: GroupBuyId, quantity: u32, price: u128 },
+		/// Order placed for an Ngo's share of an executed Group Buy
+		/// parameters. [group_buy_id, ngo, order_id, quantity, amount]
+		GroupBuyShareOrdered {
+			group_buy_id: GroupBuyId,
+			ngo: T::AccountId,
+			order_id: OrderId,
+			quantity: u32,
+			amount: u128,
+		},
+		/// Group Buy did not reach its threshold by the deadline
+		/// parameters. [group_buy_id]
+		GroupBuyFailed { group_buy_id: GroupBuyId },
+		/// Group Buy Cancelled
+		/// parameters. [group_buy_id]
+		GroupBuyCancelled { group_buy_id: GroupBuyId },
+		/// PBT of a Group Buy commitment returned to its Ngo
+		/// parameters. [group_buy_id, ngo, amount]
+		GroupBuyRefunded { group_buy_id: GroupBuyId, ngo: T::AccountId, amount: u128 },
+		/// Supply Contract Proposed
+		/// parameters. [contract_id, ngo, seller, pid]
+		SupplyContractProposed {
+			contract_id: ContractId,
+			ngo: T::AccountId,
+			seller: T::AccountId,
+			pid: T::Hash,
+		},
+		/// Supply Contract Accepted
+		/// parameters. [contract_id]
+		SupplyContractAccepted { contract_id: ContractId },
+		/// Supply cycle started, an Order is placed and its PBT held in escrow
+		/// parameters. [contract_id, order_id, due]
+		SupplyCycleStarted { contract_id: ContractId, order_id: OrderId, due: T::BlockNumber },
+		/// Seller did not ship a Supply Contract Order by its due block, it was refunded
+		/// parameters. [contract_id, order_id, seller]
+		DeliveryMissed { contract_id: ContractId, order_id: OrderId, seller: T::AccountId },
+		/// Next cycle of a Supply Contract could not start, the contract is terminated
+		/// parameters. [contract_id, error]
+		SupplyCycleFailed { contract_id: ContractId, error: DispatchError },
+		/// Notice given on a Supply Contract, no cycle starts from `ends_at`
+		/// parameters. [contract_id, by, ends_at]
+		SupplyContractNoticeGiven {
+			contract_id: ContractId,
+			by: T::AccountId,
+			ends_at: T::BlockNumber,
+		},
+		/// Supply Contract Terminated
+		/// parameters. [contract_id]
+		SupplyContractTerminated { contract_id: ContractId },
+		/// Discount Added
+		/// parameters. [pid, discount_id]
+		DiscountAdded { pid: T::Hash, discount_id: DiscountId },
+		/// Discount Removed
+		/// parameters. [pid, discount_id]
+		DiscountRemoved { pid: T::Hash, discount_id: DiscountId },
 	}
 
 	#[pallet::error]
@@ -110,6 +1151,170 @@ pub mod pallet {
 		BuyerNotValid,
 		/// Product Not Found
 		ProductNotFound,
+		/// Caller is not the owner of the Product
+		NotProductOwner,
+		/// Product has already been sold
+		ProductAlreadySold,
+		/// Quantity must be greater than zero
+		InvalidQuantity,
+		/// Not enough units in stock
+		InsufficientStock,
+		/// Total price overflowed
+		PriceOverflow,
+		/// Order Not Found
+		OrderNotFound,
+		/// Caller is not the seller of the Order
+		NotOrderSeller,
+		/// Caller is not the buyer of the Order
+		NotOrderBuyer,
+		/// Order is not in a state that allows this action
+		InvalidOrderStatus,
+		/// Delivery timeout has not passed yet
+		DeliveryTimeoutNotReached,
+		/// Dispute Not Found
+		DisputeNotFound,
+		/// Seller has already responded to the dispute
+		AlreadyResponded,
+		/// Refund is larger than the escrowed amount
+		RefundTooLarge,
+		/// Dispute timeout has not passed yet
+		DisputeTimeoutNotReached,
+		/// Tender Not Found
+		TenderNotFound,
+		/// Caller is not the Ngo who posted the Tender
+		NotTenderOwner,
+		/// Tender is no longer open
+		TenderNotOpen,
+		/// Tender deadline must be in the future
+		InvalidDeadline,
+		/// Tender deadline has passed
+		TenderDeadlinePassed,
+		/// Sealed Tender is still accepting bids
+		BiddingStillOpen,
+		/// Bid is above the maximum price of the Tender
+		BidAboveMaxPrice,
+		/// Seller is not allowed to sell in this category
+		SellerCategoryNotAllowed,
+		/// Product does not accept payment in this category
+		CategoryNotAccepted,
+		/// Category appears more than once in a payment
+		DuplicatePaymentCategory,
+		/// Payment does not add up to the total price
+		PaymentMismatch,
+		/// Bid type does not match the Tender
+		BidTypeMismatch,
+		/// Bid Not Found
+		BidNotFound,
+		/// Revealed price and salt do not match the commitment
+		InvalidReveal,
+		/// Sealed Bid has not been revealed
+		BidNotRevealed,
+		/// Score is outside of the allowed range
+		InvalidScore,
+		/// Order has already been rated
+		AlreadyRated,
+		/// Only Orders bought from a listing can rate a Product
+		NoProductToRate,
+		/// Cart has no products
+		EmptyCart,
+		/// Total price of the cart is above the buyer's maximum
+		TotalPriceExceeded,
+		/// No AMM pool prices the asset against the native token
+		NoPriceSource,
+		/// Reference asset has no time weighted average price yet
+		NoReferencePrice,
+		/// Time weighted average price window has not passed yet
+		TwapPeriodNotElapsed,
+		/// Total price is above the buyer's maximum
+		SlippageExceeded,
+		/// Auction Not Found
+		AuctionNotFound,
+		/// Caller is not the owner of the Auction
+		NotAuctionOwner,
+		/// Auction is no longer open
+		AuctionNotOpen,
+		/// Auction duration must be greater than zero
+		InvalidDuration,
+		/// Dutch Auction start price must be above its floor price
+		InvalidAuctionPrices,
+		/// Too many Auctions end in the same block
+		TooManyAuctionsEnding,
+		/// Auction has ended
+		AuctionEnded,
+		/// Auction has not ended yet
+		AuctionNotEnded,
+		/// Bid is below the reserve, the minimum raise or the current price
+		BidTooLow,
+		/// Owner cannot bid on their own Auction
+		OwnAuction,
+		/// Auction with bids cannot be cancelled
+		AuctionHasBids,
+		/// Expiry must be in the future
+		InvalidExpiry,
+		/// Product has expired
+		ProductExpired,
+		/// Product has been recalled
+		ProductRecalled,
+		/// Group Buy Not Found
+		GroupBuyNotFound,
+		/// Caller is not the owner of the Group Buy
+		NotGroupBuyOwner,
+		/// Group Buy is no longer open
+		GroupBuyNotOpen,
+		/// Tiers must be ordered by increasing quantity and decreasing price
+		InvalidPriceTiers,
+		/// Group Buy deadline has passed
+		GroupBuyDeadlinePassed,
+		/// Group Buy deadline has not passed yet
+		GroupBuyDeadlineNotReached,
+		/// Group Buy has reached its maximum number of participants
+		TooManyParticipants,
+		/// Supply Contract Not Found
+		ContractNotFound,
+		/// Caller is not a party of the Supply Contract
+		NotContractParty,
+		/// Supply Contract is not in a state that allows this action
+		InvalidContractStatus,
+		/// Delivery period must be greater than zero
+		InvalidPeriod,
+		/// Too many Supply Contract deliveries are due around the same block
+		TooManyContractsDue,
+		/// Notice has already been given on the Supply Contract
+		NoticeAlreadyGiven,
+		/// Discount Not Found
+		DiscountNotFound,
+		/// Discount must end after it starts
+		InvalidDiscountWindow,
+		/// Product has reached its maximum number of discounts
+		TooManyDiscounts,
+		/// Coupon does not match a discount the buyer can redeem
+		InvalidCoupon,
+		/// Product has more Orders than the witness given
+		BadWitness,
+		/// Tender has reached its maximum number of bids
+		TooManyBids,
+		/// Coupon discount is not restricted to any Ngo or Ngo category
+		UnrestrictedCoupon,
+		/// Product has Orders still holding PBT in escrow
+		ProductHasOpenOrders,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let ending = <AuctionsEndingAt<T>>::take(now);
+			let count = ending.len() as u32;
+			for auction_id in ending {
+				// Auctions settled or cancelled beforehand are skipped
+				let _ = Self::do_settle_auction(auction_id);
+			}
+			let due = <ContractsDueAt<T>>::take(now);
+			let contracts = due.len() as u32;
+			for contract_id in due {
+				Self::do_process_supply_contract(contract_id, now);
+			}
+			<T as Config>::WeightInfo::on_initialize(count, contracts)
+		}
 	}
 
 	#[pallet::call]
@@ -119,77 +1324,2500 @@ pub mod pallet {
 		///
 		/// # Parameters
 		/// * `category`: Category to which product belongs.
-		/// * `price`: Price of the product.
+		/// * `accepted_categories`: Other categories whose PBT is accepted as payment.
+		/// * `price`: Price of a single unit of the product.
+		/// * `quantity`: Number of units available.
 		/// * `cid`: Content Id of Product on IPFS.
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(<T as Config>::WeightInfo::list_product())]
 		pub fn list_product(
 			origin: OriginFor<T>,
 			category: Category,
+			accepted_categories: AcceptedCategories,
 			price: u128,
+			quantity: u32,
 			cid: T::Hash,
 		) -> DispatchResult {
 			let seller = ensure_signed(origin)?;
-			Self::do_list_product(&seller, category, price, cid)?;
-			Self::deposit_event(Event::ProductListed { seller, category });
+			let pid = Self::do_list_product(
+				&seller,
+				category,
+				accepted_categories,
+				price,
+				quantity,
+				cid,
+			)?;
+			Self::deposit_event(Event::ProductListed { seller, pid, category, quantity });
 			Ok(())
 		}
 
 		/// Buy Product
 		/// Only Ngo can buy it using Semi Fungible Token.
+		/// Tokens are held in escrow until the order is settled.
 		///
 		/// # Parameters
 		/// * `pid`: Product Id.
+		/// * `quantity`: Number of units to buy.
+		/// * `payment`: Amount paid in each accepted category, adding up to the total price.
+		///   Leave empty to pay everything in the product's category.
+		/// * `max_amount`: Highest total price in PBT the buyer is willing to pay.
+		/// * `coupon`: Coupon code of a discount offered on the product.
 		#[pallet::call_index(1)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
-		pub fn buy(origin: OriginFor<T>, pid: T::Hash) -> DispatchResult {
+		#[pallet::weight(<T as Config>::WeightInfo::buy(MAX_DISCOUNTS_PER_PRODUCT))]
+		pub fn buy(
+			origin: OriginFor<T>,
+			pid: T::Hash,
+			quantity: u32,
+			payment: Payment,
+			max_amount: Option<u128>,
+			coupon: Option<CouponCode>,
+		) -> DispatchResult {
 			let buyer = ensure_signed(origin)?;
-			Self::do_buy(&buyer, pid)?;
-			Self::deposit_event(Event::ProductBought { buyer, pid });
+			let (order_id, order, remaining, discount) =
+				Self::do_buy(&buyer, pid, quantity, payment, max_amount, coupon)?;
+			Self::deposit_event(Event::OrderPlaced {
+				order_id,
+				buyer,
+				seller: order.seller,
+				pid,
+				quantity,
+				amount: order.amount,
+				payment: order.payment,
+				discount,
+			});
+			Self::deposit_stock_events(pid, remaining);
 			Ok(())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		#[transactional]
-		pub fn do_list_product(
-			seller: &T::AccountId,
+		/// Update Product Price
+		/// Only owner of an unsold Product can call it.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		/// * `price`: New price of the product.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_price())]
+		pub fn update_price(origin: OriginFor<T>, pid: T::Hash, price: u128) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_update_listing(&seller, pid, |product| product.price = price)?;
+			Self::deposit_event(Event::ProductPriceUpdated { pid, price });
+			Ok(())
+		}
+
+		/// Update Product Metadata
+		/// Only owner of an unsold Product can call it.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		/// * `cid`: New Content Id of Product on IPFS.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_metadata())]
+		pub fn update_metadata(origin: OriginFor<T>, pid: T::Hash, cid: T::Hash) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_update_listing(&seller, pid, |product| product.cid = cid)?;
+			Self::deposit_event(Event::ProductMetadataUpdated { pid, cid });
+			Ok(())
+		}
+
+		/// Delist Product
+		/// Only owner of a Product without Orders holding PBT in escrow can call it.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::delist(MAX_DISCOUNTS_PER_PRODUCT))]
+		pub fn delist(origin: OriginFor<T>, pid: T::Hash) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_delist(&seller, pid)?;
+			Self::deposit_event(Event::ProductDelisted { pid });
+			Ok(())
+		}
+
+		/// Restock Product
+		/// Only owner of the Product can call it, a sold out listing is reopened.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		/// * `quantity`: Number of units to add.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::restock())]
+		pub fn restock(origin: OriginFor<T>, pid: T::Hash, quantity: u32) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			let remaining = Self::do_restock(&seller, pid, quantity)?;
+			Self::deposit_event(Event::ProductRestocked { pid, added: quantity, remaining });
+			Ok(())
+		}
+
+		/// Mark Order Shipped
+		/// Only seller of the Order can call it, calling it again updates the tracking details.
+		///
+		/// # Parameters
+		/// * `order_id`: Order Id.
+		/// * `tracking`: Content Id of shipment tracking details on IPFS.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::mark_shipped())]
+		pub fn mark_shipped(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			tracking: T::Hash,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_mark_shipped(&seller, order_id, tracking)?;
+			Self::deposit_event(Event::OrderShipped { order_id, tracking });
+			Ok(())
+		}
+
+		/// Confirm Delivery
+		/// Only buyer of the Order can call it, escrowed tokens are released to the seller.
+		///
+		/// # Parameters
+		/// * `order_id`: Order Id.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::confirm_delivery())]
+		pub fn confirm_delivery(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::do_confirm_delivery(&buyer, order_id)?;
+			Self::deposit_event(Event::OrderDelivered { order_id });
+			Self::do_settle(order_id)
+		}
+
+		/// Settle Order
+		/// Anyone can call it once the delivery timeout of a shipped Order has passed.
+		///
+		/// # Parameters
+		/// * `order_id`: Order Id.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle_order())]
+		pub fn settle_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_settle(order_id)
+		}
+
+		/// Open Dispute
+		/// Only buyer of an unsettled Order can call it, escrowed tokens are frozen until
+		/// the dispute is resolved or expires.
+		///
+		/// # Parameters
+		/// * `order_id`: Order Id.
+		/// * `evidence`: Content Id of evidence on IPFS.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::open_dispute())]
+		pub fn open_dispute(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			evidence: T::Hash,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			Self::do_open_dispute(&buyer, order_id, evidence)?;
+			Self::deposit_event(Event::DisputeOpened { order_id, evidence });
+			Ok(())
+		}
+
+		/// Respond to Dispute
+		/// Only seller of the disputed Order can call it, once.
+		///
+		/// # Parameters
+		/// * `order_id`: Order Id.
+		/// * `response`: Content Id of response on IPFS.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::respond_to_dispute())]
+		pub fn respond_to_dispute(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			response: T::Hash,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_respond_to_dispute(&seller, order_id, response)?;
+			Self::deposit_event(Event::DisputeResponded { order_id, response });
+			Ok(())
+		}
+
+		/// Resolve Dispute
+		/// Only Arbitrator can call it.
+		///
+		/// # Parameters
+		/// * `order_id`: Order Id.
+		/// * `resolution`: How the escrowed tokens are split.
+		/// * `slash`: Amount of the seller's stake to slash, if any.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::resolve_dispute())]
+		pub fn resolve_dispute(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			resolution: Resolution,
+			slash: Option<u128>,
+		) -> DispatchResult {
+			T::ArbitratorOrigin::ensure_origin(origin)?;
+			let order = Self::do_resolve_dispute(order_id, resolution)?;
+			if let Some(amount) = slash {
+				participant_handler::Pallet::<T>::slash_seller(&order.seller, amount);
+			}
+			Ok(())
+		}
+
+		/// Expire Dispute
+		/// Anyone can call it once the dispute timeout has passed. A dispute the Arbitrator has not
+		/// resolved by then refunds the buyer, whether or not the seller responded.
+		///
+		/// # Parameters
+		/// * `order_id`: Order Id.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::expire_dispute())]
+		pub fn expire_dispute(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let dispute = <Disputes<T>>::get(order_id).ok_or(Error::<T>::DisputeNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >=
+					dispute.opened_at.saturating_add(T::DisputeTimeout::get()),
+				Error::<T>::DisputeTimeoutNotReached
+			);
+			Self::do_resolve_dispute(order_id, Resolution::Refund)?;
+			Ok(())
+		}
+
+		/// Post Tender
+		/// Only Ngo can call it.
+		///
+		/// # Parameters
+		/// * `category`: Category of the requested goods.
+		/// * `quantity`: Number of units needed.
+		/// * `max_price`: Highest acceptable price of a single unit.
+		/// * `deadline`: Last block at which bids are accepted.
+		/// * `spec`: Content Id of the specification on IPFS.
+		/// * `sealed`: Whether bids are sealed until the deadline.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::post_tender())]
+		pub fn post_tender(
+			origin: OriginFor<T>,
+			category: Category,
+			quantity: u32,
+			max_price: u128,
+			deadline: T::BlockNumber,
+			spec: T::Hash,
+			sealed: bool,
+		) -> DispatchResult {
+			let ngo = ensure_signed(origin)?;
+			let tender = Tender {
+				ngo: ngo.clone(),
+				category,
+				quantity,
+				max_price,
+				deadline,
+				spec,
+				sealed,
+				status: TenderStatus::Open,
+				bids: 0,
+			};
+			let tender_id = Self::do_post_tender(tender)?;
+			Self::deposit_event(Event::TenderPosted {
+				tender_id,
+				ngo,
+				category,
+				quantity,
+				max_price,
+				deadline,
+				sealed,
+			});
+			Ok(())
+		}
+
+		/// Submit Bid
+		/// Only Seller of the Tender's category can call it, before the deadline of an open
+		/// bid Tender. Submitting again replaces the previous bid.
+		///
+		/// # Parameters
+		/// * `tender_id`: Tender Id.
+		/// * `price`: Price of a single unit.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_bid())]
+		pub fn submit_bid(
+			origin: OriginFor<T>,
+			tender_id: TenderId,
+			price: u128,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			let tender = Self::ensure_accepting_bids(&seller, tender_id, false)?;
+			ensure!(price <= tender.max_price, Error::<T>::BidAboveMaxPrice);
+			Self::insert_bid(tender_id, &seller, Bid { price: Some(price), commitment: None })?;
+			Self::deposit_event(Event::BidSubmitted { tender_id, seller, price: Some(price) });
+			Ok(())
+		}
+
+		/// Submit Sealed Bid
+		/// Only Seller of the Tender's category can call it, before the deadline of a sealed
+		/// bid Tender. Submitting again replaces the previous commitment.
+		///
+		/// # Parameters
+		/// * `tender_id`: Tender Id.
+		/// * `commitment`: Hash of `(seller, price, salt)`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::submit_sealed_bid())]
+		pub fn submit_sealed_bid(
+			origin: OriginFor<T>,
+			tender_id: TenderId,
+			commitment: T::Hash,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::ensure_accepting_bids(&seller, tender_id, true)?;
+			Self::insert_bid(
+				tender_id,
+				&seller,
+				Bid { price: None, commitment: Some(commitment) },
+			)?;
+			Self::deposit_event(Event::BidSubmitted { tender_id, seller, price: None });
+			Ok(())
+		}
+
+		/// Reveal Bid
+		/// Only Seller who submitted a sealed bid can call it, after the deadline.
+		///
+		/// # Parameters
+		/// * `tender_id`: Tender Id.
+		/// * `price`: Price of a single unit that was committed to.
+		/// * `salt`: Salt that was committed to.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::reveal_bid())]
+		pub fn reveal_bid(
+			origin: OriginFor<T>,
+			tender_id: TenderId,
+			price: u128,
+			salt: T::Hash,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_reveal_bid(&seller, tender_id, price, salt)?;
+			Self::deposit_event(Event::BidRevealed { tender_id, seller, price });
+			Ok(())
+		}
+
+		/// Award Tender
+		/// Only Ngo who posted the Tender can call it. The bid's total is moved into escrow
+		/// and an Order is placed with the seller.
+		///
+		/// # Parameters
+		/// * `tender_id`: Tender Id.
+		/// * `seller`: Seller whose bid wins.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::award_tender())]
+		pub fn award_tender(
+			origin: OriginFor<T>,
+			tender_id: TenderId,
+			seller: T::AccountId,
+		) -> DispatchResult {
+			let ngo = ensure_signed(origin)?;
+			let (order_id, amount) = Self::do_award_tender(&ngo, tender_id, &seller)?;
+			Self::deposit_event(Event::TenderAwarded { tender_id, seller, order_id, amount });
+			Ok(())
+		}
+
+		/// Cancel Tender
+		/// Only Ngo who posted an open Tender can call it.
+		///
+		/// # Parameters
+		/// * `tender_id`: Tender Id.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_tender())]
+		pub fn cancel_tender(origin: OriginFor<T>, tender_id: TenderId) -> DispatchResult {
+			let ngo = ensure_signed(origin)?;
+			<Tenders<T>>::try_mutate(tender_id, |tender| -> DispatchResult {
+				let tender = tender.as_mut().ok_or(Error::<T>::TenderNotFound)?;
+				ensure!(tender.ngo == ngo, Error::<T>::NotTenderOwner);
+				ensure!(tender.status == TenderStatus::Open, Error::<T>::TenderNotOpen);
+				tender.status = TenderStatus::Cancelled;
+				Ok(())
+			})?;
+			let _ = <Bids<T>>::clear_prefix(tender_id, MAX_TENDER_BIDS, None);
+			Self::deposit_event(Event::TenderCancelled { tender_id });
+			Ok(())
+		}
+
+		/// Rate Order
+		/// Only buyer of a settled or refunded Order can call it, once.
+		///
+		/// # Parameters
+		/// * `order_id`: Order Id.
+		/// * `seller_score`: Score of the seller from 1 to 5.
+		/// * `product_score`: Score of the product from 1 to 5, for Orders bought from a listing.
+		/// * `review`: Content Id of a written review on IPFS.
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::rate_order())]
+		pub fn rate_order(
+			origin: OriginFor<T>,
+			order_id: OrderId,
+			seller_score: u8,
+			product_score: Option<u8>,
+			review: Option<T::Hash>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			let rating = Rating { seller_score, product_score, review };
+			let seller = Self::do_rate_order(&buyer, order_id, rating)?;
+			Self::deposit_event(Event::OrderRated {
+				order_id,
+				seller,
+				seller_score,
+				product_score,
+				review,
+			});
+			Ok(())
+		}
+
+		/// Buy Many Products
+		/// Only Ngo can call it. Every product is bought, each paid in its own category, or
+		/// none is.
+		///
+		/// # Parameters
+		/// * `items`: Product Ids and number of units to buy of each.
+		/// * `max_total`: Highest total price the buyer is willing to pay.
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy_many(items.len() as u32))]
+		pub fn buy_many(
+			origin: OriginFor<T>,
+			items: Cart<T::Hash>,
+			max_total: u128,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			let (order_ids, total) = Self::do_buy_many(&buyer, items, max_total)?;
+			Self::deposit_event(Event::CartCheckedOut { buyer, order_ids, total });
+			Ok(())
+		}
+
+		/// Set Product Price Asset
+		/// Only owner of an unsold Product can call it. The price is then denominated in the
+		/// reference asset and converted to PBT at purchase time using the AMM's time weighted
+		/// average price.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		/// * `asset`: Reference asset, or `None` to price in PBT.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_price_asset())]
+		pub fn set_price_asset(
+			origin: OriginFor<T>,
+			pid: T::Hash,
+			asset: Option<u128>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			if let Some(asset) = asset {
+				Self::current_cumulative_price(asset)?;
+			}
+			Self::do_update_listing(&seller, pid, |product| product.price_asset = asset)?;
+			Self::deposit_event(Event::ProductPriceAssetUpdated { pid, asset });
+			Ok(())
+		}
+
+		/// Update Reference Price
+		/// Anyone can call it. Snapshots the cumulative price of a reference asset and, once
+		/// the TWAP period has passed since the previous snapshot, updates its average price.
+		///
+		/// # Parameters
+		/// * `asset`: Reference asset.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_reference_price())]
+		pub fn update_reference_price(origin: OriginFor<T>, asset: u128) -> DispatchResult {
+			ensure_signed(origin)?;
+			let average = Self::do_update_reference_price(asset)?;
+			Self::deposit_event(Event::ReferencePriceUpdated { asset, average });
+			Ok(())
+		}
+
+		/// Create Auction
+		/// Only seller allowed to sell in the category, or Ngo, can call it.
+		///
+		/// # Parameters
+		/// * `category`: Category of the lot, bids are paid in its PBT.
+		/// * `quantity`: Number of units in the lot.
+		/// * `kind`: English or Dutch Auction and its prices.
+		/// * `duration`: Number of blocks the Auction accepts bids for.
+		/// * `cid`: Content Id of the lot details on IPFS.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_auction())]
+		pub fn create_auction(
+			origin: OriginFor<T>,
+			category: Category,
+			quantity: u32,
+			kind: AuctionKind,
+			duration: T::BlockNumber,
+			cid: T::Hash,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let start = frame_system::Pallet::<T>::block_number();
+			let auction = Auction {
+				owner: owner.clone(),
+				category,
+				quantity,
+				kind,
+				cid,
+				start,
+				end: start.saturating_add(duration),
+				highest_bid: None,
+				status: AuctionStatus::Open,
+			};
+			let end = auction.end;
+			let auction_id = Self::do_create_auction(auction)?;
+			Self::deposit_event(Event::AuctionCreated {
+				auction_id,
+				owner,
+				category,
+				quantity,
+				kind,
+				end,
+			});
+			Ok(())
+		}
+
+		/// Bid On Auction
+		/// Only Ngo can call it. The bid is held in escrow, an outbid bid is refunded. A bid on
+		/// a Dutch Auction at or above its current price wins it at the current price.
+		///
+		/// # Parameters
+		/// * `auction_id`: Auction Id.
+		/// * `amount`: Bid for the whole lot, in PBT of the Auction's category.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::bid_auction())]
+		pub fn bid_auction(
+			origin: OriginFor<T>,
+			auction_id: AuctionId,
+			amount: u128,
+		) -> DispatchResult {
+			let bidder = ensure_signed(origin)?;
+			Self::do_bid_auction(&bidder, auction_id, amount)
+		}
+
+		/// Settle Auction
+		/// Anyone can call it once the Auction has ended, if it was not settled at the start of
+		/// its end block.
+		///
+		/// # Parameters
+		/// * `auction_id`: Auction Id.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle_auction())]
+		pub fn settle_auction(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResult {
+			ensure_signed(origin)?;
+			let auction = <Auctions<T>>::get(auction_id).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= auction.end,
+				Error::<T>::AuctionNotEnded
+			);
+			Self::do_settle_auction(auction_id)
+		}
+
+		/// Cancel Auction
+		/// Only owner of an open Auction without bids can call it.
+		///
+		/// # Parameters
+		/// * `auction_id`: Auction Id.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_auction())]
+		pub fn cancel_auction(origin: OriginFor<T>, auction_id: AuctionId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			<Auctions<T>>::try_mutate(auction_id, |auction| -> DispatchResult {
+				let auction = auction.as_mut().ok_or(Error::<T>::AuctionNotFound)?;
+				ensure!(auction.owner == owner, Error::<T>::NotAuctionOwner);
+				ensure!(auction.status == AuctionStatus::Open, Error::<T>::AuctionNotOpen);
+				ensure!(auction.highest_bid.is_none(), Error::<T>::AuctionHasBids);
+				auction.status = AuctionStatus::Cancelled;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::AuctionCancelled { auction_id });
+			Ok(())
+		}
+
+		/// Set Product Expiry
+		/// Only owner of an unsold Product can call it.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		/// * `expires_at`: Block from which the Product can no longer be bought, or `None` if it
+		///   does not expire.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_expiry())]
+		pub fn set_expiry(
+			origin: OriginFor<T>,
+			pid: T::Hash,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			let product = <ProductMap<T>>::get(pid).ok_or(Error::<T>::ProductNotFound)?;
+			Self::ensure_open_listing_owner(&seller, &product)?;
+			match expires_at {
+				Some(expires_at) => {
+					ensure!(
+						expires_at > frame_system::Pallet::<T>::block_number(),
+						Error::<T>::InvalidExpiry
+					);
+					<ProductExpiry<T>>::insert(pid, expires_at);
+				},
+				None => <ProductExpiry<T>>::remove(pid),
+			}
+			Self::deposit_event(Event::ProductExpiryUpdated { pid, expires_at });
+			Ok(())
+		}
+
+		/// Recall Product
+		/// Only owner of the Product or the recall origin can call it. Every buyer of the
+		/// Product is notified, and with `refund` their Orders are refunded from escrow or, once
+		/// settled, from the seller's pending payouts.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		/// * `reason`: Content Id of the recall notice on IPFS.
+		/// * `refund`: Whether to refund the buyers.
+		/// * `orders`: Number of Orders placed for the Product, at least.
+		/// * `payouts`: Number of pending payouts of the seller, at least. Only needed with
+		///   `refund`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as Config>::WeightInfo::recall_product(*orders, *payouts))]
+		pub fn recall_product(
+			origin: OriginFor<T>,
+			pid: T::Hash,
+			reason: T::Hash,
+			refund: bool,
+			orders: u32,
+			payouts: u32,
+		) -> DispatchResult {
+			let caller = match T::RecallOrigin::try_origin(origin) {
+				Ok(_) => None,
+				Err(origin) => Some(ensure_signed(origin)?),
+			};
+			Self::do_recall_product(caller.as_ref(), pid, reason, refund, orders, payouts)
+		}
+
+		/// Set Commission Rate
+		/// Only the commission origin can call it.
+		///
+		/// # Parameters
+		/// * `category`: PBT category the rate applies to.
+		/// * `rate`: Share of each settled payment in `category` taken as commission.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_commission_rate())]
+		pub fn set_commission_rate(
+			origin: OriginFor<T>,
+			category: Category,
+			rate: Permill,
+		) -> DispatchResult {
+			T::CommissionOrigin::ensure_origin(origin)?;
+			<CommissionRates<T>>::insert(category, rate);
+			Self::deposit_event(Event::CommissionRateUpdated { category, rate });
+			Ok(())
+		}
+
+		/// Set Commission Discount Tiers
+		/// Only the commission origin can call it.
+		///
+		/// # Parameters
+		/// * `tiers`: Discount tiers replacing the current ones.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_commission_tiers())]
+		pub fn set_commission_tiers(
+			origin: OriginFor<T>,
+			tiers: CommissionTiers,
+		) -> DispatchResult {
+			T::CommissionOrigin::ensure_origin(origin)?;
+			<CommissionDiscountTiers<T>>::put(&tiers);
+			Self::deposit_event(Event::CommissionTiersUpdated { tiers });
+			Ok(())
+		}
+
+		/// Create Group Buy
+		/// Only Approved Seller can call it.
+		///
+		/// # Parameters
+		/// * `category`: Category to which the goods belong.
+		/// * `tiers`: Unit prices by committed quantity, the first tier is the threshold.
+		/// * `max_quantity`: Most units the seller can deliver.
+		/// * `duration`: Blocks during which Ngos can commit.
+		/// * `cid`: Content Id of the offer on IPFS.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_group_buy())]
+		pub fn create_group_buy(
+			origin: OriginFor<T>,
+			category: Category,
+			tiers: PriceTiers,
+			max_quantity: u32,
+			duration: T::BlockNumber,
+			cid: T::Hash,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			let deadline = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+			let group_buy = GroupBuy {
+				owner: owner.clone(),
+				category,
+				tiers,
+				max_quantity,
+				committed: 0,
+				participants: 0,
+				cid,
+				deadline,
+				status: GroupBuyStatus::Open,
+			};
+			let group_buy_id = Self::do_create_group_buy(group_buy)?;
+			Self::deposit_event(Event::GroupBuyCreated { group_buy_id, owner, category, deadline });
+			Ok(())
+		}
+
+		/// Commit To Group Buy
+		/// Only Ngo can call it. PBT for `quantity` units at the first tier price is held in
+		/// escrow, the difference is refunded if a cheaper tier is reached.
+		///
+		/// # Parameters
+		/// * `group_buy_id`: Group Buy Id.
+		/// * `quantity`: Units to add to the Ngo's share.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::commit_group_buy())]
+		pub fn commit_group_buy(
+			origin: OriginFor<T>,
+			group_buy_id: GroupBuyId,
+			quantity: u32,
+		) -> DispatchResult {
+			let ngo = ensure_signed(origin)?;
+			let amount = Self::do_commit_group_buy(&ngo, group_buy_id, quantity)?;
+			Self::deposit_event(Event::GroupBuyCommitted { group_buy_id, ngo, quantity, amount });
+			Ok(())
+		}
+
+		/// Close Group Buy
+		/// Anyone can call it once the deadline has passed. It executes if the threshold was
+		/// reached, otherwise every commitment is refunded.
+		///
+		/// # Parameters
+		/// * `group_buy_id`: Group Buy Id.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as Config>::WeightInfo::close_group_buy(T::MaxGroupBuyParticipants::get()))]
+		pub fn close_group_buy(origin: OriginFor<T>, group_buy_id: GroupBuyId) -> DispatchResult {
+			ensure_signed(origin)?;
+			Self::do_close_group_buy(group_buy_id)
+		}
+
+		/// Cancel Group Buy
+		/// Only owner of an open Group Buy can call it, every commitment is refunded.
+		///
+		/// # Parameters
+		/// * `group_buy_id`: Group Buy Id.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_group_buy(T::MaxGroupBuyParticipants::get()))]
+		pub fn cancel_group_buy(origin: OriginFor<T>, group_buy_id: GroupBuyId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+			Self::do_cancel_group_buy(&owner, group_buy_id)?;
+			Self::deposit_event(Event::GroupBuyCancelled { group_buy_id });
+			Ok(())
+		}
+
+		/// Propose Supply Contract
+		/// Only Ngo can call it. The owner of the Product has to accept it.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		/// * `quantity`: Units per delivery.
+		/// * `price`: Agreed price of a single unit.
+		/// * `period`: Blocks between deliveries.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_supply_contract())]
+		pub fn propose_supply_contract(
+			origin: OriginFor<T>,
+			pid: T::Hash,
+			quantity: u32,
+			price: u128,
+			period: T::BlockNumber,
+		) -> DispatchResult {
+			let ngo = ensure_signed(origin)?;
+			let (contract_id, seller) =
+				Self::do_propose_supply_contract(&ngo, pid, quantity, price, period)?;
+			Self::deposit_event(Event::SupplyContractProposed { contract_id, ngo, seller, pid });
+			Ok(())
+		}
+
+		/// Accept Supply Contract
+		/// Only seller of a proposed Supply Contract can call it. The first cycle starts right
+		/// away, its PBT is held in escrow.
+		///
+		/// # Parameters
+		/// * `contract_id`: Supply Contract Id.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_supply_contract())]
+		pub fn accept_supply_contract(
+			origin: OriginFor<T>,
+			contract_id: ContractId,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			Self::do_accept_supply_contract(&seller, contract_id)?;
+			Self::deposit_event(Event::SupplyContractAccepted { contract_id });
+			Ok(())
+		}
+
+		/// Terminate Supply Contract
+		/// Only Ngo or seller of the Supply Contract can call it. A proposal ends right away, an
+		/// active contract ends at the first due block after the notice period.
+		///
+		/// # Parameters
+		/// * `contract_id`: Supply Contract Id.
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as Config>::WeightInfo::terminate_supply_contract())]
+		pub fn terminate_supply_contract(
+			origin: OriginFor<T>,
+			contract_id: ContractId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::do_terminate_supply_contract(&who, contract_id)
+		}
+
+		/// Add Discount
+		/// Only owner of the Product can call it.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		/// * `rule`: Discount offered, its coupon is the hash of the code buyers present. Coupons
+		///   are public once redeemed, so they must name some Ngos or Ngo categories.
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_discount(MAX_DISCOUNTS_PER_PRODUCT - 1))]
+		pub fn add_discount(
+			origin: OriginFor<T>,
+			pid: T::Hash,
+			rule: DiscountRule<T::AccountId, T::Hash, T::BlockNumber>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			let discount_id = Self::do_add_discount(&seller, pid, rule)?;
+			Self::deposit_event(Event::DiscountAdded { pid, discount_id });
+			Ok(())
+		}
+
+		/// Remove Discount
+		/// Only owner of the Product can call it.
+		///
+		/// # Parameters
+		/// * `pid`: Product Id.
+		/// * `discount_id`: Discount Id.
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_discount())]
+		pub fn remove_discount(
+			origin: OriginFor<T>,
+			pid: T::Hash,
+			discount_id: DiscountId,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			let product = <ProductMap<T>>::get(pid).ok_or(Error::<T>::ProductNotFound)?;
+			ensure!(product.owner == seller, Error::<T>::NotProductOwner);
+			<Discounts<T>>::take(pid, discount_id).ok_or(Error::<T>::DiscountNotFound)?;
+			<DiscountRedemptions<T>>::remove(discount_id);
+			Self::deposit_event(Event::DiscountRemoved { pid, discount_id });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		#[transactional]
+		pub fn do_list_product(
+			seller: &T::AccountId,
 			category: Category,
+			mut accepted_categories: AcceptedCategories,
 			price: u128,
+			quantity: u32,
 			cid: T::Hash,
-		) -> DispatchResult {
+		) -> Result<T::Hash, DispatchError> {
 			ensure!(
 				participant_handler::pallet::Pallet::<T>::is_active_seller(seller),
 				Error::<T>::SellerNotValid
 			);
-			let product = ProductInfo::new(category, price, seller.clone(), cid);
-			//TODO: Check if seller can list product in given category
-			let pid: T::Hash = T::Hashing::hash_of(&product);
+			ensure!(!quantity.is_zero(), Error::<T>::InvalidQuantity);
+			if !accepted_categories.contains(&category) {
+				accepted_categories
+					.try_insert(0, category)
+					.map_err(|_| Error::<T>::CategoryNotAccepted)?;
+			}
+			for accepted in accepted_categories.iter() {
+				ensure!(
+					participant_handler::pallet::Pallet::<T>::is_seller_category_allowed(
+						seller, accepted
+					),
+					Error::<T>::SellerCategoryNotAllowed
+				);
+			}
+			let product = ProductInfo::new(
+				category,
+				accepted_categories,
+				price,
+				quantity,
+				seller.clone(),
+				cid,
+			);
+			let nonce = <ProductNonce<T>>::mutate(|nonce| {
+				*nonce = nonce.saturating_add(1);
+				*nonce
+			});
+			let pid: T::Hash = T::Hashing::hash_of(&(&product, nonce));
 			<ProductMap<T>>::insert(pid, product);
+			<ProductsByCategory<T>>::insert(category, pid, ());
+			<ProductsBySeller<T>>::insert(seller, pid, ());
+			Ok(pid)
+		}
+
+		pub fn do_update_listing(
+			seller: &T::AccountId,
+			pid: T::Hash,
+			update: impl FnOnce(&mut ProductInfo<T::AccountId, T::Hash>),
+		) -> DispatchResult {
+			<ProductMap<T>>::try_mutate(pid, |product| -> DispatchResult {
+				let product = product.as_mut().ok_or(Error::<T>::ProductNotFound)?;
+				Self::ensure_open_listing_owner(seller, product)?;
+				update(product);
+				Ok(())
+			})
+		}
+
+		/// Delist a Product, whether open, sold out or recalled, once none of its Orders holds
+		/// PBT in escrow. A Product with Orders is kept, as `Delisted` unless recalled, so it can
+		/// still be recalled and shows in its buyers' purchase history.
+		pub fn do_delist(seller: &T::AccountId, pid: T::Hash) -> DispatchResult {
+			let mut product = Self::listed_product(pid)?;
+			ensure!(product.owner == *seller, Error::<T>::NotProductOwner);
+			ensure!(<OpenOrdersByProduct<T>>::get(pid).is_zero(), Error::<T>::ProductHasOpenOrders);
+			<ProductsByCategory<T>>::remove(product.category, pid);
+			<ProductsBySeller<T>>::remove(seller, pid);
+			<ProductExpiry<T>>::remove(pid);
+			for (discount_id, _) in <Discounts<T>>::drain_prefix(pid) {
+				<DiscountRedemptions<T>>::remove(discount_id);
+			}
+			if <OrdersByProduct<T>>::iter_key_prefix(pid).next().is_some() {
+				if product.status != Status::Recalled {
+					product.status = Status::Delisted;
+				}
+				<ProductMap<T>>::insert(pid, product);
+			} else {
+				<ProductMap<T>>::remove(pid);
+			}
 			Ok(())
 		}
 
+		/// A Product that has not been delisted
+		fn listed_product(
+			pid: T::Hash,
+		) -> Result<ProductInfo<T::AccountId, T::Hash>, DispatchError> {
+			<ProductMap<T>>::get(pid)
+				.filter(|product| product.status != Status::Delisted)
+				.ok_or_else(|| Error::<T>::ProductNotFound.into())
+		}
+
+		fn ensure_open_listing_owner(
+			seller: &T::AccountId,
+			product: &ProductInfo<T::AccountId, T::Hash>,
+		) -> DispatchResult {
+			ensure!(product.status != Status::Delisted, Error::<T>::ProductNotFound);
+			ensure!(product.status == Status::OpenForSell, Error::<T>::ProductAlreadySold);
+			ensure!(product.owner == *seller, Error::<T>::NotProductOwner);
+			Ok(())
+		}
+
+		/// Buy `quantity` units of a Product, moving the payment into escrow.
+		/// Returns the placed order and the units left in stock.
 		#[transactional]
-		pub fn do_buy(buyer: &T::AccountId, pid: T::Hash) -> DispatchResult {
+		pub fn do_buy(
+			buyer: &T::AccountId,
+			pid: T::Hash,
+			quantity: u32,
+			mut payment: Payment,
+			max_amount: Option<u128>,
+			coupon: Option<CouponCode>,
+		) -> Result<PurchaseOf<T>, DispatchError> {
 			ensure!(
 				participant_handler::pallet::Pallet::<T>::is_active_ngo(buyer),
 				Error::<T>::SellerNotValid
 			);
+			ensure!(!quantity.is_zero(), Error::<T>::InvalidQuantity);
 			<ProductMap<T>>::try_mutate(pid, |product| {
 				if let Some(product) = product {
-					donation_handler::Pallet::<T>::do_transfer_token(
-						buyer,
-						&product.owner,
-						&product.category,
-						product.price,
-					)?;
-					product.update_info(buyer.clone());
-					Ok(())
+					ensure!(product.status != Status::Delisted, Error::<T>::ProductNotFound);
+					ensure!(product.status != Status::Recalled, Error::<T>::ProductRecalled);
+					ensure!(product.status == Status::OpenForSell, Error::<T>::ProductAlreadySold);
+					ensure!(!Self::is_expired(pid), Error::<T>::ProductExpired);
+					ensure!(product.quantity >= quantity, Error::<T>::InsufficientStock);
+					let amount = Self::unit_price(product)?
+						.checked_mul(quantity as u128)
+						.ok_or(Error::<T>::PriceOverflow)?;
+					let discount = Self::redeem_discount(buyer, pid, amount, coupon)?;
+					let amount =
+						amount.saturating_sub(discount.map_or(0, |discount| discount.amount));
+					ensure!(
+						max_amount.map_or(true, |max_amount| amount <= max_amount),
+						Error::<T>::SlippageExceeded
+					);
+					ensure!(
+						participant_handler::pallet::Pallet::<T>::is_active_seller(&product.owner),
+						Error::<T>::SellerNotValid
+					);
+					if payment.is_empty() {
+						payment = Self::single_payment(product.category, amount);
+					}
+					Self::ensure_valid_payment(&payment, &product.accepted_categories, amount)?;
+					Self::escrow_payment(buyer, &payment)?;
+					product.take_stock(quantity);
+					if product.status == Status::Sold {
+						<ProductsByCategory<T>>::remove(product.category, pid);
+					}
+					<ProductsByBuyer<T>>::insert(buyer, pid, ());
+					let order = Order {
+						source: OrderSource::Listing(pid),
+						buyer: buyer.clone(),
+						seller: product.owner.clone(),
+						quantity,
+						amount,
+						payment,
+						status: OrderStatus::Placed,
+						tracking: None,
+						placed_at: frame_system::Pallet::<T>::block_number(),
+						shipped_at: None,
+					};
+					let order_id = Self::insert_order(order.clone());
+					<OrdersByProduct<T>>::insert(pid, order_id, ());
+					<OpenOrdersByProduct<T>>::mutate(pid, |open| *open = open.saturating_add(1));
+					Ok((order_id, order, product.quantity, discount))
 				} else {
 					Err(Error::<T>::ProductNotFound.into())
 				}
 			})
 		}
+
+		/// Price of a single unit in PBT, converting reference asset prices with the time
+		/// weighted average price of the asset.
+		fn unit_price(product: &ProductInfo<T::AccountId, T::Hash>) -> Result<u128, DispatchError> {
+			let asset = match product.price_asset {
+				Some(asset) => asset,
+				None => return Ok(product.price),
+			};
+			let refresh_due = <PriceObservations<T>>::get(asset).map_or(false, |observation| {
+				frame_system::Pallet::<T>::block_number().saturating_sub(observation.block) >=
+					T::TwapPeriod::get()
+			});
+			if refresh_due {
+				let average = Self::do_update_reference_price(asset)?;
+				Self::deposit_event(Event::ReferencePriceUpdated { asset, average });
+			}
+			let average = <PriceObservations<T>>::get(asset)
+				.and_then(|observation| observation.average)
+				.ok_or(Error::<T>::NoReferencePrice)?;
+			FixedU128::from_inner(average)
+				.checked_mul_int(product.price)
+				.ok_or_else(|| Error::<T>::PriceOverflow.into())
+		}
+
+		/// Cumulative price of `asset` in native tokens up to the current block, extending the
+		/// pool's last recorded cumulative price with its spot price.
+		pub fn current_cumulative_price(asset: u128) -> Result<u128, DispatchError> {
+			let native = T::NativeCurrencyId::get();
+			let pool = T::AMM::get_pool_by_asset_pair((asset, native))
+				.filter(|pool| !pool.base_amount.is_zero() && !pool.quote_amount.is_zero())
+				.ok_or(Error::<T>::NoPriceSource)?;
+			// Pools are keyed by the larger asset id as base, `price_0` is the base price
+			let (cumulative, spot) = if asset > native {
+				(
+					pool.price_0_cumulative_last,
+					FixedU128::saturating_from_rational(pool.quote_amount, pool.base_amount),
+				)
+			} else {
+				(
+					pool.price_1_cumulative_last,
+					FixedU128::saturating_from_rational(pool.base_amount, pool.quote_amount),
+				)
+			};
+			let elapsed: u128 = frame_system::Pallet::<T>::block_number()
+				.saturating_sub(pool.block_timestamp_last)
+				.saturated_into();
+			let pending = spot
+				.checked_mul(&FixedU128::saturating_from_integer(elapsed))
+				.ok_or(Error::<T>::PriceOverflow)?;
+			FixedU128::from_inner(cumulative)
+				.checked_add(&pending)
+				.map(|cumulative| cumulative.into_inner())
+				.ok_or_else(|| Error::<T>::PriceOverflow.into())
+		}
+
+		/// Snapshot the cumulative price of `asset`, returning the average price since the
+		/// previous snapshot. The first snapshot has no average.
+		pub fn do_update_reference_price(asset: u128) -> Result<Option<u128>, DispatchError> {
+			let cumulative = Self::current_cumulative_price(asset)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let average = match <PriceObservations<T>>::get(asset) {
+				Some(previous) => {
+					let elapsed = now.saturating_sub(previous.block);
+					ensure!(
+						!elapsed.is_zero() && elapsed >= T::TwapPeriod::get(),
+						Error::<T>::TwapPeriodNotElapsed
+					);
+					let average =
+						FixedU128::from_inner(cumulative.saturating_sub(previous.cumulative))
+							.checked_div(&FixedU128::saturating_from_integer(
+								elapsed.saturated_into::<u128>(),
+							))
+							.ok_or(Error::<T>::PriceOverflow)?;
+					Some(average.into_inner())
+				},
+				None => None,
+			};
+			<PriceObservations<T>>::insert(
+				asset,
+				PriceObservation { cumulative, block: now, average },
+			);
+			Ok(average)
+		}
+
+		/// Buy every product of the cart, failing as a whole if any purchase fails or the total
+		/// price is above `max_total`. Returns the placed Orders and the total price.
+		#[transactional]
+		pub fn do_buy_many(
+			buyer: &T::AccountId,
+			items: Cart<T::Hash>,
+			max_total: u128,
+		) -> Result<(Vec<OrderId>, u128), DispatchError> {
+			ensure!(!items.is_empty(), Error::<T>::EmptyCart);
+			let mut order_ids = Vec::with_capacity(items.len());
+			let mut total: u128 = 0;
+			for (pid, quantity) in items {
+				let (order_id, order, remaining, discount) =
+					Self::do_buy(buyer, pid, quantity, Default::default(), None, None)?;
+				total = total.checked_add(order.amount).ok_or(Error::<T>::PriceOverflow)?;
+				ensure!(total <= max_total, Error::<T>::TotalPriceExceeded);
+				Self::deposit_event(Event::OrderPlaced {
+					order_id,
+					buyer: buyer.clone(),
+					seller: order.seller,
+					pid,
+					quantity,
+					amount: order.amount,
+					payment: order.payment,
+					discount,
+				});
+				Self::deposit_stock_events(pid, remaining);
+				order_ids.push(order_id);
+			}
+			Ok((order_ids, total))
+		}
+
+		pub fn do_post_tender(
+			tender: Tender<T::AccountId, T::Hash, T::BlockNumber>,
+		) -> Result<TenderId, DispatchError> {
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_active_ngo(&tender.ngo),
+				Error::<T>::BuyerNotValid
+			);
+			ensure!(!tender.quantity.is_zero(), Error::<T>::InvalidQuantity);
+			ensure!(
+				tender.deadline > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidDeadline
+			);
+			let tender_id = <NextTenderId<T>>::mutate(|next_id| {
+				let tender_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				tender_id
+			});
+			<Tenders<T>>::insert(tender_id, tender);
+			Ok(tender_id)
+		}
+
+		fn ensure_accepting_bids(
+			seller: &T::AccountId,
+			tender_id: TenderId,
+			sealed: bool,
+		) -> Result<Tender<T::AccountId, T::Hash, T::BlockNumber>, DispatchError> {
+			let tender = <Tenders<T>>::get(tender_id).ok_or(Error::<T>::TenderNotFound)?;
+			ensure!(tender.status == TenderStatus::Open, Error::<T>::TenderNotOpen);
+			ensure!(tender.sealed == sealed, Error::<T>::BidTypeMismatch);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= tender.deadline,
+				Error::<T>::TenderDeadlinePassed
+			);
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_seller_category_allowed(
+					seller,
+					&tender.category
+				),
+				Error::<T>::SellerCategoryNotAllowed
+			);
+			Ok(tender)
+		}
+
+		/// Insert `bid`, counting it against the Tender's limit unless `seller` has bid already.
+		fn insert_bid(
+			tender_id: TenderId,
+			seller: &T::AccountId,
+			bid: Bid<T::Hash>,
+		) -> DispatchResult {
+			if !<Bids<T>>::contains_key(tender_id, seller) {
+				<Tenders<T>>::try_mutate(tender_id, |tender| -> DispatchResult {
+					let tender = tender.as_mut().ok_or(Error::<T>::TenderNotFound)?;
+					ensure!(tender.bids < MAX_TENDER_BIDS, Error::<T>::TooManyBids);
+					tender.bids += 1;
+					Ok(())
+				})?;
+			}
+			<Bids<T>>::insert(tender_id, seller, bid);
+			Ok(())
+		}
+
+		pub fn do_reveal_bid(
+			seller: &T::AccountId,
+			tender_id: TenderId,
+			price: u128,
+			salt: T::Hash,
+		) -> DispatchResult {
+			let tender = <Tenders<T>>::get(tender_id).ok_or(Error::<T>::TenderNotFound)?;
+			ensure!(tender.status == TenderStatus::Open, Error::<T>::TenderNotOpen);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > tender.deadline,
+				Error::<T>::BiddingStillOpen
+			);
+			<Bids<T>>::try_mutate(tender_id, seller, |bid| {
+				let bid = bid.as_mut().ok_or(Error::<T>::BidNotFound)?;
+				let commitment = bid.commitment.ok_or(Error::<T>::BidTypeMismatch)?;
+				ensure!(
+					T::Hashing::hash_of(&(seller, price, salt)) == commitment,
+					Error::<T>::InvalidReveal
+				);
+				ensure!(price <= tender.max_price, Error::<T>::BidAboveMaxPrice);
+				bid.price = Some(price);
+				Ok(())
+			})
+		}
+
+		/// Award a Tender to `seller`, moving the bid's total into escrow.
+		/// Returns the placed Order id and the escrowed amount.
+		#[transactional]
+		pub fn do_award_tender(
+			ngo: &T::AccountId,
+			tender_id: TenderId,
+			seller: &T::AccountId,
+		) -> Result<(OrderId, u128), DispatchError> {
+			let mut tender = <Tenders<T>>::get(tender_id).ok_or(Error::<T>::TenderNotFound)?;
+			ensure!(tender.ngo == *ngo, Error::<T>::NotTenderOwner);
+			ensure!(tender.status == TenderStatus::Open, Error::<T>::TenderNotOpen);
+			ensure!(
+				!tender.sealed || frame_system::Pallet::<T>::block_number() > tender.deadline,
+				Error::<T>::BiddingStillOpen
+			);
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_seller_category_allowed(
+					seller,
+					&tender.category
+				),
+				Error::<T>::SellerCategoryNotAllowed
+			);
+			let bid = <Bids<T>>::get(tender_id, seller).ok_or(Error::<T>::BidNotFound)?;
+			let price = bid.price.ok_or(Error::<T>::BidNotRevealed)?;
+			let amount =
+				price.checked_mul(tender.quantity as u128).ok_or(Error::<T>::PriceOverflow)?;
+			let payment = Self::single_payment(tender.category, amount);
+			Self::escrow_payment(ngo, &payment)?;
+			let order_id = Self::insert_order(Order {
+				source: OrderSource::Tender(tender_id),
+				buyer: ngo.clone(),
+				seller: seller.clone(),
+				quantity: tender.quantity,
+				amount,
+				payment,
+				status: OrderStatus::Placed,
+				tracking: None,
+				placed_at: frame_system::Pallet::<T>::block_number(),
+				shipped_at: None,
+			});
+			tender.status = TenderStatus::Awarded;
+			<Tenders<T>>::insert(tender_id, tender);
+			let _ = <Bids<T>>::clear_prefix(tender_id, MAX_TENDER_BIDS, None);
+			Ok((order_id, amount))
+		}
+
+		pub fn do_create_auction(
+			auction: Auction<T::AccountId, T::Hash, T::BlockNumber>,
+		) -> Result<AuctionId, DispatchError> {
+			if !participant_handler::pallet::Pallet::<T>::is_active_ngo(&auction.owner) {
+				ensure!(
+					participant_handler::pallet::Pallet::<T>::is_active_seller(&auction.owner),
+					Error::<T>::CallerNotValid
+				);
+				ensure!(
+					participant_handler::pallet::Pallet::<T>::is_seller_category_allowed(
+						&auction.owner,
+						&auction.category
+					),
+					Error::<T>::SellerCategoryNotAllowed
+				);
+			}
+			ensure!(!auction.quantity.is_zero(), Error::<T>::InvalidQuantity);
+			ensure!(auction.end > auction.start, Error::<T>::InvalidDuration);
+			if let AuctionKind::Dutch { start_price, floor_price } = auction.kind {
+				ensure!(start_price > floor_price, Error::<T>::InvalidAuctionPrices);
+			}
+			let auction_id = <NextAuctionId<T>>::mutate(|next_id| {
+				let auction_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				auction_id
+			});
+			<AuctionsEndingAt<T>>::try_append(auction.end, auction_id)
+				.map_err(|_| Error::<T>::TooManyAuctionsEnding)?;
+			<Auctions<T>>::insert(auction_id, auction);
+			Ok(auction_id)
+		}
+
+		#[transactional]
+		pub fn do_bid_auction(
+			bidder: &T::AccountId,
+			auction_id: AuctionId,
+			amount: u128,
+		) -> DispatchResult {
+			let mut auction = <Auctions<T>>::get(auction_id).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_active_ngo(bidder),
+				Error::<T>::BuyerNotValid
+			);
+			ensure!(auction.owner != *bidder, Error::<T>::OwnAuction);
+			ensure!(auction.status == AuctionStatus::Open, Error::<T>::AuctionNotOpen);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now < auction.end, Error::<T>::AuctionEnded);
+			match auction.kind {
+				AuctionKind::English { reserve_price, min_increment } => {
+					let min_bid = match &auction.highest_bid {
+						Some((_, highest)) => highest
+							.checked_add(min_increment.max(1))
+							.ok_or(Error::<T>::PriceOverflow)?,
+						None => reserve_price,
+					};
+					ensure!(amount >= min_bid, Error::<T>::BidTooLow);
+					Self::escrow_payment(bidder, &Self::single_payment(auction.category, amount))?;
+					if let Some((previous, refund)) = auction.highest_bid.take() {
+						<T as Config>::TokenHandler::transfer(
+							auction.category.get_id() as u128,
+							&Self::get_escrow_account(),
+							&previous,
+							refund,
+							false,
+						)?;
+						Self::deposit_event(Event::AuctionBidRefunded {
+							auction_id,
+							bidder: previous,
+							amount: refund,
+						});
+					}
+					auction.highest_bid = Some((bidder.clone(), amount));
+					<Auctions<T>>::insert(auction_id, auction);
+					Self::deposit_event(Event::AuctionBidPlaced {
+						auction_id,
+						bidder: bidder.clone(),
+						amount,
+					});
+				},
+				AuctionKind::Dutch { .. } => {
+					let price = Self::dutch_price(&auction, now);
+					ensure!(amount >= price, Error::<T>::BidTooLow);
+					Self::escrow_payment(bidder, &Self::single_payment(auction.category, price))?;
+					auction.highest_bid = Some((bidder.clone(), price));
+					<Auctions<T>>::insert(auction_id, auction);
+					Self::deposit_event(Event::AuctionBidPlaced {
+						auction_id,
+						bidder: bidder.clone(),
+						amount: price,
+					});
+					Self::do_settle_auction(auction_id)?;
+				},
+			}
+			Ok(())
+		}
+
+		/// Current price of a Dutch Auction, falling linearly from its start to its floor price
+		/// over the Auction's duration.
+		pub fn dutch_price(
+			auction: &Auction<T::AccountId, T::Hash, T::BlockNumber>,
+			now: T::BlockNumber,
+		) -> u128 {
+			match auction.kind {
+				AuctionKind::Dutch { start_price, floor_price } => {
+					let remaining: u128 = auction.end.saturating_sub(now).saturated_into();
+					let duration: u128 = auction.end.saturating_sub(auction.start).saturated_into();
+					floor_price.saturating_add(
+						Perbill::from_rational(remaining, duration) *
+							start_price.saturating_sub(floor_price),
+					)
+				},
+				AuctionKind::English { reserve_price, .. } => reserve_price,
+			}
+		}
+
+		/// Close an open Auction, placing an Order for the highest bid held in escrow.
+		#[transactional]
+		pub fn do_settle_auction(auction_id: AuctionId) -> DispatchResult {
+			let mut auction = <Auctions<T>>::get(auction_id).ok_or(Error::<T>::AuctionNotFound)?;
+			ensure!(auction.status == AuctionStatus::Open, Error::<T>::AuctionNotOpen);
+			match auction.highest_bid.clone() {
+				Some((winner, amount)) => {
+					let order_id = Self::insert_order(Order {
+						source: OrderSource::Auction(auction_id),
+						buyer: winner.clone(),
+						seller: auction.owner.clone(),
+						quantity: auction.quantity,
+						amount,
+						payment: Self::single_payment(auction.category, amount),
+						status: OrderStatus::Placed,
+						tracking: None,
+						placed_at: frame_system::Pallet::<T>::block_number(),
+						shipped_at: None,
+					});
+					auction.status = AuctionStatus::Settled;
+					Self::deposit_event(Event::AuctionSettled {
+						auction_id,
+						winner,
+						order_id,
+						amount,
+					});
+				},
+				None => {
+					auction.status = AuctionStatus::Unsold;
+					Self::deposit_event(Event::AuctionUnsold { auction_id });
+				},
+			}
+			<Auctions<T>>::insert(auction_id, auction);
+			Ok(())
+		}
+
+		pub fn do_create_group_buy(
+			group_buy: GroupBuy<T::AccountId, T::Hash, T::BlockNumber>,
+		) -> Result<GroupBuyId, DispatchError> {
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_active_seller(&group_buy.owner),
+				Error::<T>::CallerNotValid
+			);
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_seller_category_allowed(
+					&group_buy.owner,
+					&group_buy.category
+				),
+				Error::<T>::SellerCategoryNotAllowed
+			);
+			let threshold = group_buy.tiers.first().ok_or(Error::<T>::InvalidPriceTiers)?;
+			ensure!(!threshold.min_quantity.is_zero(), Error::<T>::InvalidPriceTiers);
+			ensure!(
+				group_buy
+					.tiers
+					.windows(2)
+					.all(|pair| pair[0].min_quantity < pair[1].min_quantity &&
+						pair[0].price > pair[1].price),
+				Error::<T>::InvalidPriceTiers
+			);
+			ensure!(group_buy.max_quantity >= threshold.min_quantity, Error::<T>::InvalidQuantity);
+			ensure!(
+				group_buy.deadline > frame_system::Pallet::<T>::block_number(),
+				Error::<T>::InvalidDuration
+			);
+			let group_buy_id = <NextGroupBuyId<T>>::mutate(|next_id| {
+				let group_buy_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				group_buy_id
+			});
+			<GroupBuys<T>>::insert(group_buy_id, group_buy);
+			Ok(group_buy_id)
+		}
+
+		/// Add `quantity` units to the share of `ngo`, returning the PBT held in escrow for them.
+		#[transactional]
+		pub fn do_commit_group_buy(
+			ngo: &T::AccountId,
+			group_buy_id: GroupBuyId,
+			quantity: u32,
+		) -> Result<u128, DispatchError> {
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_active_ngo(ngo),
+				Error::<T>::BuyerNotValid
+			);
+			ensure!(!quantity.is_zero(), Error::<T>::InvalidQuantity);
+			<GroupBuys<T>>::try_mutate(group_buy_id, |group_buy| {
+				let group_buy = group_buy.as_mut().ok_or(Error::<T>::GroupBuyNotFound)?;
+				ensure!(group_buy.status == GroupBuyStatus::Open, Error::<T>::GroupBuyNotOpen);
+				ensure!(
+					frame_system::Pallet::<T>::block_number() < group_buy.deadline,
+					Error::<T>::GroupBuyDeadlinePassed
+				);
+				let committed = group_buy
+					.committed
+					.checked_add(quantity)
+					.ok_or(Error::<T>::InsufficientStock)?;
+				ensure!(committed <= group_buy.max_quantity, Error::<T>::InsufficientStock);
+				let mut commitment = match <GroupCommitments<T>>::get(group_buy_id, ngo) {
+					Some(commitment) => commitment,
+					None => {
+						ensure!(
+							group_buy.participants < T::MaxGroupBuyParticipants::get(),
+							Error::<T>::TooManyParticipants
+						);
+						group_buy.participants = group_buy.participants.saturating_add(1);
+						GroupCommitment::default()
+					},
+				};
+				// Tiers are validated on creation, the first one always exists
+				let base_price = group_buy.tiers.first().map_or(0, |tier| tier.price);
+				let amount =
+					base_price.checked_mul(quantity as u128).ok_or(Error::<T>::PriceOverflow)?;
+				Self::escrow_payment(ngo, &Self::single_payment(group_buy.category, amount))?;
+				commitment.quantity = commitment.quantity.saturating_add(quantity);
+				commitment.amount = commitment.amount.saturating_add(amount);
+				<GroupCommitments<T>>::insert(group_buy_id, ngo, commitment);
+				group_buy.committed = committed;
+				Ok(amount)
+			})
+		}
+
+		/// Close a Group Buy after its deadline. If the threshold was reached an Order is placed
+		/// for each Ngo's share at the price of the tier reached and overpaid PBT is refunded,
+		/// otherwise every commitment is refunded.
+		#[transactional]
+		pub fn do_close_group_buy(group_buy_id: GroupBuyId) -> DispatchResult {
+			let mut group_buy =
+				<GroupBuys<T>>::get(group_buy_id).ok_or(Error::<T>::GroupBuyNotFound)?;
+			ensure!(group_buy.status == GroupBuyStatus::Open, Error::<T>::GroupBuyNotOpen);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= group_buy.deadline,
+				Error::<T>::GroupBuyDeadlineNotReached
+			);
+			let tier = group_buy
+				.tiers
+				.iter()
+				.rev()
+				.find(|tier| group_buy.committed >= tier.min_quantity)
+				.copied();
+			let seller_active =
+				participant_handler::pallet::Pallet::<T>::is_active_seller(&group_buy.owner);
+			let tier = match tier {
+				Some(tier) if seller_active => tier,
+				_ => {
+					Self::refund_group_commitments(group_buy_id, group_buy.category)?;
+					group_buy.status = GroupBuyStatus::Failed;
+					<GroupBuys<T>>::insert(group_buy_id, group_buy);
+					Self::deposit_event(Event::GroupBuyFailed { group_buy_id });
+					return Ok(())
+				},
+			};
+			let escrow = Self::get_escrow_account();
+			let commitments: Vec<(T::AccountId, GroupCommitment)> =
+				<GroupCommitments<T>>::iter_prefix(group_buy_id).collect();
+			for (ngo, mut commitment) in commitments {
+				let amount = tier.price.saturating_mul(commitment.quantity as u128);
+				let refund = commitment.amount.saturating_sub(amount);
+				if !refund.is_zero() {
+					<T as Config>::TokenHandler::transfer(
+						group_buy.category.get_id() as u128,
+						&escrow,
+						&ngo,
+						refund,
+						false,
+					)?;
+					Self::deposit_event(Event::GroupBuyRefunded {
+						group_buy_id,
+						ngo: ngo.clone(),
+						amount: refund,
+					});
+				}
+				let order_id = Self::insert_order(Order {
+					source: OrderSource::GroupBuy(group_buy_id),
+					buyer: ngo.clone(),
+					seller: group_buy.owner.clone(),
+					quantity: commitment.quantity,
+					amount,
+					payment: Self::single_payment(group_buy.category, amount),
+					status: OrderStatus::Placed,
+					tracking: None,
+					placed_at: frame_system::Pallet::<T>::block_number(),
+					shipped_at: None,
+				});
+				commitment.amount = amount;
+				commitment.order_id = Some(order_id);
+				<GroupCommitments<T>>::insert(group_buy_id, &ngo, commitment);
+				Self::deposit_event(Event::GroupBuyShareOrdered {
+					group_buy_id,
+					ngo,
+					order_id,
+					quantity: commitment.quantity,
+					amount,
+				});
+			}
+			group_buy.status = GroupBuyStatus::Executed;
+			let quantity = group_buy.committed;
+			<GroupBuys<T>>::insert(group_buy_id, group_buy);
+			Self::deposit_event(Event::GroupBuyExecuted {
+				group_buy_id,
+				quantity,
+				price: tier.price,
+			});
+			Ok(())
+		}
+
+		#[transactional]
+		pub fn do_cancel_group_buy(
+			owner: &T::AccountId,
+			group_buy_id: GroupBuyId,
+		) -> DispatchResult {
+			let mut group_buy =
+				<GroupBuys<T>>::get(group_buy_id).ok_or(Error::<T>::GroupBuyNotFound)?;
+			ensure!(group_buy.owner == *owner, Error::<T>::NotGroupBuyOwner);
+			ensure!(group_buy.status == GroupBuyStatus::Open, Error::<T>::GroupBuyNotOpen);
+			Self::refund_group_commitments(group_buy_id, group_buy.category)?;
+			group_buy.status = GroupBuyStatus::Cancelled;
+			<GroupBuys<T>>::insert(group_buy_id, group_buy);
+			Ok(())
+		}
+
+		/// Return the escrowed PBT of every commitment to a Group Buy.
+		fn refund_group_commitments(
+			group_buy_id: GroupBuyId,
+			category: Category,
+		) -> DispatchResult {
+			let escrow = Self::get_escrow_account();
+			for (ngo, commitment) in <GroupCommitments<T>>::drain_prefix(group_buy_id) {
+				if !commitment.amount.is_zero() {
+					<T as Config>::TokenHandler::transfer(
+						category.get_id() as u128,
+						&escrow,
+						&ngo,
+						commitment.amount,
+						false,
+					)?;
+				}
+				Self::deposit_event(Event::GroupBuyRefunded {
+					group_buy_id,
+					ngo,
+					amount: commitment.amount,
+				});
+			}
+			Ok(())
+		}
+
+		pub fn do_propose_supply_contract(
+			ngo: &T::AccountId,
+			pid: T::Hash,
+			quantity: u32,
+			price: u128,
+			period: T::BlockNumber,
+		) -> Result<(ContractId, T::AccountId), DispatchError> {
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_active_ngo(ngo),
+				Error::<T>::BuyerNotValid
+			);
+			ensure!(!quantity.is_zero(), Error::<T>::InvalidQuantity);
+			ensure!(!period.is_zero(), Error::<T>::InvalidPeriod);
+			price.checked_mul(quantity as u128).ok_or(Error::<T>::PriceOverflow)?;
+			let product = Self::listed_product(pid)?;
+			ensure!(product.status != Status::Recalled, Error::<T>::ProductRecalled);
+			let contract_id = <NextContractId<T>>::mutate(|next_id| {
+				let contract_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				contract_id
+			});
+			let contract = SupplyContract {
+				ngo: ngo.clone(),
+				seller: product.owner.clone(),
+				pid,
+				category: product.category,
+				quantity,
+				price,
+				period,
+				status: ContractStatus::Proposed,
+				current_order: None,
+				next_due: Zero::zero(),
+				ends_at: None,
+				missed: 0,
+			};
+			<SupplyContracts<T>>::insert(contract_id, contract);
+			Ok((contract_id, product.owner))
+		}
+
+		#[transactional]
+		pub fn do_accept_supply_contract(
+			seller: &T::AccountId,
+			contract_id: ContractId,
+		) -> DispatchResult {
+			let mut contract =
+				<SupplyContracts<T>>::get(contract_id).ok_or(Error::<T>::ContractNotFound)?;
+			ensure!(contract.seller == *seller, Error::<T>::NotContractParty);
+			ensure!(contract.status == ContractStatus::Proposed, Error::<T>::InvalidContractStatus);
+			Self::start_supply_cycle(contract_id, &mut contract)?;
+			contract.status = ContractStatus::Active;
+			<SupplyContracts<T>>::insert(contract_id, contract);
+			Ok(())
+		}
+
+		pub fn do_terminate_supply_contract(
+			who: &T::AccountId,
+			contract_id: ContractId,
+		) -> DispatchResult {
+			let mut contract =
+				<SupplyContracts<T>>::get(contract_id).ok_or(Error::<T>::ContractNotFound)?;
+			ensure!(contract.ngo == *who || contract.seller == *who, Error::<T>::NotContractParty);
+			match contract.status {
+				ContractStatus::Proposed => {
+					contract.status = ContractStatus::Terminated;
+					Self::deposit_event(Event::SupplyContractTerminated { contract_id });
+				},
+				ContractStatus::Active => {
+					ensure!(contract.ends_at.is_none(), Error::<T>::NoticeAlreadyGiven);
+					let ends_at = frame_system::Pallet::<T>::block_number()
+						.saturating_add(T::ContractNoticePeriod::get());
+					contract.ends_at = Some(ends_at);
+					Self::deposit_event(Event::SupplyContractNoticeGiven {
+						contract_id,
+						by: who.clone(),
+						ends_at,
+					});
+				},
+				ContractStatus::Terminated => return Err(Error::<T>::InvalidContractStatus.into()),
+			}
+			<SupplyContracts<T>>::insert(contract_id, contract);
+			Ok(())
+		}
+
+		/// Escrow the PBT of the next delivery of a Supply Contract, placing its Order and
+		/// scheduling its due block.
+		#[transactional]
+		fn start_supply_cycle(
+			contract_id: ContractId,
+			contract: &mut SupplyContract<T::AccountId, T::Hash, T::BlockNumber>,
+		) -> DispatchResult {
+			let product = Self::listed_product(contract.pid)?;
+			ensure!(product.status != Status::Recalled, Error::<T>::ProductRecalled);
+			ensure!(
+				participant_handler::pallet::Pallet::<T>::is_active_seller(&contract.seller),
+				Error::<T>::SellerNotValid
+			);
+			let amount = contract
+				.price
+				.checked_mul(contract.quantity as u128)
+				.ok_or(Error::<T>::PriceOverflow)?;
+			let payment = Self::single_payment(contract.category, amount);
+			Self::escrow_payment(&contract.ngo, &payment)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			let due =
+				Self::schedule_contract_due(contract_id, now.saturating_add(contract.period))?;
+			let order_id = Self::insert_order(Order {
+				source: OrderSource::SupplyContract(contract_id),
+				buyer: contract.ngo.clone(),
+				seller: contract.seller.clone(),
+				quantity: contract.quantity,
+				amount,
+				payment,
+				status: OrderStatus::Placed,
+				tracking: None,
+				placed_at: now,
+				shipped_at: None,
+			});
+			contract.current_order = Some(order_id);
+			contract.next_due = due;
+			Self::deposit_event(Event::SupplyCycleStarted { contract_id, order_id, due });
+			Ok(())
+		}
+
+		/// Schedule a Supply Contract delivery at `due` or, when that block is full, at the first
+		/// of the next `MAX_DUE_SPILLOVER` blocks with room. Returns the block it is due at.
+		fn schedule_contract_due(
+			contract_id: ContractId,
+			due: T::BlockNumber,
+		) -> Result<T::BlockNumber, DispatchError> {
+			let mut block = due;
+			for _ in 0..=MAX_DUE_SPILLOVER {
+				if <ContractsDueAt<T>>::try_append(block, contract_id).is_ok() {
+					return Ok(block)
+				}
+				block = block.saturating_add(1u32.into());
+			}
+			Err(Error::<T>::TooManyContractsDue.into())
+		}
+
+		/// Close the current cycle of a Supply Contract due at `now`, refunding its Order and
+		/// rating it as a missed delivery against the seller if it was not shipped, then start the
+		/// next cycle. The contract ends once its notice has run out or, with a
+		/// `SupplyCycleFailed` event giving the reason, once the next cycle cannot start.
+		pub fn do_process_supply_contract(contract_id: ContractId, now: T::BlockNumber) {
+			let mut contract = match <SupplyContracts<T>>::get(contract_id) {
+				Some(contract)
+					if contract.status == ContractStatus::Active && contract.next_due == now =>
+					contract,
+				_ => return,
+			};
+			if let Some(order_id) = contract.current_order {
+				if let Some(mut order) = <Orders<T>>::get(order_id) {
+					if order.status == OrderStatus::Placed &&
+						Self::release_escrow(order_id, &mut order, order.amount).is_ok()
+					{
+						contract.missed = contract.missed.saturating_add(1);
+						<MissedDeliveries<T>>::mutate(&contract.seller, |missed| {
+							*missed = missed.saturating_add(1)
+						});
+						// The missed delivery rates the Order, so its buyer cannot rate it again
+						Self::add_seller_rating(&contract.seller, MIN_SCORE);
+						<Ratings<T>>::insert(
+							order_id,
+							Rating { seller_score: MIN_SCORE, product_score: None, review: None },
+						);
+						Self::deposit_event(Event::DeliveryMissed {
+							contract_id,
+							order_id,
+							seller: contract.seller.clone(),
+						});
+					}
+				}
+			}
+			let ended = contract.ends_at.map_or(false, |ends_at| now >= ends_at);
+			let terminated = ended ||
+				match Self::start_supply_cycle(contract_id, &mut contract) {
+					Ok(()) => false,
+					Err(error) => {
+						Self::deposit_event(Event::SupplyCycleFailed { contract_id, error });
+						true
+					},
+				};
+			if terminated {
+				contract.status = ContractStatus::Terminated;
+				Self::deposit_event(Event::SupplyContractTerminated { contract_id });
+			}
+			<SupplyContracts<T>>::insert(contract_id, contract);
+		}
+
+		pub fn do_add_discount(
+			seller: &T::AccountId,
+			pid: T::Hash,
+			rule: DiscountRule<T::AccountId, T::Hash, T::BlockNumber>,
+		) -> Result<DiscountId, DispatchError> {
+			let product = Self::listed_product(pid)?;
+			ensure!(product.owner == *seller, Error::<T>::NotProductOwner);
+			ensure!(rule.ends_at > rule.starts_at, Error::<T>::InvalidDiscountWindow);
+			ensure!(
+				rule.coupon.is_none() || !rule.ngos.is_empty() || !rule.categories.is_empty(),
+				Error::<T>::UnrestrictedCoupon
+			);
+			ensure!(
+				(<Discounts<T>>::iter_key_prefix(pid).count() as u32) < MAX_DISCOUNTS_PER_PRODUCT,
+				Error::<T>::TooManyDiscounts
+			);
+			let discount_id = <NextDiscountId<T>>::mutate(|next_id| {
+				let discount_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				discount_id
+			});
+			<Discounts<T>>::insert(pid, discount_id, rule);
+			Ok(discount_id)
+		}
+
+		/// Record the redemption of the discount `buyer` gets on `amount` for the Product `pid`:
+		/// the largest discount matching `coupon` if one is presented, otherwise the largest
+		/// discount without a coupon.
+		fn redeem_discount(
+			buyer: &T::AccountId,
+			pid: T::Hash,
+			amount: u128,
+			coupon: Option<CouponCode>,
+		) -> Result<Option<AppliedDiscount>, DispatchError> {
+			let now = frame_system::Pallet::<T>::block_number();
+			let coupon = coupon.map(|code| T::Hashing::hash(&code[..]));
+			let discount = <Discounts<T>>::iter_prefix(pid)
+				.filter(|(discount_id, rule)| {
+					rule.coupon == coupon && Self::can_redeem(buyer, *discount_id, rule, now)
+				})
+				.map(|(discount_id, rule)| AppliedDiscount {
+					discount_id,
+					amount: rule.kind.discount(amount),
+				})
+				.max_by_key(|discount| discount.amount);
+			match discount {
+				Some(discount) => <DiscountRedemptions<T>>::mutate(discount.discount_id, |count| {
+					*count = count.saturating_add(1)
+				}),
+				None => ensure!(coupon.is_none(), Error::<T>::InvalidCoupon),
+			}
+			Ok(discount)
+		}
+
+		/// Whether `buyer` can redeem the discount at block `now`
+		fn can_redeem(
+			buyer: &T::AccountId,
+			discount_id: DiscountId,
+			rule: &DiscountRule<T::AccountId, T::Hash, T::BlockNumber>,
+			now: T::BlockNumber,
+		) -> bool {
+			now >= rule.starts_at &&
+				now < rule.ends_at &&
+				rule.max_redemptions.map_or(true, |max_redemptions| {
+					<DiscountRedemptions<T>>::get(discount_id) < max_redemptions
+				}) && (rule.ngos.is_empty() || rule.ngos.contains(buyer)) &&
+				(rule.categories.is_empty() ||
+					rule.categories.iter().any(|category| {
+						participant_handler::pallet::Pallet::<T>::is_ngo_category_allowed(
+							buyer, category,
+						)
+					}))
+		}
+
+		/// Whether the Product has reached its expiry block
+		pub fn is_expired(pid: T::Hash) -> bool {
+			<ProductExpiry<T>>::get(pid)
+				.map_or(false, |expires_at| frame_system::Pallet::<T>::block_number() >= expires_at)
+		}
+
+		/// Recall a Product on behalf of its owner `caller`, or of the recall origin if `None`.
+		/// Fails if the Product has more than `max_orders` Orders or, with `refund`, its seller
+		/// has more than `max_payouts` pending payouts.
+		#[transactional]
+		pub fn do_recall_product(
+			caller: Option<&T::AccountId>,
+			pid: T::Hash,
+			reason: T::Hash,
+			refund: bool,
+			max_orders: u32,
+			max_payouts: u32,
+		) -> DispatchResult {
+			let mut product = <ProductMap<T>>::get(pid).ok_or(Error::<T>::ProductNotFound)?;
+			if let Some(caller) = caller {
+				ensure!(product.owner == *caller, Error::<T>::NotProductOwner);
+			}
+			ensure!(product.status != Status::Recalled, Error::<T>::ProductRecalled);
+			let order_ids: Vec<OrderId> = <OrdersByProduct<T>>::iter_key_prefix(pid).collect();
+			ensure!(order_ids.len() as u32 <= max_orders, Error::<T>::BadWitness);
+			let mut payouts = if refund {
+				donation_handler::Pallet::<T>::seller_pending_payouts(&product.owner, max_payouts)?
+			} else {
+				Vec::new()
+			};
+			product.status = Status::Recalled;
+			<ProductsByCategory<T>>::remove(product.category, pid);
+			<ProductMap<T>>::insert(pid, product);
+			Self::deposit_event(Event::ProductRecalled { pid, reason, refund });
+			for order_id in order_ids {
+				let mut order = match <Orders<T>>::get(order_id) {
+					Some(order) => order,
+					None => continue,
+				};
+				Self::deposit_event(Event::RecallNotified {
+					pid,
+					order_id,
+					buyer: order.buyer.clone(),
+				});
+				if refund {
+					let refunded = Self::refund_recalled_order(order_id, &mut order, &mut payouts)?;
+					if !refunded.is_zero() {
+						Self::deposit_event(Event::RecallRefunded {
+							order_id,
+							buyer: order.buyer,
+							amount: refunded,
+						});
+					}
+				}
+			}
+			Ok(())
+		}
+
+		/// Refund an Order of a recalled Product from escrow or, once settled, from the seller's
+		/// pending `payouts` and the fee receiver's commission. The commission is only reversed in
+		/// proportion to what the payouts refunded, the rest stays recorded for the Order.
+		/// Disputed Orders are left to arbitration.
+		fn refund_recalled_order(
+			order_id: OrderId,
+			order: &mut Order<T::AccountId, T::Hash, T::BlockNumber>,
+			payouts: &mut Vec<(PayoutId, PendingPayout<T::AccountId, T::BlockNumber>)>,
+		) -> Result<u128, DispatchError> {
+			match order.status {
+				OrderStatus::Placed | OrderStatus::Shipped => {
+					let amount = order.amount;
+					Self::release_escrow(order_id, order, amount)?;
+					Ok(amount)
+				},
+				OrderStatus::Settled => {
+					let commissions = <OrderCommissions<T>>::take(order_id).unwrap_or_default();
+					let mut unreversed = Payment::default();
+					let mut refunded: u128 = 0;
+					for (category, part) in order.payment.iter() {
+						let commission = commissions
+							.iter()
+							.find(|(charged, _)| charged == category)
+							.map_or(0, |(_, commission)| *commission);
+						let seller_share = part.saturating_sub(commission);
+						let part_refunded = donation_handler::Pallet::<T>::do_refund_from_payouts(
+							payouts,
+							category,
+							seller_share,
+							&order.buyer,
+						)?;
+						refunded = refunded.saturating_add(part_refunded);
+						let reversal = if seller_share.is_zero() {
+							0
+						} else {
+							Perbill::from_rational(part_refunded, seller_share) * commission
+						};
+						// A fee receiver short of funds only leaves the Order partly refunded
+						let reversed = !reversal.is_zero() &&
+							donation_handler::Pallet::<T>::do_reissue(
+								&T::FeeReceiver::get(),
+								category,
+								reversal,
+								&order.buyer,
+							)
+							.is_ok();
+						let kept = if reversed {
+							refunded = refunded.saturating_add(reversal);
+							commission.saturating_sub(reversal)
+						} else {
+							commission
+						};
+						if !kept.is_zero() {
+							// There are never more parts left than were taken
+							let _ = unreversed.try_push((*category, kept));
+						}
+					}
+					if !unreversed.is_empty() {
+						<OrderCommissions<T>>::insert(order_id, unreversed);
+					}
+					if refunded == order.amount {
+						order.status = OrderStatus::Refunded;
+						<Orders<T>>::insert(order_id, &*order);
+					}
+					Ok(refunded)
+				},
+				_ => Ok(0),
+			}
+		}
+
+		fn single_payment(category: Category, amount: u128) -> Payment {
+			let mut payment = Payment::default();
+			// A single part always fits in a payment
+			let _ = payment.try_push((category, amount));
+			payment
+		}
+
+		/// Ensure every part of `payment` is in a distinct accepted category and that the parts
+		/// add up to `amount`.
+		fn ensure_valid_payment(
+			payment: &Payment,
+			accepted_categories: &AcceptedCategories,
+			amount: u128,
+		) -> DispatchResult {
+			let mut total: u128 = 0;
+			for (index, (category, part)) in payment.iter().enumerate() {
+				ensure!(accepted_categories.contains(category), Error::<T>::CategoryNotAccepted);
+				ensure!(
+					!payment.iter().skip(index + 1).any(|(other, _)| other == category),
+					Error::<T>::DuplicatePaymentCategory
+				);
+				total = total.checked_add(*part).ok_or(Error::<T>::PriceOverflow)?;
+			}
+			ensure!(total == amount, Error::<T>::PaymentMismatch);
+			Ok(())
+		}
+
+		fn escrow_payment(buyer: &T::AccountId, payment: &Payment) -> DispatchResult {
+			let escrow = Self::get_escrow_account();
+			for (category, part) in payment.iter().filter(|(_, part)| !part.is_zero()) {
+				<T as Config>::TokenHandler::transfer(
+					category.get_id() as u128,
+					buyer,
+					&escrow,
+					*part,
+					false,
+				)?;
+			}
+			Ok(())
+		}
+
+		fn insert_order(order: Order<T::AccountId, T::Hash, T::BlockNumber>) -> OrderId {
+			let order_id = <NextOrderId<T>>::mutate(|next_id| {
+				let order_id = *next_id;
+				*next_id = next_id.saturating_add(1);
+				order_id
+			});
+			<OrdersByBuyer<T>>::insert(&order.buyer, order_id, ());
+			<OrdersBySeller<T>>::insert(&order.seller, order_id, ());
+			<Orders<T>>::insert(order_id, order);
+			order_id
+		}
+
+		pub fn do_mark_shipped(
+			seller: &T::AccountId,
+			order_id: OrderId,
+			tracking: T::Hash,
+		) -> DispatchResult {
+			<Orders<T>>::try_mutate(order_id, |order| {
+				let order = order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
+				ensure!(order.seller == *seller, Error::<T>::NotOrderSeller);
+				ensure!(
+					matches!(order.status, OrderStatus::Placed | OrderStatus::Shipped),
+					Error::<T>::InvalidOrderStatus
+				);
+				if order.shipped_at.is_none() {
+					order.shipped_at = Some(frame_system::Pallet::<T>::block_number());
+				}
+				order.status = OrderStatus::Shipped;
+				order.tracking = Some(tracking);
+				Ok(())
+			})
+		}
+
+		pub fn do_confirm_delivery(buyer: &T::AccountId, order_id: OrderId) -> DispatchResult {
+			<Orders<T>>::try_mutate(order_id, |order| {
+				let order = order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
+				ensure!(order.buyer == *buyer, Error::<T>::NotOrderBuyer);
+				ensure!(
+					matches!(order.status, OrderStatus::Placed | OrderStatus::Shipped),
+					Error::<T>::InvalidOrderStatus
+				);
+				order.status = OrderStatus::Delivered;
+				Ok(())
+			})
+		}
+
+		/// Release escrowed tokens of a delivered Order, or of a shipped Order whose delivery
+		/// timeout has passed, to its seller.
+		#[transactional]
+		pub fn do_settle(order_id: OrderId) -> DispatchResult {
+			let mut order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			match (order.status, order.shipped_at) {
+				(OrderStatus::Delivered, _) => (),
+				(OrderStatus::Shipped, Some(shipped_at)) => ensure!(
+					frame_system::Pallet::<T>::block_number() >=
+						shipped_at.saturating_add(T::DeliveryTimeout::get()),
+					Error::<T>::DeliveryTimeoutNotReached
+				),
+				_ => return Err(Error::<T>::InvalidOrderStatus.into()),
+			}
+			Self::release_escrow(order_id, &mut order, 0)?;
+			Self::deposit_event(Event::OrderSettled {
+				order_id,
+				seller: order.seller,
+				amount: order.amount,
+			});
+			Ok(())
+		}
+
+		/// Refund `refund` of the escrowed tokens to the buyer and release the rest to the
+		/// seller, closing the Order. Refunds are taken from the payment parts in order.
+		fn release_escrow(
+			order_id: OrderId,
+			order: &mut Order<T::AccountId, T::Hash, T::BlockNumber>,
+			refund: u128,
+		) -> Result<u128, DispatchError> {
+			let escrow = Self::get_escrow_account();
+			let released = order.amount.checked_sub(refund).ok_or(Error::<T>::RefundTooLarge)?;
+			let mut to_refund = refund;
+			let mut commissions = Payment::default();
+			for (category, part) in order.payment.iter() {
+				let asset = category.get_id() as u128;
+				let refunded = to_refund.min(*part);
+				to_refund = to_refund.saturating_sub(refunded);
+				if !refunded.is_zero() {
+					<T as Config>::TokenHandler::transfer(
+						asset,
+						&escrow,
+						&order.buyer,
+						refunded,
+						false,
+					)?;
+				}
+				let part_released = part.saturating_sub(refunded);
+				if !part_released.is_zero() {
+					let commission = Self::commission(&order.seller, category, part_released);
+					if !commission.is_zero() {
+						donation_handler::Pallet::<T>::do_redeem(
+							&escrow,
+							category,
+							commission,
+							&T::FeeReceiver::get(),
+						)?;
+						// Payments never have more parts than the bound
+						let _ = commissions.try_push((*category, commission));
+						Self::deposit_event(Event::CommissionCharged {
+							order_id,
+							seller: order.seller.clone(),
+							category: *category,
+							amount: commission,
+						});
+					}
+					<T as Config>::TokenHandler::transfer(
+						asset,
+						&escrow,
+						&order.seller,
+						part_released.saturating_sub(commission),
+						false,
+					)?;
+					donation_handler::Pallet::<T>::note_purchase(
+						&order.buyer,
+						&order.seller,
+						category,
+						part_released,
+					);
+				}
+			}
+			if !commissions.is_empty() {
+				<OrderCommissions<T>>::insert(order_id, commissions);
+			}
+			<SellerVolume<T>>::mutate(&order.seller, |volume| {
+				*volume = volume.saturating_add(released)
+			});
+			order.status =
+				if released.is_zero() { OrderStatus::Refunded } else { OrderStatus::Settled };
+			<Orders<T>>::insert(order_id, &*order);
+			if let OrderSource::Listing(pid) = &order.source {
+				<OpenOrdersByProduct<T>>::mutate_exists(pid, |open| {
+					*open = open.map(|open| open.saturating_sub(1)).filter(|open| !open.is_zero())
+				});
+			}
+			Ok(released)
+		}
+
+		/// Commission taken from `amount` paid to `seller` in `category`, after the largest
+		/// discount the seller qualifies for.
+		pub fn commission(seller: &T::AccountId, category: &Category, amount: u128) -> u128 {
+			let rate = <CommissionRates<T>>::get(category);
+			if rate.is_zero() {
+				return 0
+			}
+			let volume = <SellerVolume<T>>::get(seller);
+			let score = <SellerReputation<T>>::get(seller).score;
+			let discount = <CommissionDiscountTiers<T>>::get()
+				.into_iter()
+				.filter(|tier| volume >= tier.min_volume && score >= tier.min_score)
+				.map(|tier| tier.discount)
+				.max()
+				.unwrap_or_default();
+			let commission = rate.mul_floor(amount);
+			commission.saturating_sub(discount.mul_floor(commission))
+		}
+
+		pub fn do_open_dispute(
+			buyer: &T::AccountId,
+			order_id: OrderId,
+			evidence: T::Hash,
+		) -> DispatchResult {
+			<Orders<T>>::try_mutate(order_id, |order| {
+				let order = order.as_mut().ok_or(Error::<T>::OrderNotFound)?;
+				ensure!(order.buyer == *buyer, Error::<T>::NotOrderBuyer);
+				ensure!(
+					matches!(order.status, OrderStatus::Placed | OrderStatus::Shipped),
+					Error::<T>::InvalidOrderStatus
+				);
+				order.status = OrderStatus::Disputed;
+				let dispute = Dispute {
+					evidence,
+					response: None,
+					opened_at: frame_system::Pallet::<T>::block_number(),
+				};
+				<Disputes<T>>::insert(order_id, dispute);
+				Ok(())
+			})
+		}
+
+		pub fn do_respond_to_dispute(
+			seller: &T::AccountId,
+			order_id: OrderId,
+			response: T::Hash,
+		) -> DispatchResult {
+			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(order.seller == *seller, Error::<T>::NotOrderSeller);
+			<Disputes<T>>::try_mutate(order_id, |dispute| {
+				let dispute = dispute.as_mut().ok_or(Error::<T>::DisputeNotFound)?;
+				ensure!(dispute.response.is_none(), Error::<T>::AlreadyResponded);
+				dispute.response = Some(response);
+				Ok(())
+			})
+		}
+
+		/// Close a dispute, moving the escrowed tokens of its Order according to `resolution`.
+		#[transactional]
+		pub fn do_resolve_dispute(
+			order_id: OrderId,
+			resolution: Resolution,
+		) -> Result<Order<T::AccountId, T::Hash, T::BlockNumber>, DispatchError> {
+			let mut order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(order.status == OrderStatus::Disputed, Error::<T>::InvalidOrderStatus);
+			ensure!(<Disputes<T>>::contains_key(order_id), Error::<T>::DisputeNotFound);
+			let refund = match resolution {
+				Resolution::Refund => order.amount,
+				Resolution::PartialRefund(refund) => refund,
+				Resolution::Release => 0,
+			};
+			let released = Self::release_escrow(order_id, &mut order, refund)?;
+			<Disputes<T>>::remove(order_id);
+			Self::deposit_event(Event::DisputeResolved {
+				order_id,
+				resolution,
+				refunded: refund,
+				released,
+			});
+			Ok(order)
+		}
+
+		/// Record the rating of a settled Order and update the reputation of its seller and
+		/// product. Sellers whose reputation drops below the threshold are suspended.
+		pub fn do_rate_order(
+			buyer: &T::AccountId,
+			order_id: OrderId,
+			rating: Rating<T::Hash>,
+		) -> Result<T::AccountId, DispatchError> {
+			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+			ensure!(order.buyer == *buyer, Error::<T>::NotOrderBuyer);
+			ensure!(
+				matches!(order.status, OrderStatus::Settled | OrderStatus::Refunded),
+				Error::<T>::InvalidOrderStatus
+			);
+			ensure!(!<Ratings<T>>::contains_key(order_id), Error::<T>::AlreadyRated);
+			let is_valid_score = |score: u8| (MIN_SCORE..=MAX_SCORE).contains(&score);
+			ensure!(is_valid_score(rating.seller_score), Error::<T>::InvalidScore);
+			if let Some(product_score) = rating.product_score {
+				ensure!(is_valid_score(product_score), Error::<T>::InvalidScore);
+				let pid = match order.source {
+					OrderSource::Listing(pid) => pid,
+					OrderSource::SupplyContract(contract_id) =>
+						<SupplyContracts<T>>::get(contract_id)
+							.map(|contract| contract.pid)
+							.ok_or(Error::<T>::NoProductToRate)?,
+					OrderSource::Tender(_) | OrderSource::Auction(_) | OrderSource::GroupBuy(_) =>
+						return Err(Error::<T>::NoProductToRate.into()),
+				};
+				<ProductReputation<T>>::mutate(pid, |reputation| {
+					reputation.add_rating(product_score)
+				});
+			}
+			Self::add_seller_rating(&order.seller, rating.seller_score);
+			<Ratings<T>>::insert(order_id, rating);
+			Ok(order.seller)
+		}
+
+		/// Add `score` to the reputation of `seller`, suspending them once it drops below the
+		/// threshold.
+		fn add_seller_rating(seller: &T::AccountId, score: u8) {
+			let reputation = <SellerReputation<T>>::mutate(seller, |reputation| {
+				reputation.add_rating(score);
+				*reputation
+			});
+			if reputation.ratings >= T::MinRatingsForSuspension::get() &&
+				reputation.score < T::SuspensionScoreThreshold::get()
+			{
+				participant_handler::Pallet::<T>::suspend_seller(seller);
+			}
+		}
+
+		/// Up to `limit` open and unexpired listings in `category`, starting after the product
+		/// `start_after`
+		pub fn products_by_category(
+			category: Category,
+			start_after: Option<T::Hash>,
+			limit: u32,
+		) -> Vec<(T::Hash, ProductInfo<T::AccountId, T::Hash>)> {
+			let pids = match start_after {
+				Some(pid) => <ProductsByCategory<T>>::iter_key_prefix_from(
+					category,
+					<ProductsByCategory<T>>::hashed_key_for(category, pid),
+				),
+				None => <ProductsByCategory<T>>::iter_key_prefix(category),
+			};
+			Self::collect_products(pids.filter(|pid| !Self::is_expired(*pid)), limit)
+		}
+
+		/// Up to `limit` products listed by `seller`, starting after the product `start_after`
+		pub fn products_by_seller(
+			seller: &T::AccountId,
+			start_after: Option<T::Hash>,
+			limit: u32,
+		) -> Vec<(T::Hash, ProductInfo<T::AccountId, T::Hash>)> {
+			let pids = match start_after {
+				Some(pid) => <ProductsBySeller<T>>::iter_key_prefix_from(
+					seller,
+					<ProductsBySeller<T>>::hashed_key_for(seller, pid),
+				),
+				None => <ProductsBySeller<T>>::iter_key_prefix(seller),
+			};
+			Self::collect_products(pids, limit)
+		}
+
+		/// Up to `limit` products bought by `buyer`, starting after the product `start_after`.
+		/// Delisted products are kept as `Delisted` once bought, so the history stays complete.
+		pub fn products_by_buyer(
+			buyer: &T::AccountId,
+			start_after: Option<T::Hash>,
+			limit: u32,
+		) -> Vec<(T::Hash, ProductInfo<T::AccountId, T::Hash>)> {
+			let pids = match start_after {
+				Some(pid) => <ProductsByBuyer<T>>::iter_key_prefix_from(
+					buyer,
+					<ProductsByBuyer<T>>::hashed_key_for(buyer, pid),
+				),
+				None => <ProductsByBuyer<T>>::iter_key_prefix(buyer),
+			};
+			Self::collect_products(pids, limit)
+		}
+
+		fn collect_products(
+			pids: impl Iterator<Item = T::Hash>,
+			limit: u32,
+		) -> Vec<(T::Hash, ProductInfo<T::AccountId, T::Hash>)> {
+			pids.filter_map(|pid| <ProductMap<T>>::get(pid).map(|product| (pid, product)))
+				.take(limit as usize)
+				.collect()
+		}
+
+		/// Up to `limit` Orders placed by `buyer`, starting after the Order `start_after`
+		pub fn orders_by_buyer(
+			buyer: &T::AccountId,
+			start_after: Option<OrderId>,
+			limit: u32,
+		) -> Vec<(OrderId, Order<T::AccountId, T::Hash, T::BlockNumber>)> {
+			let order_ids = match start_after {
+				Some(order_id) => <OrdersByBuyer<T>>::iter_key_prefix_from(
+					buyer,
+					<OrdersByBuyer<T>>::hashed_key_for(buyer, order_id),
+				),
+				None => <OrdersByBuyer<T>>::iter_key_prefix(buyer),
+			};
+			Self::collect_orders(order_ids, limit)
+		}
+
+		/// Up to `limit` Orders received by `seller`, starting after the Order `start_after`
+		pub fn orders_by_seller(
+			seller: &T::AccountId,
+			start_after: Option<OrderId>,
+			limit: u32,
+		) -> Vec<(OrderId, Order<T::AccountId, T::Hash, T::BlockNumber>)> {
+			let order_ids = match start_after {
+				Some(order_id) => <OrdersBySeller<T>>::iter_key_prefix_from(
+					seller,
+					<OrdersBySeller<T>>::hashed_key_for(seller, order_id),
+				),
+				None => <OrdersBySeller<T>>::iter_key_prefix(seller),
+			};
+			Self::collect_orders(order_ids, limit)
+		}
+
+		fn collect_orders(
+			order_ids: impl Iterator<Item = OrderId>,
+			limit: u32,
+		) -> Vec<(OrderId, Order<T::AccountId, T::Hash, T::BlockNumber>)> {
+			order_ids
+				.filter_map(|order_id| <Orders<T>>::get(order_id).map(|order| (order_id, order)))
+				.take(limit as usize)
+				.collect()
+		}
+
+		pub fn get_escrow_account() -> T::AccountId {
+			T::MarketplacePalletId::get().into_account_truncating()
+		}
+
+		pub fn do_restock(
+			seller: &T::AccountId,
+			pid: T::Hash,
+			quantity: u32,
+		) -> Result<u32, DispatchError> {
+			ensure!(!quantity.is_zero(), Error::<T>::InvalidQuantity);
+			<ProductMap<T>>::try_mutate(pid, |product| {
+				let product = product.as_mut().ok_or(Error::<T>::ProductNotFound)?;
+				ensure!(product.status != Status::Delisted, Error::<T>::ProductNotFound);
+				ensure!(product.owner == *seller, Error::<T>::NotProductOwner);
+				ensure!(product.status != Status::Recalled, Error::<T>::ProductRecalled);
+				product.add_stock(quantity);
+				<ProductsByCategory<T>>::insert(product.category, pid, ());
+				Ok(product.quantity)
+			})
+		}
+
+		fn deposit_stock_events(pid: T::Hash, remaining: u32) {
+			Self::deposit_event(Event::StockUpdated { pid, remaining });
+			if remaining.is_zero() {
+				Self::deposit_event(Event::ProductSoldOut { pid });
+			}
+		}
 	}
 }
 // TODO
@@ -0,0 +1,121 @@
+//! Storage migrations for the marketplace pallet.
+
+/// Products gained accepted payment categories, a reference price asset and a stock quantity,
+/// and are indexed by seller and, while open, by category. A sale used to hand the product over
+/// to its buyer, so sold products are indexed by buyer instead, their seller is not recorded.
+pub mod v1 {
+	use crate::{
+		Config, Pallet, ProductInfo, ProductMap, ProductsByBuyer, ProductsByCategory,
+		ProductsBySeller, Status,
+	};
+	use frame_support::{
+		log,
+		pallet_prelude::*,
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+	};
+	use participant_handler::Category;
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	#[derive(Decode)]
+	struct OldProductInfo<AccountId, Hash> {
+		category: Category,
+		price: u128,
+		status: Status,
+		owner: AccountId,
+		cid: Hash,
+	}
+
+	impl<AccountId, Hash> From<OldProductInfo<AccountId, Hash>> for ProductInfo<AccountId, Hash> {
+		fn from(old: OldProductInfo<AccountId, Hash>) -> Self {
+			let mut accepted_categories = BoundedVec::default();
+			// The bound is far above a single category
+			let _ = accepted_categories.try_push(old.category);
+			ProductInfo {
+				category: old.category,
+				accepted_categories,
+				price: old.price,
+				price_asset: None,
+				// Listings used to sell a single unit
+				quantity: if old.status == Status::OpenForSell { 1 } else { 0 },
+				status: old.status,
+				owner: old.owner,
+				cid: old.cid,
+			}
+		}
+	}
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!(
+					target: "marketplace",
+					"Skipping migration to v1, storage is already at {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let (mut translated, mut indexed) = (0u64, 0u64);
+			<ProductMap<T>>::translate::<OldProductInfo<T::AccountId, T::Hash>, _>(|pid, old| {
+				let product = ProductInfo::from(old);
+				match product.status {
+					Status::Sold => <ProductsByBuyer<T>>::insert(&product.owner, pid, ()),
+					_ => <ProductsBySeller<T>>::insert(&product.owner, pid, ()),
+				}
+				if product.status == Status::OpenForSell {
+					<ProductsByCategory<T>>::insert(product.category, pid, ());
+					indexed += 1;
+				}
+				translated += 1;
+				Some(product)
+			});
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "marketplace", "Migrated {} products to v1", translated);
+			T::DbWeight::get().reads_writes(
+				translated.saturating_add(1),
+				translated.saturating_mul(2).saturating_add(indexed).saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			Ok((<ProductMap<T>>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let products: u32 =
+				Decode::decode(&mut &state[..]).map_err(|_| "Cannot decode product count")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "Storage version not updated");
+			ensure!(
+				<ProductMap<T>>::iter_values().count() as u32 == products,
+				"Products lost in migration"
+			);
+			for (pid, product) in <ProductMap<T>>::iter() {
+				let (by_buyer, by_seller) = (
+					<ProductsByBuyer<T>>::contains_key(&product.owner, pid),
+					<ProductsBySeller<T>>::contains_key(&product.owner, pid),
+				);
+				if product.status == Status::Sold {
+					ensure!(by_buyer, "Sold product missing from its buyer index");
+					ensure!(!by_seller, "Sold product indexed under its buyer as seller");
+				} else {
+					ensure!(by_seller, "Product missing from its seller index");
+					ensure!(!by_buyer, "Unsold product indexed by buyer");
+				}
+				ensure!(
+					product.status != Status::OpenForSell ||
+						<ProductsByCategory<T>>::contains_key(product.category, pid),
+					"Open product missing from its category index"
+				);
+			}
+			Ok(())
+		}
+	}
+}
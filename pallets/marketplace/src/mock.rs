@@ -5,12 +5,15 @@ use frame_support::{
 	PalletId,
 };
 use frame_system as system;
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
 use sp_core::{ConstU32, H256};
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError,
 };
+use std::cell::RefCell;
+use support::{Pool, AMM};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -110,6 +113,69 @@ impl pallet_assets::Config for Test {
 
 parameter_types! {
 	pub const DonationPalletId: PalletId = PalletId(*b"py/nopls");
+	pub const MarketplacePalletId: PalletId = PalletId(*b"mp/escrw");
+	pub const DeliveryTimeout: u64 = 20;
+	pub const DisputeTimeout: u64 = 30;
+	pub const MinRatingsForSuspension: u32 = 2;
+	pub const SuspensionScoreThreshold: u32 = 200;
+	pub const NativeCurrencyId: u128 = 0;
+	pub const TwapPeriod: u64 = 10;
+	pub const MaxAuctionsPerBlock: u32 = 2;
+	pub const FeeReceiver: u64 = 99;
+	pub const MaxGroupBuyParticipants: u32 = 3;
+	pub const MaxContractsPerBlock: u32 = 2;
+	pub const ContractNoticePeriod: u64 = 5;
+}
+
+thread_local! {
+	static POOL: RefCell<Option<Pool<u128, u128, u64>>> = RefCell::new(None);
+}
+
+/// Set the pool returned for any asset pair by [`MockAmm`].
+pub fn set_pool(pool: Option<Pool<u128, u128, u64>>) {
+	POOL.with(|p| *p.borrow_mut() = pool);
+}
+
+/// AMM with a single pool, only used as a price source.
+pub struct MockAmm;
+
+impl AMM<u64, u128, u128, u64> for MockAmm {
+	fn get_amounts_out(_: u128, _: Vec<u128>) -> Result<Vec<u128>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn get_amounts_in(_: u128, _: Vec<u128>) -> Result<Vec<u128>, DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn swap(_: &u64, _: (u128, u128), _: u128) -> Result<(), DispatchError> {
+		Err(DispatchError::Other("unsupported"))
+	}
+
+	fn get_pools() -> Result<Vec<(u128, u128)>, DispatchError> {
+		Ok(Vec::new())
+	}
+
+	fn get_pool_by_lp_asset(_: u128) -> Option<(u128, u128, Pool<u128, u128, u64>)> {
+		None
+	}
+
+	fn get_pool_by_asset_pair(_: (u128, u128)) -> Option<Pool<u128, u128, u64>> {
+		POOL.with(|p| *p.borrow())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct BenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl marketplace::BenchmarkHelper for BenchmarkHelper {
+	fn create_price_source(asset: u128) {
+		let mut pool = Pool::new(asset + 1);
+		pool.base_amount = 1_000_000;
+		pool.quote_amount = 1_000_000;
+		set_pool(Some(pool));
+	}
 }
 
 impl marketplace::Config for Test {
@@ -117,6 +183,30 @@ impl marketplace::Config for Test {
 	type TokenHandler = TokenHandler;
 	type Currency = Balances;
 	type DonationPalletId = DonationPalletId;
+	type MarketplacePalletId = MarketplacePalletId;
+	type DeliveryTimeout = DeliveryTimeout;
+	type ArbitratorOrigin = EnsureRoot<Self::AccountId>;
+	type RecallOrigin = EnsureRoot<Self::AccountId>;
+	type CommissionOrigin = EnsureRoot<Self::AccountId>;
+	type DisputeTimeout = DisputeTimeout;
+	type MinRatingsForSuspension = MinRatingsForSuspension;
+	type SuspensionScoreThreshold = SuspensionScoreThreshold;
+	type AMM = MockAmm;
+	type NativeCurrencyId = NativeCurrencyId;
+	type TwapPeriod = TwapPeriod;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
+	type FeeReceiver = FeeReceiver;
+	type MaxGroupBuyParticipants = MaxGroupBuyParticipants;
+	type MaxContractsPerBlock = MaxContractsPerBlock;
+	type ContractNoticePeriod = ContractNoticePeriod;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = BenchmarkHelper;
+}
+
+parameter_types! {
+	pub const CapPeriod: u64 = 100;
+	pub const ClaimHoldBackPeriod: u64 = 10;
 }
 
 impl donation_handler::Config for Test {
@@ -124,6 +214,9 @@ impl donation_handler::Config for Test {
 	type TokenHandler = TokenHandler;
 	type Currency = Balances;
 	type DonationPalletId = DonationPalletId;
+	type CapPeriod = CapPeriod;
+	type ClaimHoldBackPeriod = ClaimHoldBackPeriod;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -161,6 +254,7 @@ impl participant_handler::Config for Test {
 	type SellerStakingAmount = SellerStakingAmount;
 	type Currency = Balances;
 	type GovernanceOrigin = EnsureSigned<Self::AccountId>;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,7 +1,26 @@
-use crate::{mock::*, Error, Event, ProductInfo};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	migrations, mock::*, AppliedDiscount, AuctionKind, AuctionStatus, Cart, CommissionTier,
+	ContractStatus, CouponCode, DiscountKind, DiscountRule, Error, Event, GenesisConfig,
+	GroupBuyStatus, OpenOrdersByProduct, OrderSource, OrderStatus, Payment, PriceTier, PriceTiers,
+	ProductInfo, ProductMap, ProductsByBuyer, ProductsByCategory, ProductsBySeller, Reputation,
+	Resolution, Status, TenderStatus,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{
+		fungibles::Inspect, GenesisBuild, GetStorageVersion, Hooks, OnRuntimeUpgrade,
+		ReservableCurrency, StorageVersion,
+	},
+};
 use participant_handler::{Category, NgoInfo, SellerInfo};
-use sp_core::H256;
+use sp_core::{bounded::BoundedVec, H256};
+use sp_runtime::{
+	traits::{AccountIdConversion, BlakeTwo256, Hash},
+	FixedPointNumber, FixedU128, Permill,
+};
+use support::Pool;
 
 #[test]
 fn test_list_product_with_valid_args_returns_ok() {
@@ -11,7 +30,9 @@ fn test_list_product_with_valid_args_returns_ok() {
 		assert_ok!(Marketplace::list_product(
 			RuntimeOrigin::signed(seller),
 			Category::Clothing,
+			Default::default(),
 			1_000_000_000_000,
+			10,
 			H256([2; 32])
 		));
 	})
@@ -25,7 +46,9 @@ fn test_list_product_with_unregistered_seller_returns_err() {
 			Marketplace::list_product(
 				RuntimeOrigin::signed(seller),
 				Category::Clothing,
+				Default::default(),
 				1_000_000_000_000,
+				10,
 				H256([2; 32])
 			),
 			Error::<Test>::SellerNotValid
@@ -41,14 +64,2202 @@ fn test_buy_with_without_valid_product_returns_error() {
 		add_ngo(ngo);
 		add_seller(seller);
 		assert_noop!(
-			Marketplace::buy(RuntimeOrigin::signed(ngo), H256::random()),
+			Marketplace::buy(
+				RuntimeOrigin::signed(ngo),
+				H256::random(),
+				1,
+				Default::default(),
+				None,
+				None
+			),
 			Error::<Test>::ProductNotFound
 		);
 	})
 }
 
+#[test]
+fn test_genesis_config_lists_products() {
+	let seller = 1u64;
+	let seller_info = SellerInfo {
+		categories: BoundedVec::try_from(vec![Category::Pharmaceutical]).unwrap(),
+		cid: H256::default(),
+	};
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(seller, 2 * SellerStakingAmount::get())],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	participant_handler::GenesisConfig::<Test> {
+		ngos: Default::default(),
+		sellers: vec![(seller, seller_info)],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	GenesisConfig::<Test> {
+		products: vec![
+			(seller, Category::Pharmaceutical, 1_000, 5, H256([1; 32])),
+			(seller, Category::Pharmaceutical, 2_000, 5, H256([2; 32])),
+		],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		assert_eq!(<ProductMap<Test>>::iter().count(), 2);
+	})
+}
+
+#[test]
+fn test_identical_listings_get_unique_ids() {
+	new_test_ext().execute_with(|| {
+		let seller = 1u64;
+		add_seller(seller);
+		let first = list_product(seller, 1_000, 1);
+		let second = list_product(seller, 1_000, 1);
+		assert_ne!(first, second);
+		assert_eq!(<ProductMap<Test>>::iter().count(), 2);
+	})
+}
+
+#[test]
+fn test_update_and_delist_product_by_owner_returns_ok() {
+	new_test_ext().execute_with(|| {
+		let seller = 1u64;
+		add_seller(seller);
+		let pid = list_product(seller, 1_000, 1);
+		assert_ok!(Marketplace::update_price(RuntimeOrigin::signed(seller), pid, 2_000));
+		assert_ok!(Marketplace::update_metadata(RuntimeOrigin::signed(seller), pid, H256([9; 32])));
+		let product = Marketplace::get_product_info(pid).unwrap();
+		assert_eq!(product.price, 2_000);
+		assert_eq!(product.cid, H256([9; 32]));
+		System::assert_last_event(Event::ProductMetadataUpdated { pid, cid: H256([9; 32]) }.into());
+
+		assert_ok!(Marketplace::delist(RuntimeOrigin::signed(seller), pid));
+		assert!(Marketplace::get_product_info(pid).is_none());
+		System::assert_last_event(Event::ProductDelisted { pid }.into());
+	})
+}
+
+#[test]
+fn test_update_product_by_non_owner_returns_err() {
+	new_test_ext().execute_with(|| {
+		let seller = 1u64;
+		let other = 3u64;
+		add_seller(seller);
+		add_seller(other);
+		let pid = list_product(seller, 1_000, 1);
+		assert_noop!(
+			Marketplace::update_price(RuntimeOrigin::signed(other), pid, 1),
+			Error::<Test>::NotProductOwner
+		);
+		assert_noop!(
+			Marketplace::delist(RuntimeOrigin::signed(other), pid),
+			Error::<Test>::NotProductOwner
+		);
+	})
+}
+
+#[test]
+fn test_update_sold_product_returns_err() {
+	new_test_ext().execute_with(|| {
+		let seller = 1u64;
+		let ngo = 2u64;
+		add_seller(seller);
+		add_ngo(ngo);
+		create_assets();
+		mint_pbt(ngo, Category::Pharmaceutical, 10_000);
+		let pid = list_product(seller, 1_000, 1);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_eq!(Marketplace::get_product_info(pid).unwrap().status, Status::Sold);
+		assert_noop!(
+			Marketplace::update_price(RuntimeOrigin::signed(seller), pid, 1),
+			Error::<Test>::ProductAlreadySold
+		);
+		assert_noop!(
+			Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 1, Default::default(), None, None),
+			Error::<Test>::ProductAlreadySold
+		);
+	})
+}
+
+#[test]
+fn test_partial_buy_reduces_stock_and_charges_per_unit() {
+	new_test_ext().execute_with(|| {
+		let seller = 1u64;
+		let ngo = 2u64;
+		add_seller(seller);
+		add_ngo(ngo);
+		create_assets();
+		mint_pbt(ngo, Category::Pharmaceutical, 10_000);
+		let pid = list_product(seller, 100, 500);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			30,
+			Default::default(),
+			None,
+			None
+		));
+		let product = Marketplace::get_product_info(pid).unwrap();
+		assert_eq!(product.quantity, 470);
+		assert_eq!(product.status, Status::OpenForSell);
+		assert_eq!(product.owner, seller);
+		System::assert_has_event(
+			Event::OrderPlaced {
+				order_id: 0,
+				buyer: ngo,
+				seller,
+				pid,
+				quantity: 30,
+				amount: 3_000,
+				payment: payment(vec![(Category::Pharmaceutical, 3_000)]),
+				discount: None,
+			}
+			.into(),
+		);
+		System::assert_last_event(Event::StockUpdated { pid, remaining: 470 }.into());
+		assert_noop!(
+			Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 471, Default::default(), None, None),
+			Error::<Test>::InsufficientStock
+		);
+		assert_noop!(
+			Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 0, Default::default(), None, None),
+			Error::<Test>::InvalidQuantity
+		);
+	})
+}
+
+#[test]
+fn test_buy_remaining_stock_closes_listing_and_restock_reopens_it() {
+	new_test_ext().execute_with(|| {
+		let seller = 1u64;
+		let ngo = 2u64;
+		add_seller(seller);
+		add_ngo(ngo);
+		create_assets();
+		mint_pbt(ngo, Category::Pharmaceutical, 10_000);
+		let pid = list_product(seller, 100, 5);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			5,
+			Default::default(),
+			None,
+			None
+		));
+		System::assert_last_event(Event::ProductSoldOut { pid }.into());
+		assert_eq!(Marketplace::get_product_info(pid).unwrap().status, Status::Sold);
+		assert_noop!(
+			Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 1, Default::default(), None, None),
+			Error::<Test>::ProductAlreadySold
+		);
+
+		assert_ok!(Marketplace::restock(RuntimeOrigin::signed(seller), pid, 10));
+		System::assert_last_event(Event::ProductRestocked { pid, added: 10, remaining: 10 }.into());
+		let product = Marketplace::get_product_info(pid).unwrap();
+		assert_eq!(product.quantity, 10);
+		assert_eq!(product.status, Status::OpenForSell);
+		assert_noop!(
+			Marketplace::restock(RuntimeOrigin::signed(ngo), pid, 10),
+			Error::<Test>::NotProductOwner
+		);
+	})
+}
+
+#[test]
+fn test_buy_holds_tokens_in_escrow_and_records_order() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			None,
+			None
+		));
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 300);
+		assert_eq!(pbt_balance(seller), 0);
+		let order = Marketplace::get_order(0).unwrap();
+		assert_eq!(order.status, OrderStatus::Placed);
+		assert_eq!(order.amount, 300);
+		assert_eq!(Marketplace::orders_by_buyer(&ngo, None, 10), vec![(0, order.clone())]);
+		assert_eq!(Marketplace::orders_by_seller(&seller, None, 10), vec![(0, order)]);
+		assert_eq!(Marketplace::get_next_order_id(), 1);
+	})
+}
+
+#[test]
+fn test_confirm_delivery_settles_order() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			None,
+			None
+		));
+		assert_noop!(
+			Marketplace::mark_shipped(RuntimeOrigin::signed(ngo), 0, H256([7; 32])),
+			Error::<Test>::NotOrderSeller
+		);
+		assert_ok!(Marketplace::mark_shipped(RuntimeOrigin::signed(seller), 0, H256([7; 32])));
+		System::assert_last_event(
+			Event::OrderShipped { order_id: 0, tracking: H256([7; 32]) }.into(),
+		);
+		assert_eq!(Marketplace::get_order(0).unwrap().tracking, Some(H256([7; 32])));
+		assert_noop!(
+			Marketplace::confirm_delivery(RuntimeOrigin::signed(seller), 0),
+			Error::<Test>::NotOrderBuyer
+		);
+
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		System::assert_last_event(Event::OrderSettled { order_id: 0, seller, amount: 300 }.into());
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Settled);
+		assert_eq!(pbt_balance(seller), 300);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 0);
+		assert_eq!(DonationHandler::get_seller_stats(seller, Category::Pharmaceutical).sold, 300);
+		assert_noop!(
+			Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0),
+			Error::<Test>::InvalidOrderStatus
+		);
+	})
+}
+
+#[test]
+fn test_settle_order_after_delivery_timeout() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		let anyone = 5u64;
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_noop!(
+			Marketplace::settle_order(RuntimeOrigin::signed(anyone), 0),
+			Error::<Test>::InvalidOrderStatus
+		);
+		assert_ok!(Marketplace::mark_shipped(RuntimeOrigin::signed(seller), 0, H256([7; 32])));
+		System::set_block_number(1 + DeliveryTimeout::get() - 1);
+		assert_noop!(
+			Marketplace::settle_order(RuntimeOrigin::signed(anyone), 0),
+			Error::<Test>::DeliveryTimeoutNotReached
+		);
+		System::set_block_number(1 + DeliveryTimeout::get());
+		assert_ok!(Marketplace::settle_order(RuntimeOrigin::signed(anyone), 0));
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Settled);
+		assert_eq!(pbt_balance(seller), 100);
+	})
+}
+
+#[test]
+fn test_disputed_order_cannot_be_settled_and_is_partially_refunded() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::mark_shipped(RuntimeOrigin::signed(seller), 0, H256([7; 32])));
+		assert_noop!(
+			Marketplace::open_dispute(RuntimeOrigin::signed(seller), 0, H256([8; 32])),
+			Error::<Test>::NotOrderBuyer
+		);
+		assert_ok!(Marketplace::open_dispute(RuntimeOrigin::signed(ngo), 0, H256([8; 32])));
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Disputed);
+		System::set_block_number(1 + DeliveryTimeout::get());
+		assert_noop!(
+			Marketplace::settle_order(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::InvalidOrderStatus
+		);
+
+		assert_ok!(Marketplace::respond_to_dispute(
+			RuntimeOrigin::signed(seller),
+			0,
+			H256([9; 32])
+		));
+		assert_noop!(
+			Marketplace::respond_to_dispute(RuntimeOrigin::signed(seller), 0, H256([9; 32])),
+			Error::<Test>::AlreadyResponded
+		);
+		assert_noop!(
+			Marketplace::resolve_dispute(
+				RuntimeOrigin::signed(ngo),
+				0,
+				Resolution::PartialRefund(100),
+				None
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			Marketplace::resolve_dispute(
+				RuntimeOrigin::root(),
+				0,
+				Resolution::PartialRefund(301),
+				None
+			),
+			Error::<Test>::RefundTooLarge
+		);
+		assert_ok!(Marketplace::resolve_dispute(
+			RuntimeOrigin::root(),
+			0,
+			Resolution::PartialRefund(100),
+			None
+		));
+		System::assert_last_event(
+			Event::DisputeResolved {
+				order_id: 0,
+				resolution: Resolution::PartialRefund(100),
+				refunded: 100,
+				released: 200,
+			}
+			.into(),
+		);
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Settled);
+		assert_eq!(pbt_balance(seller), 200);
+		assert_eq!(pbt_balance(ngo), 10_000 - 200);
+		assert!(Marketplace::get_dispute(0).is_none());
+	})
+}
+
+#[test]
+fn test_resolve_dispute_with_refund_slashes_seller() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		let stake = SellerStakingAmount::get();
+		assert_ok!(Balances::set_balance(RuntimeOrigin::root(), seller, 2 * stake, 0));
+		assert_ok!(ParticipantHandler::reserve_seller_stake(&seller));
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::open_dispute(RuntimeOrigin::signed(ngo), 0, H256([8; 32])));
+		assert_ok!(Marketplace::resolve_dispute(
+			RuntimeOrigin::root(),
+			0,
+			Resolution::Refund,
+			Some(200)
+		));
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Refunded);
+		assert_eq!(pbt_balance(ngo), 10_000);
+		assert_eq!(pbt_balance(seller), 0);
+		assert_eq!(Balances::reserved_balance(seller), stake - 200);
+		System::assert_has_event(
+			participant_handler::Event::SellerSlashed { seller, amount: 200 }.into(),
+		);
+		assert_ok!(Marketplace::rate_order(RuntimeOrigin::signed(ngo), 0, 1, None, None));
+		assert_eq!(Marketplace::get_seller_reputation(seller).ratings, 1);
+	})
+}
+
+#[test]
+fn test_expire_dispute_refunds_when_seller_never_responds() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::open_dispute(RuntimeOrigin::signed(ngo), 0, H256([8; 32])));
+		System::set_block_number(DisputeTimeout::get());
+		assert_noop!(
+			Marketplace::expire_dispute(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::DisputeTimeoutNotReached
+		);
+		System::set_block_number(1 + DisputeTimeout::get());
+		assert_ok!(Marketplace::expire_dispute(RuntimeOrigin::signed(5), 0));
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Refunded);
+		assert_eq!(pbt_balance(ngo), 10_000);
+		assert_eq!(pbt_balance(seller), 0);
+	})
+}
+
+#[test]
+fn test_expire_dispute_refunds_when_response_is_left_unresolved() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::open_dispute(RuntimeOrigin::signed(ngo), 0, H256([8; 32])));
+		assert_ok!(Marketplace::respond_to_dispute(
+			RuntimeOrigin::signed(seller),
+			0,
+			H256([9; 32])
+		));
+		System::set_block_number(1 + DisputeTimeout::get());
+		assert_ok!(Marketplace::expire_dispute(RuntimeOrigin::signed(5), 0));
+		System::assert_last_event(
+			Event::DisputeResolved {
+				order_id: 0,
+				resolution: Resolution::Refund,
+				refunded: 100,
+				released: 0,
+			}
+			.into(),
+		);
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Refunded);
+		assert_eq!(pbt_balance(ngo), 10_000);
+		assert_eq!(pbt_balance(seller), 0);
+		assert!(Marketplace::get_dispute(0).is_none());
+	})
+}
+
+#[test]
+fn test_award_open_tender_places_escrowed_order() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, _) = setup_order_parties(100, 10);
+		let clothing_seller = 4u64;
+		ParticipantHandler::add_seller_to_active_list(
+			&clothing_seller,
+			SellerInfo {
+				categories: BoundedVec::try_from(vec![Category::Clothing]).unwrap(),
+				cid: H256::default(),
+			},
+		);
+		assert_ok!(Marketplace::post_tender(
+			RuntimeOrigin::signed(ngo),
+			Category::Pharmaceutical,
+			20,
+			50,
+			10,
+			H256([3; 32]),
+			false
+		));
+		assert_noop!(
+			Marketplace::submit_bid(RuntimeOrigin::signed(seller), 0, 51),
+			Error::<Test>::BidAboveMaxPrice
+		);
+		assert_noop!(
+			Marketplace::submit_bid(RuntimeOrigin::signed(clothing_seller), 0, 40),
+			Error::<Test>::SellerCategoryNotAllowed
+		);
+		assert_ok!(Marketplace::submit_bid(RuntimeOrigin::signed(seller), 0, 40));
+		assert_noop!(
+			Marketplace::award_tender(RuntimeOrigin::signed(seller), 0, seller),
+			Error::<Test>::NotTenderOwner
+		);
+
+		assert_ok!(Marketplace::award_tender(RuntimeOrigin::signed(ngo), 0, seller));
+		System::assert_last_event(
+			Event::TenderAwarded { tender_id: 0, seller, order_id: 0, amount: 800 }.into(),
+		);
+		assert_eq!(Marketplace::get_tender(0).unwrap().status, TenderStatus::Awarded);
+		assert_eq!(Marketplace::get_bid(0, seller), None);
+		let order = Marketplace::get_order(0).unwrap();
+		assert_eq!(order.source, OrderSource::Tender(0));
+		assert_eq!(order.status, OrderStatus::Placed);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 800);
+		assert_noop!(
+			Marketplace::submit_bid(RuntimeOrigin::signed(seller), 0, 30),
+			Error::<Test>::TenderNotOpen
+		);
+	})
+}
+
+#[test]
+fn test_sealed_bids_are_revealed_after_deadline() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, _) = setup_order_parties(100, 10);
+		let salt = H256([4; 32]);
+		let commitment = BlakeTwo256::hash_of(&(seller, 45u128, salt));
+		assert_ok!(Marketplace::post_tender(
+			RuntimeOrigin::signed(ngo),
+			Category::Pharmaceutical,
+			10,
+			50,
+			10,
+			H256([3; 32]),
+			true
+		));
+		assert_noop!(
+			Marketplace::submit_bid(RuntimeOrigin::signed(seller), 0, 45),
+			Error::<Test>::BidTypeMismatch
+		);
+		assert_ok!(Marketplace::submit_sealed_bid(RuntimeOrigin::signed(seller), 0, commitment));
+		System::assert_last_event(Event::BidSubmitted { tender_id: 0, seller, price: None }.into());
+		assert_noop!(
+			Marketplace::reveal_bid(RuntimeOrigin::signed(seller), 0, 45, salt),
+			Error::<Test>::BiddingStillOpen
+		);
+		assert_noop!(
+			Marketplace::award_tender(RuntimeOrigin::signed(ngo), 0, seller),
+			Error::<Test>::BiddingStillOpen
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Marketplace::submit_sealed_bid(RuntimeOrigin::signed(seller), 0, commitment),
+			Error::<Test>::TenderDeadlinePassed
+		);
+		assert_noop!(
+			Marketplace::award_tender(RuntimeOrigin::signed(ngo), 0, seller),
+			Error::<Test>::BidNotRevealed
+		);
+		assert_noop!(
+			Marketplace::reveal_bid(RuntimeOrigin::signed(seller), 0, 40, salt),
+			Error::<Test>::InvalidReveal
+		);
+		assert_ok!(Marketplace::reveal_bid(RuntimeOrigin::signed(seller), 0, 45, salt));
+		assert_eq!(Marketplace::get_bid(0, seller).unwrap().price, Some(45));
+		assert_ok!(Marketplace::award_tender(RuntimeOrigin::signed(ngo), 0, seller));
+		assert_eq!(Marketplace::get_order(0).unwrap().amount, 450);
+	})
+}
+
+#[test]
+fn test_post_and_cancel_tender() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, _) = setup_order_parties(100, 10);
+		assert_noop!(
+			Marketplace::post_tender(
+				RuntimeOrigin::signed(seller),
+				Category::Pharmaceutical,
+				10,
+				50,
+				10,
+				H256([3; 32]),
+				false
+			),
+			Error::<Test>::BuyerNotValid
+		);
+		assert_noop!(
+			Marketplace::post_tender(
+				RuntimeOrigin::signed(ngo),
+				Category::Pharmaceutical,
+				10,
+				50,
+				1,
+				H256([3; 32]),
+				false
+			),
+			Error::<Test>::InvalidDeadline
+		);
+		assert_ok!(Marketplace::post_tender(
+			RuntimeOrigin::signed(ngo),
+			Category::Pharmaceutical,
+			10,
+			50,
+			10,
+			H256([3; 32]),
+			false
+		));
+		assert_ok!(Marketplace::submit_bid(RuntimeOrigin::signed(seller), 0, 40));
+		assert_eq!(Marketplace::get_tender(0).unwrap().bids, 1);
+		assert_ok!(Marketplace::submit_bid(RuntimeOrigin::signed(seller), 0, 35));
+		assert_eq!(Marketplace::get_tender(0).unwrap().bids, 1);
+		assert_ok!(Marketplace::cancel_tender(RuntimeOrigin::signed(ngo), 0));
+		System::assert_last_event(Event::TenderCancelled { tender_id: 0 }.into());
+		assert_eq!(Marketplace::get_bid(0, seller), None);
+		assert_noop!(
+			Marketplace::submit_bid(RuntimeOrigin::signed(seller), 0, 40),
+			Error::<Test>::TenderNotOpen
+		);
+	})
+}
+
+#[test]
+fn test_rate_settled_order_updates_reputation() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_noop!(
+			Marketplace::rate_order(RuntimeOrigin::signed(ngo), 0, 5, Some(4), None),
+			Error::<Test>::InvalidOrderStatus
+		);
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		assert_noop!(
+			Marketplace::rate_order(RuntimeOrigin::signed(seller), 0, 5, None, None),
+			Error::<Test>::NotOrderBuyer
+		);
+		assert_noop!(
+			Marketplace::rate_order(RuntimeOrigin::signed(ngo), 0, 6, None, None),
+			Error::<Test>::InvalidScore
+		);
+
+		assert_ok!(Marketplace::rate_order(
+			RuntimeOrigin::signed(ngo),
+			0,
+			5,
+			Some(4),
+			Some(H256([6; 32]))
+		));
+		assert_eq!(
+			Marketplace::get_seller_reputation(seller),
+			Reputation { ratings: 1, total: 5, score: 500 }
+		);
+		assert_eq!(Marketplace::get_product_reputation(pid).score, 400);
+		assert_eq!(Marketplace::get_rating(0).unwrap().review, Some(H256([6; 32])));
+		assert_noop!(
+			Marketplace::rate_order(RuntimeOrigin::signed(ngo), 0, 5, None, None),
+			Error::<Test>::AlreadyRated
+		);
+	})
+}
+
+#[test]
+fn test_poor_reputation_suspends_seller() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		for order_id in 0..2 {
+			assert_ok!(Marketplace::buy(
+				RuntimeOrigin::signed(ngo),
+				pid,
+				1,
+				Default::default(),
+				None,
+				None
+			));
+			assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), order_id));
+		}
+		assert_ok!(Marketplace::rate_order(RuntimeOrigin::signed(ngo), 0, 1, None, None));
+		assert!(ParticipantHandler::is_active_seller(&seller));
+		assert_ok!(Marketplace::rate_order(RuntimeOrigin::signed(ngo), 1, 2, None, None));
+		assert_eq!(Marketplace::get_seller_reputation(seller).score, 150);
+		assert!(!ParticipantHandler::is_active_seller(&seller));
+		assert_noop!(
+			Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 1, Default::default(), None, None),
+			Error::<Test>::SellerNotValid
+		);
+	})
+}
+
+#[test]
+fn test_buy_with_split_payment_escrows_each_category() {
+	new_test_ext().execute_with(|| {
+		let seller = 3u64;
+		let ngo = 2u64;
+		add_seller(seller);
+		add_ngo(ngo);
+		create_assets();
+		Balances::set_balance(RuntimeOrigin::root(), seller, 1_000_000, 0);
+		mint_pbt(ngo, Category::Pharmaceutical, 10_000);
+		mint_pbt(ngo, Category::Grocery, 10_000);
+		System::set_block_number(1);
+		assert_ok!(Marketplace::list_product(
+			RuntimeOrigin::signed(seller),
+			Category::Pharmaceutical,
+			BoundedVec::try_from(vec![Category::Grocery]).unwrap(),
+			100,
+			10,
+			H256([2; 32])
+		));
+		let pid = last_listed_pid();
+		assert_eq!(
+			Marketplace::get_product_info(pid).unwrap().accepted_categories.into_inner(),
+			vec![Category::Pharmaceutical, Category::Grocery]
+		);
+		assert_noop!(
+			Marketplace::buy(
+				RuntimeOrigin::signed(ngo),
+				pid,
+				3,
+				payment(vec![(Category::Pharmaceutical, 100), (Category::Grocery, 100)]),
+				None,
+				None
+			),
+			Error::<Test>::PaymentMismatch
+		);
+		assert_noop!(
+			Marketplace::buy(
+				RuntimeOrigin::signed(ngo),
+				pid,
+				3,
+				payment(vec![(Category::Pharmaceutical, 100), (Category::Clothing, 200)]),
+				None,
+				None
+			),
+			Error::<Test>::CategoryNotAccepted
+		);
+		assert_noop!(
+			Marketplace::buy(
+				RuntimeOrigin::signed(ngo),
+				pid,
+				3,
+				payment(vec![(Category::Grocery, 100), (Category::Grocery, 200)]),
+				None,
+				None
+			),
+			Error::<Test>::DuplicatePaymentCategory
+		);
+
+		let split = payment(vec![(Category::Pharmaceutical, 100), (Category::Grocery, 200)]);
+		assert_ok!(Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 3, split.clone(), None, None));
+		System::assert_has_event(
+			Event::OrderPlaced {
+				order_id: 0,
+				buyer: ngo,
+				seller,
+				pid,
+				quantity: 3,
+				amount: 300,
+				payment: split,
+				discount: None,
+			}
+			.into(),
+		);
+		let escrow = Marketplace::get_escrow_account();
+		assert_eq!(pbt_balance(escrow), 100);
+		assert_eq!(TokenHandler::balance(Category::Grocery.get_id() as u128, &escrow), 200);
+
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		assert_eq!(pbt_balance(seller), 100);
+		assert_eq!(TokenHandler::balance(Category::Grocery.get_id() as u128, &seller), 200);
+	})
+}
+
+#[test]
+fn test_list_product_in_category_not_allowed_for_seller_returns_err() {
+	new_test_ext().execute_with(|| {
+		let seller = 3u64;
+		let seller_info = SellerInfo {
+			categories: BoundedVec::try_from(vec![Category::Pharmaceutical]).unwrap(),
+			cid: H256::default(),
+		};
+		ParticipantHandler::add_seller_to_active_list(&seller, seller_info);
+		assert_noop!(
+			Marketplace::list_product(
+				RuntimeOrigin::signed(seller),
+				Category::Pharmaceutical,
+				BoundedVec::try_from(vec![Category::Clothing]).unwrap(),
+				100,
+				10,
+				H256([2; 32])
+			),
+			Error::<Test>::SellerCategoryNotAllowed
+		);
+	})
+}
+
+#[test]
+fn test_buy_many_places_an_order_per_product() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, first) = setup_order_parties(100, 10);
+		let second = list_product(seller, 50, 10);
+		let cart = Cart::try_from(vec![(first, 2), (second, 4)]).unwrap();
+		assert_ok!(Marketplace::buy_many(RuntimeOrigin::signed(ngo), cart, 400));
+		System::assert_last_event(
+			Event::CartCheckedOut { buyer: ngo, order_ids: vec![0, 1], total: 400 }.into(),
+		);
+		assert_eq!(Marketplace::get_product_info(first).unwrap().quantity, 8);
+		assert_eq!(Marketplace::get_product_info(second).unwrap().quantity, 6);
+		assert_eq!(Marketplace::orders_by_buyer(&ngo, None, 10).len(), 2);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 400);
+	})
+}
+
+#[test]
+fn test_buy_many_is_all_or_nothing() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, first) = setup_order_parties(100, 10);
+		let second = list_product(seller, 50, 3);
+		assert_noop!(
+			Marketplace::buy_many(
+				RuntimeOrigin::signed(ngo),
+				Cart::try_from(vec![(first, 2), (second, 4)]).unwrap(),
+				1_000
+			),
+			Error::<Test>::InsufficientStock
+		);
+		assert_noop!(
+			Marketplace::buy_many(
+				RuntimeOrigin::signed(ngo),
+				Cart::try_from(vec![(first, 2), (second, 3)]).unwrap(),
+				349
+			),
+			Error::<Test>::TotalPriceExceeded
+		);
+		assert_noop!(
+			Marketplace::buy_many(RuntimeOrigin::signed(ngo), Cart::default(), 1_000),
+			Error::<Test>::EmptyCart
+		);
+		assert_eq!(Marketplace::get_product_info(first).unwrap().quantity, 10);
+		assert_eq!(Marketplace::get_next_order_id(), 0);
+	})
+}
+
+fn payment(parts: Vec<(Category, u128)>) -> Payment {
+	Payment::try_from(parts).unwrap()
+}
+
+#[test]
+fn test_buy_product_priced_in_reference_asset_uses_twap() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(10, 20);
+		set_reference_pool(1_000, 2_000);
+		assert_ok!(Marketplace::set_price_asset(RuntimeOrigin::signed(seller), pid, Some(5)));
+		System::assert_last_event(Event::ProductPriceAssetUpdated { pid, asset: Some(5) }.into());
+
+		assert_ok!(Marketplace::update_reference_price(RuntimeOrigin::signed(ngo), 5));
+		System::assert_last_event(Event::ReferencePriceUpdated { asset: 5, average: None }.into());
+		assert_noop!(
+			Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 3, Default::default(), None, None),
+			Error::<Test>::NoReferencePrice
+		);
+		assert_noop!(
+			Marketplace::update_reference_price(RuntimeOrigin::signed(ngo), 5),
+			Error::<Test>::TwapPeriodNotElapsed
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Marketplace::update_reference_price(RuntimeOrigin::signed(ngo), 5));
+		let average = FixedU128::saturating_from_integer(2u128).into_inner();
+		System::assert_last_event(
+			Event::ReferencePriceUpdated { asset: 5, average: Some(average) }.into(),
+		);
+
+		assert_noop!(
+			Marketplace::buy(
+				RuntimeOrigin::signed(ngo),
+				pid,
+				3,
+				Default::default(),
+				Some(59),
+				None
+			),
+			Error::<Test>::SlippageExceeded
+		);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			Some(60),
+			None
+		));
+		assert_eq!(Marketplace::get_order(0).unwrap().amount, 60);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 60);
+	})
+}
+
+#[test]
+fn test_buy_refreshes_stale_reference_price() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(10, 20);
+		set_reference_pool(1_000, 2_000);
+		assert_ok!(Marketplace::set_price_asset(RuntimeOrigin::signed(seller), pid, Some(5)));
+		assert_ok!(Marketplace::update_reference_price(RuntimeOrigin::signed(ngo), 5));
+
+		System::set_block_number(11);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_eq!(Marketplace::get_order(0).unwrap().amount, 20);
+		assert_eq!(Marketplace::get_price_observation(5).unwrap().block, 11);
+	})
+}
+
+#[test]
+fn test_set_price_asset_without_pool_returns_err() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(10, 20);
+		assert_noop!(
+			Marketplace::set_price_asset(RuntimeOrigin::signed(seller), pid, Some(5)),
+			Error::<Test>::NoPriceSource
+		);
+		assert_noop!(
+			Marketplace::set_price_asset(RuntimeOrigin::signed(ngo), pid, None),
+			Error::<Test>::NotProductOwner
+		);
+	})
+}
+
+#[test]
+fn test_english_auction_refunds_outbid_and_settles_on_initialize() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, other_ngo) = setup_auction_parties();
+		let kind = AuctionKind::English { reserve_price: 100, min_increment: 10 };
+		assert_ok!(Marketplace::create_auction(
+			RuntimeOrigin::signed(seller),
+			Category::Pharmaceutical,
+			5,
+			kind,
+			10,
+			H256([3; 32])
+		));
+		System::assert_last_event(
+			Event::AuctionCreated {
+				auction_id: 0,
+				owner: seller,
+				category: Category::Pharmaceutical,
+				quantity: 5,
+				kind,
+				end: 11,
+			}
+			.into(),
+		);
+		assert_noop!(
+			Marketplace::bid_auction(RuntimeOrigin::signed(ngo), 0, 99),
+			Error::<Test>::BidTooLow
+		);
+		assert_noop!(
+			Marketplace::bid_auction(RuntimeOrigin::signed(seller), 0, 100),
+			Error::<Test>::BuyerNotValid
+		);
+		assert_ok!(Marketplace::bid_auction(RuntimeOrigin::signed(ngo), 0, 100));
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 100);
+
+		assert_noop!(
+			Marketplace::bid_auction(RuntimeOrigin::signed(other_ngo), 0, 109),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Marketplace::bid_auction(RuntimeOrigin::signed(other_ngo), 0, 110));
+		System::assert_has_event(
+			Event::AuctionBidRefunded { auction_id: 0, bidder: ngo, amount: 100 }.into(),
+		);
+		assert_eq!(pbt_balance(ngo), 10_000);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 110);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Marketplace::bid_auction(RuntimeOrigin::signed(ngo), 0, 200),
+			Error::<Test>::AuctionEnded
+		);
+		Marketplace::on_initialize(11);
+		System::assert_last_event(
+			Event::AuctionSettled { auction_id: 0, winner: other_ngo, order_id: 0, amount: 110 }
+				.into(),
+		);
+		assert_eq!(Marketplace::get_auction(0).unwrap().status, AuctionStatus::Settled);
+		let order = Marketplace::get_order(0).unwrap();
+		assert_eq!(order.source, OrderSource::Auction(0));
+		assert_eq!((order.buyer, order.seller, order.quantity), (other_ngo, seller, 5));
+
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(other_ngo), 0));
+		assert_eq!(pbt_balance(seller), 110);
+	})
+}
+
+#[test]
+fn test_dutch_auction_first_bid_wins_at_current_price() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, other_ngo) = setup_auction_parties();
+		assert_noop!(
+			Marketplace::create_auction(
+				RuntimeOrigin::signed(seller),
+				Category::Pharmaceutical,
+				5,
+				AuctionKind::Dutch { start_price: 200, floor_price: 200 },
+				10,
+				H256([3; 32])
+			),
+			Error::<Test>::InvalidAuctionPrices
+		);
+		assert_ok!(Marketplace::create_auction(
+			RuntimeOrigin::signed(seller),
+			Category::Pharmaceutical,
+			5,
+			AuctionKind::Dutch { start_price: 1_000, floor_price: 200 },
+			10,
+			H256([3; 32])
+		));
+
+		System::set_block_number(6);
+		assert_noop!(
+			Marketplace::bid_auction(RuntimeOrigin::signed(ngo), 0, 599),
+			Error::<Test>::BidTooLow
+		);
+		assert_ok!(Marketplace::bid_auction(RuntimeOrigin::signed(ngo), 0, 700));
+		System::assert_last_event(
+			Event::AuctionSettled { auction_id: 0, winner: ngo, order_id: 0, amount: 600 }.into(),
+		);
+		assert_eq!(pbt_balance(ngo), 9_400);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 600);
+		assert_noop!(
+			Marketplace::bid_auction(RuntimeOrigin::signed(other_ngo), 0, 1_000),
+			Error::<Test>::AuctionNotOpen
+		);
+	})
+}
+
+#[test]
+fn test_settle_auction_without_bids_marks_it_unsold() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, other_ngo) = setup_auction_parties();
+		let kind = AuctionKind::English { reserve_price: 100, min_increment: 10 };
+		// Ngos can auction surplus too
+		assert_ok!(Marketplace::create_auction(
+			RuntimeOrigin::signed(ngo),
+			Category::Pharmaceutical,
+			5,
+			kind,
+			5,
+			H256([3; 32])
+		));
+		assert_ok!(Marketplace::create_auction(
+			RuntimeOrigin::signed(seller),
+			Category::Pharmaceutical,
+			5,
+			kind,
+			5,
+			H256([3; 32])
+		));
+		assert_noop!(
+			Marketplace::create_auction(
+				RuntimeOrigin::signed(seller),
+				Category::Pharmaceutical,
+				5,
+				kind,
+				5,
+				H256([3; 32])
+			),
+			Error::<Test>::TooManyAuctionsEnding
+		);
+		assert_noop!(
+			Marketplace::bid_auction(RuntimeOrigin::signed(ngo), 0, 100),
+			Error::<Test>::OwnAuction
+		);
+		assert_ok!(Marketplace::bid_auction(RuntimeOrigin::signed(other_ngo), 1, 100));
+		assert_noop!(
+			Marketplace::cancel_auction(RuntimeOrigin::signed(seller), 1),
+			Error::<Test>::AuctionHasBids
+		);
+		assert_noop!(
+			Marketplace::settle_auction(RuntimeOrigin::signed(other_ngo), 0),
+			Error::<Test>::AuctionNotEnded
+		);
+
+		System::set_block_number(6);
+		assert_ok!(Marketplace::settle_auction(RuntimeOrigin::signed(other_ngo), 0));
+		System::assert_last_event(Event::AuctionUnsold { auction_id: 0 }.into());
+		assert_eq!(Marketplace::get_auction(0).unwrap().status, AuctionStatus::Unsold);
+		Marketplace::on_initialize(6);
+		assert_eq!(Marketplace::get_auction(1).unwrap().status, AuctionStatus::Settled);
+		assert_eq!(Marketplace::get_order(0).unwrap().buyer, other_ngo);
+	})
+}
+
+#[test]
+fn test_product_indexes_follow_list_buy_and_delist() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 2);
+		let pids = |products: Vec<(H256, ProductInfo<u64, H256>)>| {
+			products.into_iter().map(|(pid, _)| pid).collect::<Vec<_>>()
+		};
+		assert_eq!(
+			pids(Marketplace::products_by_category(Category::Pharmaceutical, None, 10)),
+			vec![pid]
+		);
+		assert_eq!(pids(Marketplace::products_by_seller(&seller, None, 10)), vec![pid]);
+		assert!(Marketplace::products_by_category(Category::Clothing, None, 10).is_empty());
+
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			2,
+			Default::default(),
+			None,
+			None
+		));
+		assert!(Marketplace::products_by_category(Category::Pharmaceutical, None, 10).is_empty());
+		assert_eq!(pids(Marketplace::products_by_seller(&seller, None, 10)), vec![pid]);
+		assert_eq!(pids(Marketplace::products_by_buyer(&ngo, None, 10)), vec![pid]);
+		assert_noop!(
+			Marketplace::delist(RuntimeOrigin::signed(seller), pid),
+			Error::<Test>::ProductHasOpenOrders
+		);
+
+		assert_ok!(Marketplace::restock(RuntimeOrigin::signed(seller), pid, 1));
+		assert_eq!(
+			pids(Marketplace::products_by_category(Category::Pharmaceutical, None, 10)),
+			vec![pid]
+		);
+
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		assert_ok!(Marketplace::delist(RuntimeOrigin::signed(seller), pid));
+		assert!(Marketplace::products_by_category(Category::Pharmaceutical, None, 10).is_empty());
+		assert!(Marketplace::products_by_seller(&seller, None, 10).is_empty());
+		let history = Marketplace::products_by_buyer(&ngo, None, 10);
+		assert_eq!(pids(history.clone()), vec![pid]);
+		assert_eq!(history[0].1.status, Status::Delisted);
+	})
+}
+
+#[test]
+fn test_delisted_product_with_orders_can_still_be_recalled() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			2,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		assert_ok!(Marketplace::delist(RuntimeOrigin::signed(seller), pid));
+		assert_eq!(Marketplace::get_product_info(pid).unwrap().status, Status::Delisted);
+		assert_noop!(
+			Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 1, Default::default(), None, None),
+			Error::<Test>::ProductNotFound
+		);
+		assert_noop!(
+			Marketplace::restock(RuntimeOrigin::signed(seller), pid, 1),
+			Error::<Test>::ProductNotFound
+		);
+		assert_noop!(
+			Marketplace::delist(RuntimeOrigin::signed(seller), pid),
+			Error::<Test>::ProductNotFound
+		);
+
+		let reason = H256([9; 32]);
+		assert_ok!(Marketplace::recall_product(
+			RuntimeOrigin::signed(seller),
+			pid,
+			reason,
+			false,
+			1,
+			0
+		));
+		System::assert_last_event(Event::RecallNotified { pid, order_id: 0, buyer: ngo }.into());
+		assert_eq!(Marketplace::get_product_info(pid).unwrap().status, Status::Recalled);
+	})
+}
+
+#[test]
+fn test_sold_out_product_can_be_delisted_once_its_orders_close() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 2);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_eq!(Marketplace::get_product_info(pid).unwrap().status, Status::Sold);
+		assert_eq!(Marketplace::get_open_orders(pid), 2);
+
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		assert_eq!(Marketplace::get_open_orders(pid), 1);
+		assert_noop!(
+			Marketplace::delist(RuntimeOrigin::signed(seller), pid),
+			Error::<Test>::ProductHasOpenOrders
+		);
+
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 1));
+		assert!(!OpenOrdersByProduct::<Test>::contains_key(pid));
+		assert_ok!(Marketplace::delist(RuntimeOrigin::signed(seller), pid));
+		assert!(Marketplace::products_by_seller(&seller, None, 10).is_empty());
+		assert_eq!(Marketplace::get_product_info(pid).unwrap().status, Status::Delisted);
+	})
+}
+
+#[test]
+fn test_products_by_seller_paginates() {
+	new_test_ext().execute_with(|| {
+		let (seller, _, first) = setup_order_parties(100, 2);
+		let second = list_product(seller, 200, 2);
+		let third = list_product(seller, 300, 2);
+
+		let page = Marketplace::products_by_seller(&seller, None, 2);
+		assert_eq!(page.len(), 2);
+		let last = page.last().map(|(pid, _)| *pid);
+		let next_page = Marketplace::products_by_seller(&seller, last, 2);
+		assert_eq!(next_page.len(), 1);
+		assert!(Marketplace::products_by_seller(&seller, next_page.last().map(|(pid, _)| *pid), 2)
+			.is_empty());
+
+		let mut listed: Vec<H256> = page.into_iter().chain(next_page).map(|(pid, _)| pid).collect();
+		listed.sort();
+		let mut expected = vec![first, second, third];
+		expected.sort();
+		assert_eq!(listed, expected);
+	})
+}
+
+#[test]
+fn test_orders_by_buyer_paginates() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		for _ in 0..3 {
+			assert_ok!(Marketplace::buy(
+				RuntimeOrigin::signed(ngo),
+				pid,
+				1,
+				Default::default(),
+				None,
+				None
+			));
+		}
+		let page = Marketplace::orders_by_buyer(&ngo, None, 2);
+		assert_eq!(page.len(), 2);
+		let next_page = Marketplace::orders_by_buyer(&ngo, page.last().map(|(id, _)| *id), 2);
+		assert_eq!(next_page.len(), 1);
+
+		let mut order_ids: Vec<_> = page.into_iter().chain(next_page).map(|(id, _)| id).collect();
+		order_ids.sort();
+		assert_eq!(order_ids, vec![0, 1, 2]);
+		assert_eq!(Marketplace::orders_by_seller(&seller, None, 10).len(), 3);
+	})
+}
+
+#[test]
+fn test_expired_product_cannot_be_bought() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_noop!(
+			Marketplace::set_expiry(RuntimeOrigin::signed(seller), pid, Some(1)),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_noop!(
+			Marketplace::set_expiry(RuntimeOrigin::signed(ngo), pid, Some(5)),
+			Error::<Test>::NotProductOwner
+		);
+		assert_ok!(Marketplace::set_expiry(RuntimeOrigin::signed(seller), pid, Some(5)));
+		System::assert_last_event(Event::ProductExpiryUpdated { pid, expires_at: Some(5) }.into());
+
+		System::set_block_number(4);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		System::set_block_number(5);
+		assert_noop!(
+			Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 1, Default::default(), None, None),
+			Error::<Test>::ProductExpired
+		);
+		assert!(Marketplace::products_by_category(Category::Pharmaceutical, None, 10).is_empty());
+
+		assert_ok!(Marketplace::set_expiry(RuntimeOrigin::signed(seller), pid, None));
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+	})
+}
+
+#[test]
+fn test_recall_refunds_orders_from_escrow_and_pending_payouts() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			2,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 1));
+		let donation_account: u64 = DonationPalletId::get().into_account_truncating();
+		Balances::set_balance(RuntimeOrigin::root(), donation_account, 1_000_000, 0);
+		assert_ok!(DonationHandler::claim_token(
+			RuntimeOrigin::signed(seller),
+			Category::Pharmaceutical,
+			300
+		));
+		assert_eq!(pbt_balance(ngo), 9_500);
+
+		let reason = H256([9; 32]);
+		assert_noop!(
+			Marketplace::recall_product(RuntimeOrigin::signed(ngo), pid, reason, true, 2, 1),
+			Error::<Test>::NotProductOwner
+		);
+		assert_noop!(
+			Marketplace::recall_product(RuntimeOrigin::root(), pid, reason, true, 2, 0),
+			donation_handler::Error::<Test>::TooManyPayouts
+		);
+		assert_ok!(Marketplace::recall_product(RuntimeOrigin::root(), pid, reason, true, 2, 1));
+		System::assert_has_event(Event::ProductRecalled { pid, reason, refund: true }.into());
+		System::assert_has_event(Event::RecallNotified { pid, order_id: 0, buyer: ngo }.into());
+		System::assert_has_event(Event::RecallNotified { pid, order_id: 1, buyer: ngo }.into());
+		System::assert_has_event(
+			Event::RecallRefunded { order_id: 0, buyer: ngo, amount: 200 }.into(),
+		);
+		System::assert_has_event(
+			Event::RecallRefunded { order_id: 1, buyer: ngo, amount: 300 }.into(),
+		);
+		assert_eq!(pbt_balance(ngo), 10_000);
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Refunded);
+		assert_eq!(Marketplace::get_order(1).unwrap().status, OrderStatus::Refunded);
+		assert!(DonationHandler::get_pending_payout(0).is_none());
+	})
+}
+
+#[test]
+fn test_recall_of_settled_order_reverses_commission() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::set_commission_rate(
+			RuntimeOrigin::root(),
+			Category::Pharmaceutical,
+			Permill::from_percent(5)
+		));
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		assert_ok!(DonationHandler::claim_token(
+			RuntimeOrigin::signed(seller),
+			Category::Pharmaceutical,
+			285
+		));
+
+		let reason = H256([9; 32]);
+		assert_ok!(Marketplace::recall_product(
+			RuntimeOrigin::signed(seller),
+			pid,
+			reason,
+			true,
+			1,
+			1
+		));
+		System::assert_last_event(
+			Event::RecallRefunded { order_id: 0, buyer: ngo, amount: 300 }.into(),
+		);
+		assert_eq!(pbt_balance(ngo), 10_000);
+		assert_eq!(Balances::free_balance(FeeReceiver::get()), 1_000_000);
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Refunded);
+		assert!(Marketplace::get_order_commission(0).is_none());
+	})
+}
+
+#[test]
+fn test_recall_without_seller_payouts_keeps_the_commission() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::set_commission_rate(
+			RuntimeOrigin::root(),
+			Category::Pharmaceutical,
+			Permill::from_percent(5)
+		));
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		assert_eq!(Balances::free_balance(FeeReceiver::get()), 1_000_015);
+
+		assert_ok!(Marketplace::recall_product(
+			RuntimeOrigin::signed(seller),
+			pid,
+			H256([9; 32]),
+			true,
+			1,
+			0
+		));
+		assert_eq!(pbt_balance(ngo), 9_700);
+		assert_eq!(Balances::free_balance(FeeReceiver::get()), 1_000_015);
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Settled);
+		assert_eq!(
+			Marketplace::get_order_commission(0).unwrap().into_inner(),
+			vec![(Category::Pharmaceutical, 15)]
+		);
+	})
+}
+
+#[test]
+fn test_recalled_product_cannot_be_bought_or_restocked() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			2,
+			Default::default(),
+			None,
+			None
+		));
+		let reason = H256([9; 32]);
+		assert_noop!(
+			Marketplace::recall_product(RuntimeOrigin::signed(seller), pid, reason, false, 0, 0),
+			Error::<Test>::BadWitness
+		);
+		assert_ok!(Marketplace::recall_product(
+			RuntimeOrigin::signed(seller),
+			pid,
+			reason,
+			false,
+			1,
+			0
+		));
+		System::assert_last_event(Event::RecallNotified { pid, order_id: 0, buyer: ngo }.into());
+		assert_eq!(Marketplace::get_product_info(pid).unwrap().status, Status::Recalled);
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Placed);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 200);
+
+		assert_noop!(
+			Marketplace::buy(RuntimeOrigin::signed(ngo), pid, 1, Default::default(), None, None),
+			Error::<Test>::ProductRecalled
+		);
+		assert_noop!(
+			Marketplace::restock(RuntimeOrigin::signed(seller), pid, 1),
+			Error::<Test>::ProductRecalled
+		);
+		assert_noop!(
+			Marketplace::recall_product(RuntimeOrigin::signed(seller), pid, reason, true, 1, 0),
+			Error::<Test>::ProductRecalled
+		);
+	})
+}
+
+#[test]
+fn test_commission_is_charged_to_fee_receiver_on_settlement() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_noop!(
+			Marketplace::set_commission_rate(
+				RuntimeOrigin::signed(ngo),
+				Category::Pharmaceutical,
+				Permill::from_percent(5)
+			),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Marketplace::set_commission_rate(
+			RuntimeOrigin::root(),
+			Category::Pharmaceutical,
+			Permill::from_percent(5)
+		));
+		System::assert_last_event(
+			Event::CommissionRateUpdated {
+				category: Category::Pharmaceutical,
+				rate: Permill::from_percent(5),
+			}
+			.into(),
+		);
+
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		System::assert_has_event(
+			Event::CommissionCharged {
+				order_id: 0,
+				seller,
+				category: Category::Pharmaceutical,
+				amount: 15,
+			}
+			.into(),
+		);
+		System::assert_last_event(Event::OrderSettled { order_id: 0, seller, amount: 300 }.into());
+		assert_eq!(pbt_balance(seller), 285);
+		assert_eq!(Balances::free_balance(FeeReceiver::get()), 1_000_015);
+		assert_eq!(pbt_balance(FeeReceiver::get()), 0);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 0);
+		assert_eq!(
+			Marketplace::get_order_commission(0).unwrap().into_inner(),
+			vec![(Category::Pharmaceutical, 15)]
+		);
+		assert_eq!(Marketplace::get_seller_volume(seller), 300);
+	})
+}
+
+#[test]
+fn test_commission_discount_tiers_reward_volume_and_reputation() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::set_commission_rate(
+			RuntimeOrigin::root(),
+			Category::Pharmaceutical,
+			Permill::from_percent(10)
+		));
+		let tiers: BoundedVec<_, _> = vec![
+			CommissionTier { min_volume: 300, min_score: 0, discount: Permill::from_percent(50) },
+			CommissionTier {
+				min_volume: 300,
+				min_score: 400,
+				discount: Permill::from_percent(100),
+			},
+		]
+		.try_into()
+		.unwrap();
+		assert_ok!(Marketplace::set_commission_tiers(RuntimeOrigin::root(), tiers.clone()));
+		System::assert_last_event(Event::CommissionTiersUpdated { tiers }.into());
+
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			3,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		assert_eq!(pbt_balance(seller), 270);
+
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 1));
+		assert_eq!(pbt_balance(seller), 365);
+
+		assert_ok!(Marketplace::rate_order(RuntimeOrigin::signed(ngo), 0, 5, None, None));
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 2));
+		assert_eq!(pbt_balance(seller), 465);
+		assert_eq!(Balances::free_balance(FeeReceiver::get()), 1_000_035);
+	})
+}
+
+#[test]
+fn test_group_buy_executes_at_reached_tier_price() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, other_ngo) = setup_auction_parties();
+		assert_noop!(
+			Marketplace::create_group_buy(
+				RuntimeOrigin::signed(seller),
+				Category::Pharmaceutical,
+				price_tiers(&[(5, 100), (10, 120)]),
+				20,
+				10,
+				H256([3; 32])
+			),
+			Error::<Test>::InvalidPriceTiers
+		);
+		assert_ok!(Marketplace::create_group_buy(
+			RuntimeOrigin::signed(seller),
+			Category::Pharmaceutical,
+			price_tiers(&[(5, 100), (10, 80)]),
+			12,
+			10,
+			H256([3; 32])
+		));
+		System::assert_last_event(
+			Event::GroupBuyCreated {
+				group_buy_id: 0,
+				owner: seller,
+				category: Category::Pharmaceutical,
+				deadline: 11,
+			}
+			.into(),
+		);
+
+		assert_ok!(Marketplace::commit_group_buy(RuntimeOrigin::signed(ngo), 0, 4));
+		assert_ok!(Marketplace::commit_group_buy(RuntimeOrigin::signed(other_ngo), 0, 6));
+		System::assert_last_event(
+			Event::GroupBuyCommitted { group_buy_id: 0, ngo: other_ngo, quantity: 6, amount: 600 }
+				.into(),
+		);
+		assert_noop!(
+			Marketplace::commit_group_buy(RuntimeOrigin::signed(ngo), 0, 3),
+			Error::<Test>::InsufficientStock
+		);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 1_000);
+		assert_noop!(
+			Marketplace::close_group_buy(RuntimeOrigin::signed(seller), 0),
+			Error::<Test>::GroupBuyDeadlineNotReached
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Marketplace::commit_group_buy(RuntimeOrigin::signed(ngo), 0, 1),
+			Error::<Test>::GroupBuyDeadlinePassed
+		);
+		assert_ok!(Marketplace::close_group_buy(RuntimeOrigin::signed(5), 0));
+		System::assert_last_event(
+			Event::GroupBuyExecuted { group_buy_id: 0, quantity: 10, price: 80 }.into(),
+		);
+		assert_eq!(Marketplace::get_group_buy(0).unwrap().status, GroupBuyStatus::Executed);
+		assert_eq!(pbt_balance(ngo), 9_680);
+		assert_eq!(pbt_balance(other_ngo), 9_520);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 800);
+		for (buyer, quantity) in [(ngo, 4), (other_ngo, 6)] {
+			let commitment = Marketplace::get_group_commitment(0, buyer).unwrap();
+			assert_eq!(commitment.amount, 80 * quantity as u128);
+			let order = Marketplace::get_order(commitment.order_id.unwrap()).unwrap();
+			assert_eq!(order.source, OrderSource::GroupBuy(0));
+			assert_eq!((order.buyer, order.seller), (buyer, seller));
+			assert_eq!((order.quantity, order.amount), (quantity, 80 * quantity as u128));
+			assert_eq!(order.status, OrderStatus::Placed);
+		}
+	})
+}
+
+#[test]
+fn test_group_buy_below_threshold_or_cancelled_refunds_commitments() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, other_ngo) = setup_auction_parties();
+		for _ in 0..2 {
+			assert_ok!(Marketplace::create_group_buy(
+				RuntimeOrigin::signed(seller),
+				Category::Pharmaceutical,
+				price_tiers(&[(5, 100)]),
+				10,
+				10,
+				H256([3; 32])
+			));
+		}
+		assert_ok!(Marketplace::commit_group_buy(RuntimeOrigin::signed(ngo), 0, 4));
+		assert_ok!(Marketplace::commit_group_buy(RuntimeOrigin::signed(other_ngo), 1, 2));
+
+		assert_noop!(
+			Marketplace::cancel_group_buy(RuntimeOrigin::signed(ngo), 1),
+			Error::<Test>::NotGroupBuyOwner
+		);
+		assert_ok!(Marketplace::cancel_group_buy(RuntimeOrigin::signed(seller), 1));
+		System::assert_has_event(
+			Event::GroupBuyRefunded { group_buy_id: 1, ngo: other_ngo, amount: 200 }.into(),
+		);
+		System::assert_last_event(Event::GroupBuyCancelled { group_buy_id: 1 }.into());
+		assert_eq!(pbt_balance(other_ngo), 10_000);
+
+		System::set_block_number(11);
+		assert_ok!(Marketplace::close_group_buy(RuntimeOrigin::signed(ngo), 0));
+		System::assert_last_event(Event::GroupBuyFailed { group_buy_id: 0 }.into());
+		assert_eq!(Marketplace::get_group_buy(0).unwrap().status, GroupBuyStatus::Failed);
+		assert_eq!(Marketplace::get_group_commitment(0, ngo), None);
+		assert_eq!(pbt_balance(ngo), 10_000);
+		assert_eq!(pbt_balance(Marketplace::get_escrow_account()), 0);
+		assert_eq!(Marketplace::get_next_order_id(), 0);
+		assert_noop!(
+			Marketplace::close_group_buy(RuntimeOrigin::signed(ngo), 0),
+			Error::<Test>::GroupBuyNotOpen
+		);
+	})
+}
+
+#[test]
+fn test_supply_contract_starts_a_cycle_every_period() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_noop!(
+			Marketplace::propose_supply_contract(RuntimeOrigin::signed(ngo), pid, 2, 90, 0),
+			Error::<Test>::InvalidPeriod
+		);
+		assert_ok!(Marketplace::propose_supply_contract(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			2,
+			90,
+			10
+		));
+		System::assert_last_event(
+			Event::SupplyContractProposed { contract_id: 0, ngo, seller, pid }.into(),
+		);
+		assert_noop!(
+			Marketplace::accept_supply_contract(RuntimeOrigin::signed(ngo), 0),
+			Error::<Test>::NotContractParty
+		);
+		assert_ok!(Marketplace::accept_supply_contract(RuntimeOrigin::signed(seller), 0));
+		System::assert_has_event(
+			Event::SupplyCycleStarted { contract_id: 0, order_id: 0, due: 11 }.into(),
+		);
+		assert_eq!(Marketplace::get_supply_contract(0).unwrap().status, ContractStatus::Active);
+		assert_eq!(pbt_balance(ngo), 9_820);
+
+		assert_ok!(Marketplace::mark_shipped(RuntimeOrigin::signed(seller), 0, H256([7; 32])));
+		assert_ok!(Marketplace::confirm_delivery(RuntimeOrigin::signed(ngo), 0));
+		assert_eq!(pbt_balance(seller), 180);
+
+		System::set_block_number(11);
+		Marketplace::on_initialize(11);
+		System::assert_last_event(
+			Event::SupplyCycleStarted { contract_id: 0, order_id: 1, due: 21 }.into(),
+		);
+		let order = Marketplace::get_order(1).unwrap();
+		assert_eq!(order.source, OrderSource::SupplyContract(0));
+		assert_eq!((order.quantity, order.amount), (2, 180));
+		assert_eq!(pbt_balance(ngo), 9_640);
+		assert_eq!(Marketplace::get_supply_contract(0).unwrap().missed, 0);
+	})
+}
+
+#[test]
+fn test_supply_contract_tracks_missed_deliveries_and_ends_after_notice() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::propose_supply_contract(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			2,
+			90,
+			10
+		));
+		assert_ok!(Marketplace::accept_supply_contract(RuntimeOrigin::signed(seller), 0));
+
+		System::set_block_number(11);
+		Marketplace::on_initialize(11);
+		System::assert_has_event(
+			Event::DeliveryMissed { contract_id: 0, order_id: 0, seller }.into(),
+		);
+		assert_eq!(Marketplace::get_order(0).unwrap().status, OrderStatus::Refunded);
+		assert_eq!(Marketplace::get_supply_contract(0).unwrap().missed, 1);
+		assert_eq!(Marketplace::get_missed_deliveries(seller), 1);
+		assert_eq!(Marketplace::get_seller_reputation(seller).score, 100);
+		assert_eq!(pbt_balance(ngo), 9_820);
+		// The missed delivery already rated the refunded Order
+		assert_noop!(
+			Marketplace::rate_order(RuntimeOrigin::signed(ngo), 0, 1, None, None),
+			Error::<Test>::AlreadyRated
+		);
+		assert_eq!(Marketplace::get_seller_reputation(seller).ratings, 1);
+
+		System::set_block_number(12);
+		assert_noop!(
+			Marketplace::terminate_supply_contract(RuntimeOrigin::signed(5), 0),
+			Error::<Test>::NotContractParty
+		);
+		assert_ok!(Marketplace::terminate_supply_contract(RuntimeOrigin::signed(ngo), 0));
+		System::assert_last_event(
+			Event::SupplyContractNoticeGiven { contract_id: 0, by: ngo, ends_at: 17 }.into(),
+		);
+		assert_noop!(
+			Marketplace::terminate_supply_contract(RuntimeOrigin::signed(seller), 0),
+			Error::<Test>::NoticeAlreadyGiven
+		);
+		assert_ok!(Marketplace::mark_shipped(RuntimeOrigin::signed(seller), 1, H256([7; 32])));
+
+		System::set_block_number(21);
+		Marketplace::on_initialize(21);
+		System::assert_last_event(Event::SupplyContractTerminated { contract_id: 0 }.into());
+		assert_eq!(Marketplace::get_supply_contract(0).unwrap().status, ContractStatus::Terminated);
+		assert_eq!(Marketplace::get_supply_contract(0).unwrap().missed, 1);
+		assert_eq!(Marketplace::get_order(1).unwrap().status, OrderStatus::Shipped);
+		assert_eq!(Marketplace::get_next_order_id(), 2);
+		assert_noop!(
+			Marketplace::terminate_supply_contract(RuntimeOrigin::signed(ngo), 0),
+			Error::<Test>::InvalidContractStatus
+		);
+	})
+}
+
+#[test]
+fn test_supply_contract_spills_over_a_full_due_block() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		for contract_id in 0..3 {
+			assert_ok!(Marketplace::propose_supply_contract(
+				RuntimeOrigin::signed(ngo),
+				pid,
+				2,
+				90,
+				10
+			));
+			assert_ok!(Marketplace::accept_supply_contract(
+				RuntimeOrigin::signed(seller),
+				contract_id
+			));
+		}
+		assert_eq!(Marketplace::get_contracts_due_at(11).into_inner(), vec![0, 1]);
+		System::assert_has_event(
+			Event::SupplyCycleStarted { contract_id: 2, order_id: 2, due: 12 }.into(),
+		);
+		assert_eq!(Marketplace::get_supply_contract(2).unwrap().next_due, 12);
+
+		System::set_block_number(12);
+		Marketplace::on_initialize(12);
+		System::assert_has_event(
+			Event::SupplyCycleStarted { contract_id: 2, order_id: 3, due: 22 }.into(),
+		);
+	})
+}
+
+#[test]
+fn test_supply_contract_reports_why_its_next_cycle_failed() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		assert_ok!(Marketplace::propose_supply_contract(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			2,
+			90,
+			10
+		));
+		assert_ok!(Marketplace::accept_supply_contract(RuntimeOrigin::signed(seller), 0));
+		ParticipantHandler::suspend_seller(&seller);
+
+		System::set_block_number(11);
+		Marketplace::on_initialize(11);
+		System::assert_has_event(
+			Event::SupplyCycleFailed {
+				contract_id: 0,
+				error: Error::<Test>::SellerNotValid.into(),
+			}
+			.into(),
+		);
+		System::assert_last_event(Event::SupplyContractTerminated { contract_id: 0 }.into());
+		assert_eq!(Marketplace::get_supply_contract(0).unwrap().status, ContractStatus::Terminated);
+	})
+}
+
+#[test]
+fn test_discount_applies_to_whitelisted_ngo_until_redeemed() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		let other_ngo = 4u64;
+		add_ngo(other_ngo);
+		mint_pbt(other_ngo, Category::Pharmaceutical, 10_000);
+		let rule = DiscountRule {
+			ngos: vec![ngo].try_into().unwrap(),
+			max_redemptions: Some(1),
+			..discount_rule(DiscountKind::Percentage(Permill::from_percent(10)))
+		};
+		assert_noop!(
+			Marketplace::add_discount(RuntimeOrigin::signed(ngo), pid, rule.clone()),
+			Error::<Test>::NotProductOwner
+		);
+		assert_noop!(
+			Marketplace::add_discount(
+				RuntimeOrigin::signed(seller),
+				pid,
+				DiscountRule { ends_at: 1, ..rule.clone() }
+			),
+			Error::<Test>::InvalidDiscountWindow
+		);
+		assert_ok!(Marketplace::add_discount(RuntimeOrigin::signed(seller), pid, rule));
+		System::assert_last_event(Event::DiscountAdded { pid, discount_id: 0 }.into());
+
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(other_ngo),
+			pid,
+			2,
+			Default::default(),
+			None,
+			None
+		));
+		assert_eq!(Marketplace::get_order(0).unwrap().amount, 200);
+
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			2,
+			Default::default(),
+			Some(180),
+			None
+		));
+		System::assert_has_event(
+			Event::OrderPlaced {
+				order_id: 1,
+				buyer: ngo,
+				seller,
+				pid,
+				quantity: 2,
+				amount: 180,
+				payment: payment(vec![(Category::Pharmaceutical, 180)]),
+				discount: Some(AppliedDiscount { discount_id: 0, amount: 20 }),
+			}
+			.into(),
+		);
+		assert_eq!(Marketplace::get_discount_redemptions(0), 1);
+
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			2,
+			Default::default(),
+			None,
+			None
+		));
+		assert_eq!(Marketplace::get_order(2).unwrap().amount, 200);
+	})
+}
+
+#[test]
+fn test_coupon_discount_requires_code_category_and_window() {
+	new_test_ext().execute_with(|| {
+		let (seller, ngo, pid) = setup_order_parties(100, 10);
+		let ngo_info = NgoInfo {
+			categories: vec![Category::Pharmaceutical].try_into().unwrap(),
+			cid: H256::zero(),
+		};
+		ParticipantHandler::add_ngo_to_active_list(&ngo, ngo_info);
+		let other_ngo = 4u64;
+		add_ngo(other_ngo);
+		mint_pbt(other_ngo, Category::Pharmaceutical, 10_000);
+		let rule = DiscountRule {
+			coupon: Some(BlakeTwo256::hash(b"SPRING")),
+			categories: vec![Category::Pharmaceutical].try_into().unwrap(),
+			ends_at: 5,
+			..discount_rule(DiscountKind::FixedAmount(50))
+		};
+		assert_noop!(
+			Marketplace::add_discount(
+				RuntimeOrigin::signed(seller),
+				pid,
+				DiscountRule { categories: Default::default(), ..rule.clone() }
+			),
+			Error::<Test>::UnrestrictedCoupon
+		);
+		assert_ok!(Marketplace::add_discount(RuntimeOrigin::signed(seller), pid, rule));
+
+		assert_noop!(
+			Marketplace::buy(
+				RuntimeOrigin::signed(ngo),
+				pid,
+				1,
+				Default::default(),
+				None,
+				Some(coupon(b"WINTER"))
+			),
+			Error::<Test>::InvalidCoupon
+		);
+		assert_noop!(
+			Marketplace::buy(
+				RuntimeOrigin::signed(other_ngo),
+				pid,
+				1,
+				Default::default(),
+				None,
+				Some(coupon(b"SPRING"))
+			),
+			Error::<Test>::InvalidCoupon
+		);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			None
+		));
+		assert_eq!(Marketplace::get_order(0).unwrap().amount, 100);
+		assert_ok!(Marketplace::buy(
+			RuntimeOrigin::signed(ngo),
+			pid,
+			1,
+			Default::default(),
+			None,
+			Some(coupon(b"SPRING"))
+		));
+		assert_eq!(Marketplace::get_order(1).unwrap().amount, 50);
+
+		System::set_block_number(5);
+		assert_noop!(
+			Marketplace::buy(
+				RuntimeOrigin::signed(ngo),
+				pid,
+				1,
+				Default::default(),
+				None,
+				Some(coupon(b"SPRING"))
+			),
+			Error::<Test>::InvalidCoupon
+		);
+		assert_ok!(Marketplace::remove_discount(RuntimeOrigin::signed(seller), pid, 0));
+		System::assert_last_event(Event::DiscountRemoved { pid, discount_id: 0 }.into());
+		assert_eq!(Marketplace::get_discount(pid, 0), None);
+	})
+}
+
+#[test]
+fn test_migrate_to_v1_translates_and_indexes_single_unit_products() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Marketplace>();
+		let (seller, buyer) = (1u64, 2u64);
+		let cid = H256::from([1; 32]);
+		let (open, sold) = (H256::from([2; 32]), H256::from([3; 32]));
+		// A sale used to make the buyer the owner
+		for (pid, status, owner) in
+			[(open, Status::OpenForSell, seller), (sold, Status::Sold, buyer)]
+		{
+			unhashed::put_raw(
+				&<ProductMap<Test>>::hashed_key_for(pid),
+				&(Category::Grocery, 100u128, status, owner, cid).encode(),
+			);
+		}
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Marketplace::on_chain_storage_version(), 1);
+		let product = Marketplace::get_product_info(open).unwrap();
+		assert_eq!(product.accepted_categories.into_inner(), vec![Category::Grocery]);
+		assert_eq!((product.price, product.price_asset, product.quantity), (100, None, 1));
+		assert_eq!(Marketplace::get_product_info(sold).unwrap().quantity, 0);
+		assert!(<ProductsBySeller<Test>>::contains_key(seller, open));
+		assert!(!<ProductsByBuyer<Test>>::contains_key(seller, open));
+		assert!(<ProductsByBuyer<Test>>::contains_key(buyer, sold));
+		assert!(!<ProductsBySeller<Test>>::contains_key(buyer, sold));
+		assert!(<ProductsByCategory<Test>>::contains_key(Category::Grocery, open));
+		assert!(!<ProductsByCategory<Test>>::contains_key(Category::Grocery, sold));
+	})
+}
+
+fn discount_rule(kind: DiscountKind) -> DiscountRule<u64, H256, u64> {
+	DiscountRule {
+		kind,
+		coupon: None,
+		ngos: Default::default(),
+		categories: Default::default(),
+		starts_at: 1,
+		ends_at: 10,
+		max_redemptions: None,
+	}
+}
+
+fn coupon(code: &[u8]) -> CouponCode {
+	code.to_vec().try_into().unwrap()
+}
+
+fn price_tiers(tiers: &[(u32, u128)]) -> PriceTiers {
+	tiers
+		.iter()
+		.map(|&(min_quantity, price)| PriceTier { min_quantity, price })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn setup_auction_parties() -> (u64, u64, u64) {
+	let (seller, ngo) = (3u64, 2u64);
+	let other_ngo = 4u64;
+	add_seller(seller);
+	add_ngo(ngo);
+	add_ngo(other_ngo);
+	create_assets();
+	Balances::set_balance(RuntimeOrigin::root(), seller, 1_000_000, 0);
+	mint_pbt(ngo, Category::Pharmaceutical, 10_000);
+	mint_pbt(other_ngo, Category::Pharmaceutical, 10_000);
+	System::set_block_number(1);
+	(seller, ngo, other_ngo)
+}
+
+fn set_reference_pool(base_amount: u128, quote_amount: u128) {
+	let mut pool = Pool::new(100);
+	pool.base_amount = base_amount;
+	pool.quote_amount = quote_amount;
+	set_pool(Some(pool));
+}
+
+fn setup_order_parties(price: u128, quantity: u32) -> (u64, u64, H256) {
+	let seller = 3u64;
+	let ngo = 2u64;
+	add_seller(seller);
+	add_ngo(ngo);
+	create_assets();
+	Balances::set_balance(RuntimeOrigin::root(), seller, 1_000_000, 0);
+	mint_pbt(ngo, Category::Pharmaceutical, 10_000);
+	let pid = list_product(seller, price, quantity);
+	(seller, ngo, pid)
+}
+
+fn pbt_balance(who: u64) -> u128 {
+	TokenHandler::balance(Category::Pharmaceutical.get_id() as u128, &who)
+}
+
+fn list_product(seller: u64, price: u128, quantity: u32) -> H256 {
+	System::set_block_number(1);
+	assert_ok!(Marketplace::list_product(
+		RuntimeOrigin::signed(seller),
+		Category::Pharmaceutical,
+		Default::default(),
+		price,
+		quantity,
+		H256([2; 32])
+	));
+	last_listed_pid()
+}
+
+fn last_listed_pid() -> H256 {
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::Marketplace(Event::ProductListed { pid, .. }) => Some(pid),
+			_ => None,
+		})
+		.expect("ProductListed event must be emitted")
+}
+
+fn mint_pbt(who: u64, category: Category, amount: u128) {
+	Balances::set_balance(RuntimeOrigin::root(), who, 1_000_000, 0);
+	assert_ok!(TokenHandler::mint(
+		RuntimeOrigin::signed(1),
+		codec::Compact(category.get_id() as u128),
+		who,
+		amount
+	));
+}
+
 fn add_seller(seller: u64) {
-	let seller_info = SellerInfo { category: Default::default(), cid: H256::default() };
+	let seller_info = SellerInfo {
+		categories: BoundedVec::try_from(Category::all().to_vec()).unwrap(),
+		cid: H256::default(),
+	};
 	ParticipantHandler::add_seller_to_active_list(&seller, seller_info);
 }
 
@@ -59,6 +2270,10 @@ fn add_ngo(ngo: u64) {
 
 fn create_assets() {
 	Balances::set_balance(RuntimeOrigin::root(), 1, 1_000_000_000_000_000, 0);
+	Balances::set_balance(RuntimeOrigin::root(), Marketplace::get_escrow_account(), 1_000_000, 0);
+	Balances::set_balance(RuntimeOrigin::root(), FeeReceiver::get(), 1_000_000, 0);
+	let donation_account: u64 = DonationPalletId::get().into_account_truncating();
+	Balances::set_balance(RuntimeOrigin::root(), donation_account, 1_000_000, 0);
 	assert_ok!(TokenHandler::create(
 		RuntimeOrigin::signed(1),
 		codec::Compact(Category::Pharmaceutical.get_id() as u128),
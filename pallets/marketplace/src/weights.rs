@@ -0,0 +1,987 @@
+//! PLACEHOLDER weights for marketplace, hand-written and not produced by the benchmark CLI.
+//!
+//! Do not ship these. Replace this file with the output of `.maintain/benchmark.sh` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for marketplace.
+pub trait WeightInfo {
+	fn list_product() -> Weight;
+	fn buy(d: u32, ) -> Weight;
+	fn update_price() -> Weight;
+	fn update_metadata() -> Weight;
+	fn delist(d: u32, ) -> Weight;
+	fn restock() -> Weight;
+	fn mark_shipped() -> Weight;
+	fn confirm_delivery() -> Weight;
+	fn settle_order() -> Weight;
+	fn open_dispute() -> Weight;
+	fn respond_to_dispute() -> Weight;
+	fn resolve_dispute() -> Weight;
+	fn expire_dispute() -> Weight;
+	fn post_tender() -> Weight;
+	fn submit_bid() -> Weight;
+	fn submit_sealed_bid() -> Weight;
+	fn reveal_bid() -> Weight;
+	fn award_tender() -> Weight;
+	fn cancel_tender() -> Weight;
+	fn rate_order() -> Weight;
+	fn buy_many(n: u32, ) -> Weight;
+	fn set_price_asset() -> Weight;
+	fn update_reference_price() -> Weight;
+	fn create_auction() -> Weight;
+	fn bid_auction() -> Weight;
+	fn settle_auction() -> Weight;
+	fn cancel_auction() -> Weight;
+	fn set_expiry() -> Weight;
+	fn recall_product(o: u32, p: u32, ) -> Weight;
+	fn set_commission_rate() -> Weight;
+	fn set_commission_tiers() -> Weight;
+	fn create_group_buy() -> Weight;
+	fn commit_group_buy() -> Weight;
+	fn close_group_buy(p: u32, ) -> Weight;
+	fn cancel_group_buy(p: u32, ) -> Weight;
+	fn propose_supply_contract() -> Weight;
+	fn accept_supply_contract() -> Weight;
+	fn terminate_supply_contract() -> Weight;
+	fn add_discount(d: u32, ) -> Weight;
+	fn remove_discount() -> Weight;
+	fn on_initialize(a: u32, c: u32, ) -> Weight;
+}
+
+/// Weights for marketplace using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace ProductNonce (r:1 w:1)
+	// Storage: Marketplace ProductMap (r:0 w:1)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	// Storage: Marketplace ProductsBySeller (r:0 w:1)
+	fn list_product() -> Weight {
+		Weight::from_ref_time(34_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	// Storage: Marketplace ProductExpiry (r:1 w:0)
+	// Storage: Swap Pools (r:1 w:0)
+	// Storage: Marketplace PriceObservations (r:1 w:1)
+	// Storage: Marketplace Discounts (r:8 w:0)
+	// Storage: Marketplace DiscountRedemptions (r:8 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:8 w:8)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	// Storage: Marketplace ProductsByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersByProduct (r:0 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	/// The range of component `d` is `[0, 8]`.
+	fn buy(d: u32, ) -> Weight {
+		Weight::from_ref_time(179_000_000)
+			.saturating_add(Weight::from_ref_time(6_400_000).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(23))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	fn update_price() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	fn update_metadata() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:0)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	// Storage: Marketplace ProductsBySeller (r:0 w:1)
+	// Storage: Marketplace ProductExpiry (r:0 w:1)
+	// Storage: Marketplace Discounts (r:9 w:8)
+	// Storage: Marketplace DiscountRedemptions (r:0 w:8)
+	// Storage: Marketplace OrdersByProduct (r:1 w:0)
+	/// The range of component `d` is `[0, 8]`.
+	fn delist(d: u32, ) -> Weight {
+		Weight::from_ref_time(36_000_000)
+			.saturating_add(Weight::from_ref_time(4_800_000).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(d.into())))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	fn restock() -> Weight {
+		Weight::from_ref_time(22_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	fn mark_shipped() -> Weight {
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:12 w:12)
+	// Storage: Marketplace CommissionRates (r:4 w:0)
+	// Storage: Marketplace SellerVolume (r:5 w:1)
+	// Storage: Marketplace SellerReputation (r:4 w:0)
+	// Storage: Marketplace CommissionDiscountTiers (r:4 w:0)
+	// Storage: DonationHandler NgoStats (r:4 w:4)
+	// Storage: DonationHandler SellerStats (r:4 w:4)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Marketplace OrderCommissions (r:0 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	fn confirm_delivery() -> Weight {
+		Weight::from_ref_time(285_000_000)
+			.saturating_add(T::DbWeight::get().reads(45))
+			.saturating_add(T::DbWeight::get().writes(30))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:12 w:12)
+	// Storage: Marketplace CommissionRates (r:4 w:0)
+	// Storage: Marketplace SellerVolume (r:5 w:1)
+	// Storage: Marketplace SellerReputation (r:4 w:0)
+	// Storage: Marketplace CommissionDiscountTiers (r:4 w:0)
+	// Storage: DonationHandler NgoStats (r:4 w:4)
+	// Storage: DonationHandler SellerStats (r:4 w:4)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Marketplace OrderCommissions (r:0 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	fn settle_order() -> Weight {
+		Weight::from_ref_time(281_000_000)
+			.saturating_add(T::DbWeight::get().reads(45))
+			.saturating_add(T::DbWeight::get().writes(30))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: Marketplace Disputes (r:0 w:1)
+	fn open_dispute() -> Weight {
+		Weight::from_ref_time(25_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Orders (r:1 w:0)
+	// Storage: Marketplace Disputes (r:1 w:1)
+	fn respond_to_dispute() -> Weight {
+		Weight::from_ref_time(26_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: Marketplace Disputes (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:16 w:16)
+	// Storage: Marketplace CommissionRates (r:4 w:0)
+	// Storage: Marketplace SellerVolume (r:5 w:1)
+	// Storage: Marketplace SellerReputation (r:4 w:0)
+	// Storage: Marketplace CommissionDiscountTiers (r:4 w:0)
+	// Storage: DonationHandler NgoStats (r:4 w:4)
+	// Storage: DonationHandler SellerStats (r:4 w:4)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Marketplace OrderCommissions (r:0 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	// Storage: ParticipantHandler SellerStake (r:1 w:1)
+	fn resolve_dispute() -> Weight {
+		Weight::from_ref_time(328_000_000)
+			.saturating_add(T::DbWeight::get().reads(52))
+			.saturating_add(T::DbWeight::get().writes(36))
+	}
+	// Storage: Marketplace Disputes (r:2 w:1)
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:8 w:8)
+	// Storage: Marketplace SellerVolume (r:1 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	fn expire_dispute() -> Weight {
+		Weight::from_ref_time(148_000_000)
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: Marketplace NextTenderId (r:1 w:1)
+	// Storage: Marketplace Tenders (r:0 w:1)
+	fn post_tender() -> Weight {
+		Weight::from_ref_time(29_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Tenders (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace Bids (r:1 w:1)
+	fn submit_bid() -> Weight {
+		Weight::from_ref_time(34_000_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Tenders (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace Bids (r:1 w:1)
+	fn submit_sealed_bid() -> Weight {
+		Weight::from_ref_time(34_000_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Tenders (r:1 w:0)
+	// Storage: Marketplace Bids (r:1 w:1)
+	fn reveal_bid() -> Weight {
+		Weight::from_ref_time(31_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Marketplace Tenders (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace Bids (r:1 w:100)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	fn award_tender() -> Weight {
+		Weight::from_ref_time(243_000_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(108))
+	}
+	// Storage: Marketplace Tenders (r:1 w:1)
+	// Storage: Marketplace Bids (r:0 w:100)
+	fn cancel_tender() -> Weight {
+		Weight::from_ref_time(187_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(101))
+	}
+	// Storage: Marketplace Orders (r:1 w:0)
+	// Storage: Marketplace Ratings (r:1 w:1)
+	// Storage: Marketplace ProductReputation (r:1 w:1)
+	// Storage: Marketplace SellerReputation (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:1)
+	// Storage: ParticipantHandler SuspendedSellers (r:0 w:1)
+	fn rate_order() -> Weight {
+		Weight::from_ref_time(46_000_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:50 w:0)
+	// Storage: Marketplace ProductMap (r:50 w:50)
+	// Storage: Marketplace ProductExpiry (r:50 w:0)
+	// Storage: Marketplace Discounts (r:50 w:0)
+	// Storage: ParticipantHandler SellerActiveList (r:50 w:0)
+	// Storage: PurposeBasedTokens Asset (r:50 w:50)
+	// Storage: PurposeBasedTokens Account (r:100 w:100)
+	// Storage: Marketplace NextOrderId (r:50 w:50)
+	// Storage: Marketplace ProductsByCategory (r:0 w:50)
+	// Storage: Marketplace ProductsByBuyer (r:0 w:50)
+	// Storage: Marketplace OrdersByProduct (r:0 w:50)
+	// Storage: Marketplace OpenOrdersByProduct (r:50 w:50)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:50)
+	// Storage: Marketplace OrdersBySeller (r:0 w:50)
+	// Storage: Marketplace Orders (r:0 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn buy_many(n: u32, ) -> Weight {
+		Weight::from_ref_time(12_000_000)
+			.saturating_add(Weight::from_ref_time(77_000_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((13_u64).saturating_mul(n.into())))
+	}
+	// Storage: Swap Pools (r:1 w:0)
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	fn set_price_asset() -> Weight {
+		Weight::from_ref_time(33_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Swap Pools (r:1 w:0)
+	// Storage: Marketplace PriceObservations (r:1 w:1)
+	fn update_reference_price() -> Weight {
+		Weight::from_ref_time(31_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace NextAuctionId (r:1 w:1)
+	// Storage: Marketplace AuctionsEndingAt (r:1 w:1)
+	// Storage: Marketplace Auctions (r:0 w:1)
+	fn create_auction() -> Weight {
+		Weight::from_ref_time(37_000_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: Marketplace Auctions (r:2 w:2)
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	fn bid_auction() -> Weight {
+		Weight::from_ref_time(86_000_000)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	// Storage: Marketplace Auctions (r:1 w:1)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	fn settle_auction() -> Weight {
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: Marketplace Auctions (r:1 w:1)
+	fn cancel_auction() -> Weight {
+		Weight::from_ref_time(23_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: Marketplace ProductExpiry (r:0 w:1)
+	fn set_expiry() -> Weight {
+		Weight::from_ref_time(23_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	// Storage: Marketplace OrdersByProduct (r:1001 w:0)
+	// Storage: DonationHandler SellerPayouts (r:101 w:0)
+	// Storage: DonationHandler PendingPayouts (r:100 w:0)
+	// Storage: Marketplace Orders (r:1000 w:1000)
+	// Storage: PurposeBasedTokens Asset (r:4000 w:4000)
+	// Storage: PurposeBasedTokens Account (r:8000 w:8000)
+	// Storage: Marketplace SellerVolume (r:1000 w:1000)
+	// Storage: Marketplace OrderCommissions (r:1000 w:1000)
+	// Storage: Marketplace OpenOrdersByProduct (r:1000 w:1000)
+	/// The range of component `o` is `[0, 1000]`.
+	/// The range of component `p` is `[0, 100]`.
+	fn recall_product(o: u32, p: u32, ) -> Weight {
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(Weight::from_ref_time(63_000_000).saturating_mul(o.into()))
+			.saturating_add(Weight::from_ref_time(4_000_000).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((17_u64).saturating_mul(o.into())))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((12_u64).saturating_mul(o.into())))
+	}
+	// Storage: Marketplace CommissionRates (r:0 w:1)
+	fn set_commission_rate() -> Weight {
+		Weight::from_ref_time(17_000_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Marketplace CommissionDiscountTiers (r:0 w:1)
+	fn set_commission_tiers() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace NextGroupBuyId (r:1 w:1)
+	// Storage: Marketplace GroupBuys (r:0 w:1)
+	fn create_group_buy() -> Weight {
+		Weight::from_ref_time(33_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: Marketplace GroupBuys (r:1 w:1)
+	// Storage: Marketplace GroupCommitments (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	fn commit_group_buy() -> Weight {
+		Weight::from_ref_time(61_000_000)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	// Storage: Marketplace GroupBuys (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace GroupCommitments (r:101 w:100)
+	// Storage: PurposeBasedTokens Asset (r:100 w:100)
+	// Storage: PurposeBasedTokens Account (r:200 w:200)
+	// Storage: Marketplace NextOrderId (r:100 w:100)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:100)
+	// Storage: Marketplace OrdersBySeller (r:0 w:100)
+	// Storage: Marketplace Orders (r:0 w:100)
+	/// The range of component `p` is `[1, 100]`.
+	fn close_group_buy(p: u32, ) -> Weight {
+		Weight::from_ref_time(39_000_000)
+			.saturating_add(Weight::from_ref_time(56_000_000).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(p.into())))
+	}
+	// Storage: Marketplace GroupBuys (r:1 w:1)
+	// Storage: Marketplace GroupCommitments (r:101 w:100)
+	// Storage: PurposeBasedTokens Asset (r:100 w:100)
+	// Storage: PurposeBasedTokens Account (r:200 w:200)
+	/// The range of component `p` is `[0, 100]`.
+	fn cancel_group_buy(p: u32, ) -> Weight {
+		Weight::from_ref_time(31_000_000)
+			.saturating_add(Weight::from_ref_time(41_000_000).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(p.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(p.into())))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: Marketplace NextContractId (r:1 w:1)
+	// Storage: Marketplace SupplyContracts (r:0 w:1)
+	fn propose_supply_contract() -> Weight {
+		Weight::from_ref_time(35_000_000)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Marketplace SupplyContracts (r:1 w:1)
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: Marketplace ContractsDueAt (r:11 w:1)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	fn accept_supply_contract() -> Weight {
+		Weight::from_ref_time(111_000_000)
+			.saturating_add(T::DbWeight::get().reads(18))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: Marketplace SupplyContracts (r:1 w:1)
+	fn terminate_supply_contract() -> Weight {
+		Weight::from_ref_time(25_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: Marketplace Discounts (r:8 w:1)
+	// Storage: Marketplace NextDiscountId (r:1 w:1)
+	/// The range of component `d` is `[0, 7]`.
+	fn add_discount(d: u32, ) -> Weight {
+		Weight::from_ref_time(29_000_000)
+			.saturating_add(Weight::from_ref_time(2_900_000).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: Marketplace Discounts (r:1 w:1)
+	// Storage: Marketplace DiscountRedemptions (r:0 w:1)
+	fn remove_discount() -> Weight {
+		Weight::from_ref_time(29_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Marketplace AuctionsEndingAt (r:1 w:1)
+	// Storage: Marketplace ContractsDueAt (r:551 w:51)
+	// Storage: Marketplace Auctions (r:50 w:50)
+	// Storage: Marketplace SupplyContracts (r:50 w:50)
+	// Storage: Marketplace Orders (r:50 w:150)
+	// Storage: PurposeBasedTokens Asset (r:100 w:100)
+	// Storage: PurposeBasedTokens Account (r:200 w:200)
+	// Storage: Marketplace MissedDeliveries (r:50 w:50)
+	// Storage: Marketplace SellerReputation (r:50 w:50)
+	// Storage: Marketplace Ratings (r:0 w:50)
+	// Storage: ParticipantHandler SellerActiveList (r:100 w:50)
+	// Storage: ParticipantHandler SuspendedSellers (r:0 w:50)
+	// Storage: Marketplace ProductMap (r:50 w:0)
+	// Storage: Marketplace NextOrderId (r:100 w:100)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:100)
+	// Storage: Marketplace OrdersBySeller (r:0 w:100)
+	/// The range of component `a` is `[0, 50]`.
+	/// The range of component `c` is `[0, 50]`.
+	fn on_initialize(a: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(9_000_000)
+			.saturating_add(Weight::from_ref_time(38_000_000).saturating_mul(a.into()))
+			.saturating_add(Weight::from_ref_time(175_000_000).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().reads((26_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes((17_u64).saturating_mul(c.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace ProductNonce (r:1 w:1)
+	// Storage: Marketplace ProductMap (r:0 w:1)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	// Storage: Marketplace ProductsBySeller (r:0 w:1)
+	fn list_product() -> Weight {
+		Weight::from_ref_time(34_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	// Storage: Marketplace ProductExpiry (r:1 w:0)
+	// Storage: Swap Pools (r:1 w:0)
+	// Storage: Marketplace PriceObservations (r:1 w:1)
+	// Storage: Marketplace Discounts (r:8 w:0)
+	// Storage: Marketplace DiscountRedemptions (r:8 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:8 w:8)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	// Storage: Marketplace ProductsByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersByProduct (r:0 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	/// The range of component `d` is `[0, 8]`.
+	fn buy(d: u32, ) -> Weight {
+		Weight::from_ref_time(179_000_000)
+			.saturating_add(Weight::from_ref_time(6_400_000).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(20))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(23))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	fn update_price() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	fn update_metadata() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:0)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	// Storage: Marketplace ProductsBySeller (r:0 w:1)
+	// Storage: Marketplace ProductExpiry (r:0 w:1)
+	// Storage: Marketplace Discounts (r:9 w:8)
+	// Storage: Marketplace DiscountRedemptions (r:0 w:8)
+	// Storage: Marketplace OrdersByProduct (r:1 w:0)
+	/// The range of component `d` is `[0, 8]`.
+	fn delist(d: u32, ) -> Weight {
+		Weight::from_ref_time(36_000_000)
+			.saturating_add(Weight::from_ref_time(4_800_000).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(4))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(d.into())))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	fn restock() -> Weight {
+		Weight::from_ref_time(22_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	fn mark_shipped() -> Weight {
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:12 w:12)
+	// Storage: Marketplace CommissionRates (r:4 w:0)
+	// Storage: Marketplace SellerVolume (r:5 w:1)
+	// Storage: Marketplace SellerReputation (r:4 w:0)
+	// Storage: Marketplace CommissionDiscountTiers (r:4 w:0)
+	// Storage: DonationHandler NgoStats (r:4 w:4)
+	// Storage: DonationHandler SellerStats (r:4 w:4)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Marketplace OrderCommissions (r:0 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	fn confirm_delivery() -> Weight {
+		Weight::from_ref_time(285_000_000)
+			.saturating_add(RocksDbWeight::get().reads(45))
+			.saturating_add(RocksDbWeight::get().writes(30))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:12 w:12)
+	// Storage: Marketplace CommissionRates (r:4 w:0)
+	// Storage: Marketplace SellerVolume (r:5 w:1)
+	// Storage: Marketplace SellerReputation (r:4 w:0)
+	// Storage: Marketplace CommissionDiscountTiers (r:4 w:0)
+	// Storage: DonationHandler NgoStats (r:4 w:4)
+	// Storage: DonationHandler SellerStats (r:4 w:4)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Marketplace OrderCommissions (r:0 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	fn settle_order() -> Weight {
+		Weight::from_ref_time(281_000_000)
+			.saturating_add(RocksDbWeight::get().reads(45))
+			.saturating_add(RocksDbWeight::get().writes(30))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: Marketplace Disputes (r:0 w:1)
+	fn open_dispute() -> Weight {
+		Weight::from_ref_time(25_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Orders (r:1 w:0)
+	// Storage: Marketplace Disputes (r:1 w:1)
+	fn respond_to_dispute() -> Weight {
+		Weight::from_ref_time(26_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: Marketplace Disputes (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:16 w:16)
+	// Storage: Marketplace CommissionRates (r:4 w:0)
+	// Storage: Marketplace SellerVolume (r:5 w:1)
+	// Storage: Marketplace SellerReputation (r:4 w:0)
+	// Storage: Marketplace CommissionDiscountTiers (r:4 w:0)
+	// Storage: DonationHandler NgoStats (r:4 w:4)
+	// Storage: DonationHandler SellerStats (r:4 w:4)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Marketplace OrderCommissions (r:0 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	// Storage: ParticipantHandler SellerStake (r:1 w:1)
+	fn resolve_dispute() -> Weight {
+		Weight::from_ref_time(328_000_000)
+			.saturating_add(RocksDbWeight::get().reads(52))
+			.saturating_add(RocksDbWeight::get().writes(36))
+	}
+	// Storage: Marketplace Disputes (r:2 w:1)
+	// Storage: Marketplace Orders (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:4 w:4)
+	// Storage: PurposeBasedTokens Account (r:8 w:8)
+	// Storage: Marketplace SellerVolume (r:1 w:1)
+	// Storage: Marketplace OpenOrdersByProduct (r:1 w:1)
+	fn expire_dispute() -> Weight {
+		Weight::from_ref_time(148_000_000)
+			.saturating_add(RocksDbWeight::get().reads(17))
+			.saturating_add(RocksDbWeight::get().writes(16))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: Marketplace NextTenderId (r:1 w:1)
+	// Storage: Marketplace Tenders (r:0 w:1)
+	fn post_tender() -> Weight {
+		Weight::from_ref_time(29_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Tenders (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace Bids (r:1 w:1)
+	fn submit_bid() -> Weight {
+		Weight::from_ref_time(34_000_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Tenders (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace Bids (r:1 w:1)
+	fn submit_sealed_bid() -> Weight {
+		Weight::from_ref_time(34_000_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Marketplace Tenders (r:1 w:0)
+	// Storage: Marketplace Bids (r:1 w:1)
+	fn reveal_bid() -> Weight {
+		Weight::from_ref_time(31_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Marketplace Tenders (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace Bids (r:1 w:100)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	fn award_tender() -> Weight {
+		Weight::from_ref_time(243_000_000)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(108))
+	}
+	// Storage: Marketplace Tenders (r:1 w:1)
+	// Storage: Marketplace Bids (r:0 w:100)
+	fn cancel_tender() -> Weight {
+		Weight::from_ref_time(187_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(101))
+	}
+	// Storage: Marketplace Orders (r:1 w:0)
+	// Storage: Marketplace Ratings (r:1 w:1)
+	// Storage: Marketplace ProductReputation (r:1 w:1)
+	// Storage: Marketplace SellerReputation (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:1)
+	// Storage: ParticipantHandler SuspendedSellers (r:0 w:1)
+	fn rate_order() -> Weight {
+		Weight::from_ref_time(46_000_000)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:50 w:0)
+	// Storage: Marketplace ProductMap (r:50 w:50)
+	// Storage: Marketplace ProductExpiry (r:50 w:0)
+	// Storage: Marketplace Discounts (r:50 w:0)
+	// Storage: ParticipantHandler SellerActiveList (r:50 w:0)
+	// Storage: PurposeBasedTokens Asset (r:50 w:50)
+	// Storage: PurposeBasedTokens Account (r:100 w:100)
+	// Storage: Marketplace NextOrderId (r:50 w:50)
+	// Storage: Marketplace ProductsByCategory (r:0 w:50)
+	// Storage: Marketplace ProductsByBuyer (r:0 w:50)
+	// Storage: Marketplace OrdersByProduct (r:0 w:50)
+	// Storage: Marketplace OpenOrdersByProduct (r:50 w:50)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:50)
+	// Storage: Marketplace OrdersBySeller (r:0 w:50)
+	// Storage: Marketplace Orders (r:0 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn buy_many(n: u32, ) -> Weight {
+		Weight::from_ref_time(12_000_000)
+			.saturating_add(Weight::from_ref_time(77_000_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((13_u64).saturating_mul(n.into())))
+	}
+	// Storage: Swap Pools (r:1 w:0)
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	fn set_price_asset() -> Weight {
+		Weight::from_ref_time(33_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Swap Pools (r:1 w:0)
+	// Storage: Marketplace PriceObservations (r:1 w:1)
+	fn update_reference_price() -> Weight {
+		Weight::from_ref_time(31_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace NextAuctionId (r:1 w:1)
+	// Storage: Marketplace AuctionsEndingAt (r:1 w:1)
+	// Storage: Marketplace Auctions (r:0 w:1)
+	fn create_auction() -> Weight {
+		Weight::from_ref_time(37_000_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	// Storage: Marketplace Auctions (r:2 w:2)
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	fn bid_auction() -> Weight {
+		Weight::from_ref_time(86_000_000)
+			.saturating_add(RocksDbWeight::get().reads(7))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
+	// Storage: Marketplace Auctions (r:1 w:1)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	fn settle_auction() -> Weight {
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	// Storage: Marketplace Auctions (r:1 w:1)
+	fn cancel_auction() -> Weight {
+		Weight::from_ref_time(23_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: Marketplace ProductExpiry (r:0 w:1)
+	fn set_expiry() -> Weight {
+		Weight::from_ref_time(23_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:1)
+	// Storage: Marketplace ProductsByCategory (r:0 w:1)
+	// Storage: Marketplace OrdersByProduct (r:1001 w:0)
+	// Storage: DonationHandler SellerPayouts (r:101 w:0)
+	// Storage: DonationHandler PendingPayouts (r:100 w:0)
+	// Storage: Marketplace Orders (r:1000 w:1000)
+	// Storage: PurposeBasedTokens Asset (r:4000 w:4000)
+	// Storage: PurposeBasedTokens Account (r:8000 w:8000)
+	// Storage: Marketplace SellerVolume (r:1000 w:1000)
+	// Storage: Marketplace OrderCommissions (r:1000 w:1000)
+	// Storage: Marketplace OpenOrdersByProduct (r:1000 w:1000)
+	/// The range of component `o` is `[0, 1000]`.
+	/// The range of component `p` is `[0, 100]`.
+	fn recall_product(o: u32, p: u32, ) -> Weight {
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(Weight::from_ref_time(63_000_000).saturating_mul(o.into()))
+			.saturating_add(Weight::from_ref_time(4_000_000).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((17_u64).saturating_mul(o.into())))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((12_u64).saturating_mul(o.into())))
+	}
+	// Storage: Marketplace CommissionRates (r:0 w:1)
+	fn set_commission_rate() -> Weight {
+		Weight::from_ref_time(17_000_000)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Marketplace CommissionDiscountTiers (r:0 w:1)
+	fn set_commission_tiers() -> Weight {
+		Weight::from_ref_time(19_000_000)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace NextGroupBuyId (r:1 w:1)
+	// Storage: Marketplace GroupBuys (r:0 w:1)
+	fn create_group_buy() -> Weight {
+		Weight::from_ref_time(33_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: Marketplace GroupBuys (r:1 w:1)
+	// Storage: Marketplace GroupCommitments (r:1 w:1)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	fn commit_group_buy() -> Weight {
+		Weight::from_ref_time(61_000_000)
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(5))
+	}
+	// Storage: Marketplace GroupBuys (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: Marketplace GroupCommitments (r:101 w:100)
+	// Storage: PurposeBasedTokens Asset (r:100 w:100)
+	// Storage: PurposeBasedTokens Account (r:200 w:200)
+	// Storage: Marketplace NextOrderId (r:100 w:100)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:100)
+	// Storage: Marketplace OrdersBySeller (r:0 w:100)
+	// Storage: Marketplace Orders (r:0 w:100)
+	/// The range of component `p` is `[1, 100]`.
+	fn close_group_buy(p: u32, ) -> Weight {
+		Weight::from_ref_time(39_000_000)
+			.saturating_add(Weight::from_ref_time(56_000_000).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((8_u64).saturating_mul(p.into())))
+	}
+	// Storage: Marketplace GroupBuys (r:1 w:1)
+	// Storage: Marketplace GroupCommitments (r:101 w:100)
+	// Storage: PurposeBasedTokens Asset (r:100 w:100)
+	// Storage: PurposeBasedTokens Account (r:200 w:200)
+	/// The range of component `p` is `[0, 100]`.
+	fn cancel_group_buy(p: u32, ) -> Weight {
+		Weight::from_ref_time(31_000_000)
+			.saturating_add(Weight::from_ref_time(41_000_000).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(p.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(p.into())))
+	}
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: Marketplace NextContractId (r:1 w:1)
+	// Storage: Marketplace SupplyContracts (r:0 w:1)
+	fn propose_supply_contract() -> Weight {
+		Weight::from_ref_time(35_000_000)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Marketplace SupplyContracts (r:1 w:1)
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: PurposeBasedTokens Asset (r:1 w:1)
+	// Storage: PurposeBasedTokens Account (r:2 w:2)
+	// Storage: Marketplace ContractsDueAt (r:11 w:1)
+	// Storage: Marketplace NextOrderId (r:1 w:1)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:1)
+	// Storage: Marketplace OrdersBySeller (r:0 w:1)
+	// Storage: Marketplace Orders (r:0 w:1)
+	fn accept_supply_contract() -> Weight {
+		Weight::from_ref_time(111_000_000)
+			.saturating_add(RocksDbWeight::get().reads(18))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	// Storage: Marketplace SupplyContracts (r:1 w:1)
+	fn terminate_supply_contract() -> Weight {
+		Weight::from_ref_time(25_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: Marketplace Discounts (r:8 w:1)
+	// Storage: Marketplace NextDiscountId (r:1 w:1)
+	/// The range of component `d` is `[0, 7]`.
+	fn add_discount(d: u32, ) -> Weight {
+		Weight::from_ref_time(29_000_000)
+			.saturating_add(Weight::from_ref_time(2_900_000).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(d.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Marketplace ProductMap (r:1 w:0)
+	// Storage: Marketplace Discounts (r:1 w:1)
+	// Storage: Marketplace DiscountRedemptions (r:0 w:1)
+	fn remove_discount() -> Weight {
+		Weight::from_ref_time(29_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Marketplace AuctionsEndingAt (r:1 w:1)
+	// Storage: Marketplace ContractsDueAt (r:551 w:51)
+	// Storage: Marketplace Auctions (r:50 w:50)
+	// Storage: Marketplace SupplyContracts (r:50 w:50)
+	// Storage: Marketplace Orders (r:50 w:150)
+	// Storage: PurposeBasedTokens Asset (r:100 w:100)
+	// Storage: PurposeBasedTokens Account (r:200 w:200)
+	// Storage: Marketplace MissedDeliveries (r:50 w:50)
+	// Storage: Marketplace SellerReputation (r:50 w:50)
+	// Storage: Marketplace Ratings (r:0 w:50)
+	// Storage: ParticipantHandler SellerActiveList (r:100 w:50)
+	// Storage: ParticipantHandler SuspendedSellers (r:0 w:50)
+	// Storage: Marketplace ProductMap (r:50 w:0)
+	// Storage: Marketplace NextOrderId (r:100 w:100)
+	// Storage: Marketplace OrdersByBuyer (r:0 w:100)
+	// Storage: Marketplace OrdersBySeller (r:0 w:100)
+	/// The range of component `a` is `[0, 50]`.
+	/// The range of component `c` is `[0, 50]`.
+	fn on_initialize(a: u32, c: u32, ) -> Weight {
+		Weight::from_ref_time(9_000_000)
+			.saturating_add(Weight::from_ref_time(38_000_000).saturating_mul(a.into()))
+			.saturating_add(Weight::from_ref_time(175_000_000).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().reads((26_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes((17_u64).saturating_mul(c.into())))
+	}
+}
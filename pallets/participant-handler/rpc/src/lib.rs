@@ -0,0 +1,158 @@
+//! RPC interface for the participant-handler pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use participant_handler::{Category, NgoInfo, SellerInfo};
+pub use participant_handler_runtime_api::ParticipantApi as ParticipantRuntimeApi;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+pub use support_rpc::Page;
+use support_rpc::{block_at, into_page, page_size, runtime_error};
+
+/// Ngo or seller, either applying or active.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Participant<AccountId, Hash> {
+	pub account: AccountId,
+	pub categories: Vec<Category>,
+	/// Content Id of the participant details on IPFS.
+	pub cid: Hash,
+}
+
+impl<AccountId, Hash> From<(AccountId, NgoInfo<Hash>)> for Participant<AccountId, Hash> {
+	fn from((account, info): (AccountId, NgoInfo<Hash>)) -> Self {
+		Self { account, categories: info.categories.into_inner(), cid: info.cid }
+	}
+}
+
+impl<AccountId, Hash> From<(AccountId, SellerInfo<Hash>)> for Participant<AccountId, Hash> {
+	fn from((account, info): (AccountId, SellerInfo<Hash>)) -> Self {
+		Self { account, categories: info.categories.into_inner(), cid: info.cid }
+	}
+}
+
+#[rpc(client, server)]
+pub trait ParticipantApi<BlockHash, AccountId, Hash> {
+	/// Returns a page of pending Ngo applications.
+	#[method(name = "participants_ngoApplications")]
+	fn ngo_applications(
+		&self,
+		start_after: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Participant<AccountId, Hash>, AccountId>>;
+
+	/// Returns a page of active Ngos with their categories.
+	#[method(name = "participants_activeNgos")]
+	fn active_ngos(
+		&self,
+		start_after: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Participant<AccountId, Hash>, AccountId>>;
+
+	/// Returns a page of pending seller applications.
+	#[method(name = "participants_sellerApplications")]
+	fn seller_applications(
+		&self,
+		start_after: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Participant<AccountId, Hash>, AccountId>>;
+
+	/// Returns a page of active sellers with their categories.
+	#[method(name = "participants_activeSellers")]
+	fn active_sellers(
+		&self,
+		start_after: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Page<Participant<AccountId, Hash>, AccountId>>;
+}
+
+/// Participant RPC methods, backed by the `ParticipantApi` runtime API.
+pub struct Participants<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Participants<C, Block> {
+	/// Create new `Participants` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, AccountId, Hash> ParticipantApiServer<<Block as BlockT>::Hash, AccountId, Hash>
+	for Participants<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: ParticipantRuntimeApi<Block, AccountId, Hash>,
+	AccountId: Codec + Clone + Send + Sync + 'static + Serialize + DeserializeOwned,
+	Hash: Codec + Send + Sync + 'static + Serialize + DeserializeOwned,
+{
+	fn ngo_applications(
+		&self,
+		start_after: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Participant<AccountId, Hash>, AccountId>> {
+		let limit = page_size(limit);
+		let applications = self
+			.client
+			.runtime_api()
+			.ngo_applications(&block_at(&*self.client, at), start_after, limit)
+			.map_err(runtime_error)?;
+		Ok(into_page(applications, limit))
+	}
+
+	fn active_ngos(
+		&self,
+		start_after: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Participant<AccountId, Hash>, AccountId>> {
+		let limit = page_size(limit);
+		let ngos = self
+			.client
+			.runtime_api()
+			.active_ngos(&block_at(&*self.client, at), start_after, limit)
+			.map_err(runtime_error)?;
+		Ok(into_page(ngos, limit))
+	}
+
+	fn seller_applications(
+		&self,
+		start_after: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Participant<AccountId, Hash>, AccountId>> {
+		let limit = page_size(limit);
+		let applications = self
+			.client
+			.runtime_api()
+			.seller_applications(&block_at(&*self.client, at), start_after, limit)
+			.map_err(runtime_error)?;
+		Ok(into_page(applications, limit))
+	}
+
+	fn active_sellers(
+		&self,
+		start_after: Option<AccountId>,
+		limit: Option<u32>,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Page<Participant<AccountId, Hash>, AccountId>> {
+		let limit = page_size(limit);
+		let sellers = self
+			.client
+			.runtime_api()
+			.active_sellers(&block_at(&*self.client, at), start_after, limit)
+			.map_err(runtime_error)?;
+		Ok(into_page(sellers, limit))
+	}
+}
@@ -0,0 +1,28 @@
+//! Runtime API definition for the participant-handler pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::too_many_arguments, clippy::unnecessary_mut_passed)]
+
+use codec::Codec;
+use participant_handler::{NgoInfo, SellerInfo};
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ParticipantApi<AccountId, Hash>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+	{
+		/// Returns up to `limit` pending Ngo applications, starting after `start_after`.
+		fn ngo_applications(start_after: Option<AccountId>, limit: u32) -> Vec<(AccountId, NgoInfo<Hash>)>;
+
+		/// Returns up to `limit` active Ngos with their categories, starting after `start_after`.
+		fn active_ngos(start_after: Option<AccountId>, limit: u32) -> Vec<(AccountId, NgoInfo<Hash>)>;
+
+		/// Returns up to `limit` pending seller applications, starting after `start_after`.
+		fn seller_applications(start_after: Option<AccountId>, limit: u32) -> Vec<(AccountId, SellerInfo<Hash>)>;
+
+		/// Returns up to `limit` active sellers with their categories, starting after `start_after`.
+		fn active_sellers(start_after: Option<AccountId>, limit: u32) -> Vec<(AccountId, SellerInfo<Hash>)>;
+	}
+}
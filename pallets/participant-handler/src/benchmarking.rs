@@ -0,0 +1,110 @@
+//! Benchmarking setup for participant-handler
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as ParticipantHandler;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::{
+	sp_runtime::traits::{Bounded, Hash},
+	traits::{Currency, EnsureOrigin},
+};
+use frame_system::RawOrigin;
+use pallet_identity::{Data, IdentityInfo};
+use sp_std::boxed::Box;
+
+const SEED: u32 = 0;
+
+type CurrencyBalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Funded account with the display and legal name set, as required to apply.
+fn applicant<T: Config>(name: &'static str) -> T::AccountId {
+	let who: T::AccountId = account(name, 0, SEED);
+	<T as Config>::Currency::make_free_balance_be(
+		&who,
+		CurrencyBalanceOf::<T>::max_value() / 2u32.into(),
+	);
+	let info = IdentityInfo {
+		additional: Default::default(),
+		display: Data::Raw(b"name".to_vec().try_into().unwrap()),
+		legal: Data::Raw(b"legal name".to_vec().try_into().unwrap()),
+		web: Default::default(),
+		riot: Default::default(),
+		email: Default::default(),
+		pgp_fingerprint: None,
+		image: Default::default(),
+		twitter: Default::default(),
+	};
+	pallet_identity::Pallet::<T>::set_identity(RawOrigin::Signed(who.clone()).into(), Box::new(info))
+		.unwrap();
+	who
+}
+
+fn ngo_info<T: Config>(who: &T::AccountId) -> NgoInfo<T::Hash> {
+	NgoInfo {
+		categories: Category::all().to_vec().try_into().unwrap(),
+		cid: T::Hashing::hash_of(who),
+	}
+}
+
+fn seller_info<T: Config>(who: &T::AccountId) -> SellerInfo<T::Hash> {
+	SellerInfo {
+		categories: Category::all().to_vec().try_into().unwrap(),
+		cid: T::Hashing::hash_of(who),
+	}
+}
+
+benchmarks! {
+	apply_as_ngo {
+		let ngo = applicant::<T>("ngo");
+		let info = ngo_info::<T>(&ngo);
+	}: _(RawOrigin::Signed(ngo.clone()), info)
+	verify {
+		assert!(<NgoWaitingList<T>>::contains_key(&ngo));
+	}
+
+	approve_ngo {
+		let ngo = applicant::<T>("ngo");
+		ParticipantHandler::<T>::apply_as_ngo(RawOrigin::Signed(ngo.clone()).into(), ngo_info::<T>(&ngo))?;
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, ngo.clone())
+	verify {
+		assert!(<NgoActiveList<T>>::contains_key(&ngo));
+	}
+
+	apply_as_seller {
+		let seller = applicant::<T>("seller");
+		let info = seller_info::<T>(&seller);
+	}: _(RawOrigin::Signed(seller.clone()), info)
+	verify {
+		assert!(<SellerWaitingList<T>>::contains_key(&seller));
+	}
+
+	approve_seller {
+		let seller = applicant::<T>("seller");
+		ParticipantHandler::<T>::apply_as_seller(
+			RawOrigin::Signed(seller.clone()).into(),
+			seller_info::<T>(&seller),
+		)?;
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, seller.clone())
+	verify {
+		assert!(<SellerActiveList<T>>::contains_key(&seller));
+	}
+
+	reinstate_seller {
+		let seller: T::AccountId = account("seller", 0, SEED);
+		ParticipantHandler::<T>::add_seller_to_active_list(&seller, seller_info::<T>(&seller));
+		ParticipantHandler::<T>::suspend_seller(&seller);
+		let origin = T::GovernanceOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, seller.clone())
+	verify {
+		assert!(<SellerActiveList<T>>::contains_key(&seller));
+	}
+
+	impl_benchmark_test_suite!(ParticipantHandler, crate::mock::new_test_ext(), crate::mock::Test);
+}
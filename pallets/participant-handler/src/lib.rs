@@ -8,6 +8,12 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+pub mod weights;
+pub use weights::WeightInfo;
+
 pub type BalanceOf<T> = <T as pallet_balances::Config>::Balance;
 
 #[frame_support::pallet]
@@ -18,11 +24,16 @@ pub mod pallet {
 		sp_runtime::SaturatedConversion,
 		traits::{Currency, NamedReservableCurrency, ReservableCurrency},
 	};
+	use crate::WeightInfo;
 	use frame_system::pallet_prelude::*;
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
+	use sp_std::vec::Vec;
 
 	#[derive(
 		Encode, Decode, Clone, Copy, Debug, MaxEncodedLen, TypeInfo, PartialOrd, PartialEq, Eq,
 	)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	pub enum Category {
 		Pharmaceutical = 0,
 		Stationery = 1,
@@ -40,25 +51,32 @@ pub mod pallet {
 		pub fn get_id(&self) -> u8 {
 			*self as u8
 		}
+
+		/// Returns every Category, each of which is backed by its own Purpose Based Token.
+		pub fn all() -> [Category; 4] {
+			[Self::Pharmaceutical, Self::Stationery, Self::Grocery, Self::Clothing]
+		}
 	}
 
 	#[derive(
 		Encode, Decode, Clone, Debug, MaxEncodedLen, TypeInfo, Default, PartialOrd, PartialEq,
 	)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	pub struct SellerInfo<Hash> {
-		pub category: Category,
+		pub categories: BoundedVec<Category, ConstU32<100>>,
 		pub cid: Hash,
 	}
 
 	impl<Hash> SellerInfo<Hash> {
 		pub fn is_category_allowed(&self, category: &Category) -> bool {
-			*category == self.category
+			self.categories.contains(category)
 		}
 	}
 
 	#[derive(
 		Encode, Decode, Clone, Debug, MaxEncodedLen, TypeInfo, Default, PartialOrd, PartialEq,
 	)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	pub struct NgoInfo<Hash> {
 		pub categories: BoundedVec<Category, ConstU32<100>>,
 		pub cid: Hash,
@@ -70,8 +88,12 @@ pub mod pallet {
 		}
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -87,6 +109,8 @@ pub mod pallet {
 		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
 		/// Governance Origin
 		type GovernanceOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	#[pallet::storage]
@@ -109,6 +133,47 @@ pub mod pallet {
 	pub type SellerActiveList<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, SellerInfo<T::Hash>, ValueQuery>;
 
+	/// Sellers removed from the active list, e.g. for a poor reputation
+	#[pallet::storage]
+	#[pallet::getter(fn get_suspended_seller)]
+	pub type SuspendedSellers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, SellerInfo<T::Hash>, OptionQuery>;
+
+	/// Stake each seller still has reserved, slashes draw from it and nothing else
+	#[pallet::storage]
+	#[pallet::getter(fn get_seller_stake)]
+	pub type SellerStake<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, u128, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Ngos approved at genesis.
+		pub ngos: Vec<(T::AccountId, NgoInfo<T::Hash>)>,
+		/// Sellers approved at genesis.
+		pub sellers: Vec<(T::AccountId, SellerInfo<T::Hash>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { ngos: Default::default(), sellers: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (ngo, ngo_info) in &self.ngos {
+				<NgoActiveList<T>>::insert(ngo, ngo_info.clone());
+			}
+			for (seller, seller_info) in &self.sellers {
+				Pallet::<T>::reserve_seller_stake(seller)
+					.expect("Genesis seller cannot reserve its stake");
+				<SellerActiveList<T>>::insert(seller, seller_info.clone());
+			}
+		}
+	}
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -124,6 +189,15 @@ pub mod pallet {
 		/// New Seller Added
 		/// parameters. [who]
 		NewSellerAdded { applicant: T::AccountId },
+		/// Seller Stake Slashed
+		/// parameters. [seller, amount]
+		SellerSlashed { seller: T::AccountId, amount: u128 },
+		/// Seller Suspended
+		/// parameters. [seller]
+		SellerSuspended { seller: T::AccountId },
+		/// Seller Reinstated
+		/// parameters. [seller]
+		SellerReinstated { seller: T::AccountId },
 	}
 
 	// Errors inform users that something went wrong.
@@ -139,6 +213,8 @@ pub mod pallet {
 		AlreadyPartOfActiveList,
 		/// Applicant Not Found
 		ApplicantNotFound,
+		/// Seller Not Suspended
+		SellerNotSuspended,
 	}
 
 	#[pallet::call]
@@ -148,7 +224,7 @@ pub mod pallet {
 		/// # Parameters
 		/// * `ngo_info`: Ngo info.
 		#[pallet::call_index(0)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(<T as Config>::WeightInfo::apply_as_ngo())]
 		pub fn apply_as_ngo(origin: OriginFor<T>, ngo_info: NgoInfo<T::Hash>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			ensure!(
@@ -166,7 +242,7 @@ pub mod pallet {
 		/// # Parameters
 		/// * `applicant`: Applicant.
 		#[pallet::call_index(1)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_ngo())]
 		pub fn approve_ngo(origin: OriginFor<T>, applicant: T::AccountId) -> DispatchResult {
 			T::GovernanceOrigin::ensure_origin(origin)?;
 			Self::do_approve_ngo(&applicant)?;
@@ -180,7 +256,7 @@ pub mod pallet {
 		/// # Parameters
 		/// * `seller_info`: Seller info.
 		#[pallet::call_index(2)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(<T as Config>::WeightInfo::apply_as_seller())]
 		pub fn apply_as_seller(
 			origin: OriginFor<T>,
 			seller_info: SellerInfo<T::Hash>,
@@ -201,7 +277,7 @@ pub mod pallet {
 		/// # Parameters
 		/// * `applicant`: Applicant.
 		#[pallet::call_index(3)]
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1).ref_time())]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_seller())]
 		pub fn approve_seller(origin: OriginFor<T>, applicant: T::AccountId) -> DispatchResult {
 			T::GovernanceOrigin::ensure_origin(origin)?;
 			Self::do_approve_seller(&applicant)?;
@@ -209,6 +285,22 @@ pub mod pallet {
 			// Emit Event
 			Ok(())
 		}
+
+		/// Reinstate Seller
+		/// Only Half of General Council or Root can call it.
+		///
+		/// # Parameters
+		/// * `seller`: Suspended Seller.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::reinstate_seller())]
+		pub fn reinstate_seller(origin: OriginFor<T>, seller: T::AccountId) -> DispatchResult {
+			T::GovernanceOrigin::ensure_origin(origin)?;
+			let seller_info =
+				<SuspendedSellers<T>>::take(&seller).ok_or(Error::<T>::SellerNotSuspended)?;
+			<SellerActiveList<T>>::insert(&seller, seller_info);
+			Self::deposit_event(Event::SellerReinstated { seller });
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -253,10 +345,7 @@ pub mod pallet {
 				!<SellerActiveList<T>>::contains_key(applicant),
 				Error::<T>::AlreadyPartOfActiveList
 			);
-			<T as Config>::Currency::reserve(
-				applicant,
-				T::SellerStakingAmount::get().saturated_into(),
-			)?;
+			Self::reserve_seller_stake(applicant)?;
 			<SellerWaitingList<T>>::insert(applicant, seller_info);
 			Ok(())
 		}
@@ -294,5 +383,103 @@ pub mod pallet {
 		pub fn is_active_seller(seller: &T::AccountId) -> bool {
 			<SellerActiveList<T>>::contains_key(seller)
 		}
+
+		/// Whether `seller` is active and allowed to sell in `category`.
+		pub fn is_seller_category_allowed(seller: &T::AccountId, category: &Category) -> bool {
+			<SellerActiveList<T>>::try_get(seller)
+				.map(|seller_info| seller_info.is_category_allowed(category))
+				.unwrap_or(false)
+		}
+
+		/// Whether `ngo` is active and allowed to receive PBT of `category`.
+		pub fn is_ngo_category_allowed(ngo: &T::AccountId, category: &Category) -> bool {
+			<NgoActiveList<T>>::try_get(ngo)
+				.map(|ngo_info| ngo_info.is_category_allowed(category))
+				.unwrap_or(false)
+		}
+
+		/// Move an active seller to the suspended list until governance reinstates it.
+		pub fn suspend_seller(seller: &T::AccountId) {
+			if let Ok(seller_info) = <SellerActiveList<T>>::try_get(seller) {
+				<SellerActiveList<T>>::remove(seller);
+				<SuspendedSellers<T>>::insert(seller, seller_info);
+				Self::deposit_event(Event::SellerSuspended { seller: seller.clone() });
+			}
+		}
+
+		/// Up to `limit` pending Ngo applications, starting after the applicant `start_after`.
+		pub fn ngo_applications(
+			start_after: Option<T::AccountId>,
+			limit: u32,
+		) -> Vec<(T::AccountId, NgoInfo<T::Hash>)> {
+			let applications = match start_after {
+				Some(applicant) =>
+					<NgoWaitingList<T>>::iter_from(<NgoWaitingList<T>>::hashed_key_for(applicant)),
+				None => <NgoWaitingList<T>>::iter(),
+			};
+			applications.take(limit as usize).collect()
+		}
+
+		/// Up to `limit` active Ngos, starting after the Ngo `start_after`.
+		pub fn active_ngos(
+			start_after: Option<T::AccountId>,
+			limit: u32,
+		) -> Vec<(T::AccountId, NgoInfo<T::Hash>)> {
+			let ngos = match start_after {
+				Some(ngo) => <NgoActiveList<T>>::iter_from(<NgoActiveList<T>>::hashed_key_for(ngo)),
+				None => <NgoActiveList<T>>::iter(),
+			};
+			ngos.take(limit as usize).collect()
+		}
+
+		/// Up to `limit` pending seller applications, starting after the applicant `start_after`.
+		pub fn seller_applications(
+			start_after: Option<T::AccountId>,
+			limit: u32,
+		) -> Vec<(T::AccountId, SellerInfo<T::Hash>)> {
+			let applications = match start_after {
+				Some(applicant) => <SellerWaitingList<T>>::iter_from(
+					<SellerWaitingList<T>>::hashed_key_for(applicant),
+				),
+				None => <SellerWaitingList<T>>::iter(),
+			};
+			applications.take(limit as usize).collect()
+		}
+
+		/// Up to `limit` active sellers, starting after the seller `start_after`.
+		pub fn active_sellers(
+			start_after: Option<T::AccountId>,
+			limit: u32,
+		) -> Vec<(T::AccountId, SellerInfo<T::Hash>)> {
+			let sellers = match start_after {
+				Some(seller) =>
+					<SellerActiveList<T>>::iter_from(<SellerActiveList<T>>::hashed_key_for(seller)),
+				None => <SellerActiveList<T>>::iter(),
+			};
+			sellers.take(limit as usize).collect()
+		}
+
+		/// Reserve `SellerStakingAmount` from a seller and add it to the stake it can be slashed
+		/// for.
+		pub fn reserve_seller_stake(seller: &T::AccountId) -> DispatchResult {
+			let stake = T::SellerStakingAmount::get();
+			<T as Config>::Currency::reserve(seller, stake.saturated_into())?;
+			<SellerStake<T>>::mutate(seller, |staked| *staked = staked.saturating_add(stake));
+			Ok(())
+		}
+
+		/// Slash up to `amount` of a seller's reserved stake, returning the amount slashed.
+		///
+		/// The slash is capped at the stake the seller has left across all slashes, other
+		/// reserves such as the identity deposit are left untouched.
+		pub fn slash_seller(seller: &T::AccountId, amount: u128) -> u128 {
+			let amount = amount.min(Self::get_seller_stake(seller));
+			let (_, remaining) =
+				<T as Config>::Currency::slash_reserved(seller, amount.saturated_into());
+			let slashed = amount.saturating_sub(remaining.saturated_into::<u128>());
+			<SellerStake<T>>::mutate(seller, |staked| *staked = staked.saturating_sub(slashed));
+			Self::deposit_event(Event::SellerSlashed { seller: seller.clone(), amount: slashed });
+			slashed
+		}
 	}
 }
@@ -0,0 +1,109 @@
+//! Storage migrations for the participant-handler pallet.
+
+/// Sellers may deal in several categories, `SellerInfo` keeps a list of them instead of a
+/// single `category`, and their reserved stake is tracked so slashes cannot reach other
+/// reserves.
+pub mod v1 {
+	use crate::{
+		Category, Config, Pallet, SellerActiveList, SellerInfo, SellerStake, SellerWaitingList,
+	};
+	use frame_support::{
+		log,
+		pallet_prelude::*,
+		sp_runtime::SaturatedConversion,
+		traits::{GetStorageVersion, OnRuntimeUpgrade, ReservableCurrency},
+	};
+	use sp_std::marker::PhantomData;
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	#[derive(Decode)]
+	struct OldSellerInfo<Hash> {
+		category: Category,
+		cid: Hash,
+	}
+
+	impl<Hash> From<OldSellerInfo<Hash>> for SellerInfo<Hash> {
+		fn from(old: OldSellerInfo<Hash>) -> Self {
+			let mut categories = BoundedVec::default();
+			// The bound is far above a single category
+			let _ = categories.try_push(old.category);
+			SellerInfo { categories, cid: old.cid }
+		}
+	}
+
+	pub struct MigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain = Pallet::<T>::on_chain_storage_version();
+			if on_chain != 0 {
+				log::info!(
+					target: "participant-handler",
+					"Skipping migration to v1, storage is already at {:?}",
+					on_chain
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let mut translated = 0u64;
+			let mut translate = |seller: T::AccountId, old: OldSellerInfo<T::Hash>| {
+				// Sellers reserved their stake on applying, genesis sellers may hold none
+				let reserved: u128 =
+					<T as Config>::Currency::reserved_balance(&seller).saturated_into();
+				<SellerStake<T>>::insert(&seller, reserved.min(T::SellerStakingAmount::get()));
+				translated += 1;
+				Some(SellerInfo::from(old))
+			};
+			<SellerWaitingList<T>>::translate(&mut translate);
+			<SellerActiveList<T>>::translate(&mut translate);
+			StorageVersion::new(1).put::<Pallet<T>>();
+
+			log::info!(target: "participant-handler", "Migrated {} sellers to v1", translated);
+			T::DbWeight::get().reads_writes(
+				translated.saturating_mul(2).saturating_add(1),
+				translated.saturating_mul(2).saturating_add(1),
+			)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let sellers = (
+				<SellerWaitingList<T>>::iter_keys().count() as u32,
+				<SellerActiveList<T>>::iter_keys().count() as u32,
+			);
+			Ok(sellers.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (waiting, active): (u32, u32) =
+				Decode::decode(&mut &state[..]).map_err(|_| "Cannot decode seller counts")?;
+			ensure!(Pallet::<T>::on_chain_storage_version() == 1, "Storage version not updated");
+			ensure!(
+				<SellerWaitingList<T>>::iter_values().count() as u32 == waiting,
+				"Waiting sellers lost in migration"
+			);
+			ensure!(
+				<SellerActiveList<T>>::iter_values().count() as u32 == active,
+				"Active sellers lost in migration"
+			);
+			ensure!(
+				<SellerWaitingList<T>>::iter_values()
+					.chain(<SellerActiveList<T>>::iter_values())
+					.all(|info| !info.categories.is_empty()),
+				"Seller without a category"
+			);
+			ensure!(
+				<SellerWaitingList<T>>::iter_keys()
+					.chain(<SellerActiveList<T>>::iter_keys())
+					.all(|seller| {
+						<SellerStake<T>>::get(&seller) <=
+							<T as Config>::Currency::reserved_balance(&seller).saturated_into()
+					}),
+				"Seller stake above its reserved balance"
+			);
+			Ok(())
+		}
+	}
+}
@@ -110,6 +110,7 @@ impl participant_handler::Config for Test {
 	type SellerStakingAmount = SellerStakingAmount;
 	type Currency = Balances;
 	type GovernanceOrigin = EnsureSigned<Self::AccountId>;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
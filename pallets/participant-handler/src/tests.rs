@@ -1,8 +1,13 @@
 use crate::{
-	mock::*, Category, Error, Event, NgoActiveList, NgoInfo, NgoWaitingList, SellerActiveList,
-	SellerInfo, SellerWaitingList,
+	migrations, mock::*, Category, Error, Event, GenesisConfig, NgoActiveList, NgoInfo,
+	NgoWaitingList, SellerActiveList, SellerInfo, SellerWaitingList,
+};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	storage::unhashed,
+	traits::{GenesisBuild, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
 };
-use frame_support::{assert_noop, assert_ok};
 use pallet_identity::{Data, IdentityInfo};
 use sp_core::bounded::BoundedVec;
 use sp_runtime::testing::H256;
@@ -143,6 +148,110 @@ fn test_approve_seller_with_already_part_of_active_list_returns_error() {
 	})
 }
 
+#[test]
+fn test_genesis_config_adds_participants_to_active_list() {
+	let (ngo, ngo_info) = get_ngo_info();
+	let seller: u64 = 2;
+	let seller_info = SellerInfo {
+		categories: BoundedVec::try_from(vec![Category::Grocery]).unwrap(),
+		cid: H256::from([2; 32]),
+	};
+	let mut storage = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(seller, 2 * SellerStakingAmount::get())],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	GenesisConfig::<Test> {
+		ngos: vec![(ngo, ngo_info.clone())],
+		sellers: vec![(seller, seller_info.clone())],
+	}
+	.assimilate_storage(&mut storage)
+	.unwrap();
+	sp_io::TestExternalities::new(storage).execute_with(|| {
+		assert_eq!(<NgoActiveList<Test>>::get(ngo), ngo_info);
+		assert_eq!(<SellerActiveList<Test>>::get(seller), seller_info);
+		assert!(!<NgoWaitingList<Test>>::contains_key(ngo));
+		assert!(ParticipantHandler::is_active_ngo(&ngo));
+		assert!(ParticipantHandler::is_active_seller(&seller));
+		assert_eq!(ParticipantHandler::get_seller_stake(seller), SellerStakingAmount::get());
+		assert_eq!(Balances::reserved_balance(seller), SellerStakingAmount::get());
+	})
+}
+
+#[test]
+fn test_suspended_seller_can_be_reinstated_by_governance() {
+	new_test_ext().execute_with(|| {
+		let (seller, seller_info) = get_seller_info();
+		ParticipantHandler::add_seller_to_active_list(&seller, seller_info.clone());
+		ParticipantHandler::suspend_seller(&seller);
+		assert!(!ParticipantHandler::is_active_seller(&seller));
+		assert_eq!(ParticipantHandler::get_suspended_seller(seller), Some(seller_info));
+
+		assert_ok!(ParticipantHandler::reinstate_seller(RuntimeOrigin::signed(1), seller));
+		assert!(ParticipantHandler::is_active_seller(&seller));
+		assert_noop!(
+			ParticipantHandler::reinstate_seller(RuntimeOrigin::signed(1), seller),
+			Error::<Test>::SellerNotSuspended
+		);
+	})
+}
+
+#[test]
+fn test_slash_seller_is_capped_at_the_seller_stake() {
+	new_test_ext().execute_with(|| {
+		let (seller, seller_info) = get_seller_info();
+		add_identity(seller);
+		assert_ok!(ParticipantHandler::apply_as_seller(RuntimeOrigin::signed(seller), seller_info));
+		assert_eq!(ParticipantHandler::slash_seller(&seller, 2_000_000_000_000), 1_000_000_000_000);
+		// The identity deposit stays reserved
+		assert_eq!(Balances::reserved_balance(seller), 1);
+	})
+}
+
+#[test]
+fn test_repeated_slashes_only_draw_from_the_remaining_stake() {
+	new_test_ext().execute_with(|| {
+		let (seller, seller_info) = get_seller_info();
+		add_identity(seller);
+		assert_ok!(ParticipantHandler::apply_as_seller(RuntimeOrigin::signed(seller), seller_info));
+		assert_eq!(ParticipantHandler::slash_seller(&seller, 600_000_000_000), 600_000_000_000);
+		assert_eq!(ParticipantHandler::get_seller_stake(seller), 400_000_000_000);
+		assert_eq!(ParticipantHandler::slash_seller(&seller, 600_000_000_000), 400_000_000_000);
+		assert_eq!(ParticipantHandler::get_seller_stake(seller), 0);
+		assert_eq!(ParticipantHandler::slash_seller(&seller, 1), 0);
+		// The identity deposit stays reserved
+		assert_eq!(Balances::reserved_balance(seller), 1);
+	})
+}
+
+#[test]
+fn test_participant_queries_paginate_applications_and_active_lists() {
+	new_test_ext().execute_with(|| {
+		let (_, ngo_info) = get_ngo_info();
+		for ngo in 1..=3u64 {
+			<NgoWaitingList<Test>>::insert(ngo, ngo_info.clone());
+		}
+		let page = ParticipantHandler::ngo_applications(None, 2);
+		assert_eq!(page.len(), 2);
+		let next_page = ParticipantHandler::ngo_applications(page.last().map(|(ngo, _)| *ngo), 2);
+		assert_eq!(next_page.len(), 1);
+		let mut applicants: Vec<u64> =
+			page.into_iter().chain(next_page).map(|(ngo, _)| ngo).collect();
+		applicants.sort();
+		assert_eq!(applicants, vec![1, 2, 3]);
+
+		assert_ok!(ParticipantHandler::approve_ngo(RuntimeOrigin::signed(0), 2));
+		assert_eq!(ParticipantHandler::ngo_applications(None, 10).len(), 2);
+		assert_eq!(ParticipantHandler::active_ngos(None, 10), vec![(2, ngo_info)]);
+
+		let (seller, seller_info) = get_seller_info();
+		<SellerWaitingList<Test>>::insert(seller, seller_info.clone());
+		assert_eq!(ParticipantHandler::seller_applications(None, 10), vec![(seller, seller_info)]);
+		assert!(ParticipantHandler::active_sellers(None, 10).is_empty());
+	})
+}
+
 fn get_ngo_info() -> (u64, NgoInfo<H256>) {
 	let ngo: u64 = 1;
 	let categories_supported = vec![Category::Pharmaceutical, Category::Clothing];
@@ -153,9 +262,41 @@ fn get_ngo_info() -> (u64, NgoInfo<H256>) {
 	(ngo, ngo_info)
 }
 
+#[test]
+fn test_migrate_to_v1_lists_the_single_seller_category() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<ParticipantHandler>();
+		let cid = H256::from([1; 32]);
+		unhashed::put_raw(
+			&<SellerWaitingList<Test>>::hashed_key_for(1),
+			&(Category::Grocery, cid).encode(),
+		);
+		unhashed::put_raw(
+			&<SellerActiveList<Test>>::hashed_key_for(2),
+			&(Category::Clothing, cid).encode(),
+		);
+
+		migrations::v1::MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(ParticipantHandler::on_chain_storage_version(), 1);
+		assert_eq!(
+			<SellerWaitingList<Test>>::get(1),
+			SellerInfo { categories: BoundedVec::try_from(vec![Category::Grocery]).unwrap(), cid }
+		);
+		assert_eq!(
+			<SellerActiveList<Test>>::get(2),
+			SellerInfo { categories: BoundedVec::try_from(vec![Category::Clothing]).unwrap(), cid }
+		);
+	})
+}
+
 fn get_seller_info() -> (u64, SellerInfo<H256>) {
 	let seller: u64 = 1;
-	let seller_info = SellerInfo { category: Category::Pharmaceutical, cid: H256::from([1; 32]) };
+	let seller_info = SellerInfo {
+		categories: BoundedVec::try_from(vec![Category::Pharmaceutical, Category::Grocery])
+			.unwrap(),
+		cid: H256::from([1; 32]),
+	};
 	(seller, seller_info)
 }
 
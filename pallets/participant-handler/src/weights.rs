@@ -0,0 +1,109 @@
+//! PLACEHOLDER weights for participant_handler, hand-written and not produced by the benchmark CLI.
+//!
+//! Do not ship these. Replace this file with the output of `.maintain/benchmark.sh` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for participant_handler.
+pub trait WeightInfo {
+	fn apply_as_ngo() -> Weight;
+	fn approve_ngo() -> Weight;
+	fn apply_as_seller() -> Weight;
+	fn approve_seller() -> Weight;
+	fn reinstate_seller() -> Weight;
+}
+
+/// Weights for participant_handler using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: ParticipantHandler NgoWaitingList (r:1 w:1)
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn apply_as_ngo() -> Weight {
+		Weight::from_ref_time(38_000_000)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: ParticipantHandler NgoWaitingList (r:1 w:1)
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:1)
+	fn approve_ngo() -> Weight {
+		Weight::from_ref_time(24_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: ParticipantHandler SellerWaitingList (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ParticipantHandler SellerStake (r:1 w:1)
+	fn apply_as_seller() -> Weight {
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	// Storage: ParticipantHandler SellerWaitingList (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:1)
+	fn approve_seller() -> Weight {
+		Weight::from_ref_time(24_000_000)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: ParticipantHandler SuspendedSellers (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:0 w:1)
+	fn reinstate_seller() -> Weight {
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: ParticipantHandler NgoWaitingList (r:1 w:1)
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn apply_as_ngo() -> Weight {
+		Weight::from_ref_time(38_000_000)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: ParticipantHandler NgoWaitingList (r:1 w:1)
+	// Storage: ParticipantHandler NgoActiveList (r:1 w:1)
+	fn approve_ngo() -> Weight {
+		Weight::from_ref_time(24_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: Identity IdentityOf (r:1 w:0)
+	// Storage: ParticipantHandler SellerWaitingList (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ParticipantHandler SellerStake (r:1 w:1)
+	fn apply_as_seller() -> Weight {
+		Weight::from_ref_time(41_000_000)
+			.saturating_add(RocksDbWeight::get().reads(5))
+			.saturating_add(RocksDbWeight::get().writes(3))
+	}
+	// Storage: ParticipantHandler SellerWaitingList (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:1 w:1)
+	fn approve_seller() -> Weight {
+		Weight::from_ref_time(24_000_000)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+	// Storage: ParticipantHandler SuspendedSellers (r:1 w:1)
+	// Storage: ParticipantHandler SellerActiveList (r:0 w:1)
+	fn reinstate_seller() -> Weight {
+		Weight::from_ref_time(21_000_000)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+}
@@ -0,0 +1,97 @@
+//! Benchmarking setup for router
+//!
+//! The pools are opened on `pallet_amm`, so these benchmarks assume it is the `AMM` being
+//! routed through.
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as Router;
+use frame_benchmarking::{account, benchmarks_instance_pallet, whitelisted_caller, BenchmarkError};
+use frame_support::traits::{
+	fungibles::{Create, Inspect, Mutate},
+	EnsureOrigin, Get,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Zero;
+use sp_std::vec::Vec;
+
+const SEED: u32 = 0;
+const FIRST_ASSET: u128 = 200;
+const FIRST_LP_TOKEN: u128 = 2_000;
+const BALANCE: u128 = 1_000_000_000_000_000_000;
+const LIQUIDITY: u128 = 1_000_000_000_000_000;
+const AMOUNT: u128 = 1_000_000_000;
+
+fn ensure_asset<T>(id: u128)
+where
+	T: pallet_amm::Config,
+	<T as pallet_amm::Config>::Assets: Create<T::AccountId>,
+{
+	let admin = pallet_amm::Pallet::<T>::account_id();
+	if <T as pallet_amm::Config>::Assets::minimum_balance(id).is_zero() {
+		<T as pallet_amm::Config>::Assets::create(id, admin, true, 1).unwrap();
+	}
+}
+
+/// Route through `n` assets, with a pool opened between each consecutive pair.
+fn route<T, I: 'static>(n: u32) -> Result<Vec<u128>, BenchmarkError>
+where
+	T: Config<I> + pallet_amm::Config,
+	<T as pallet_amm::Config>::Assets: Create<T::AccountId>,
+{
+	let provider: T::AccountId = account("provider", 0, SEED);
+	let route: Vec<u128> = (0..n as u128).map(|i| FIRST_ASSET + i).collect();
+	for &id in &route {
+		ensure_asset::<T>(id);
+		<T as Config<I>>::Assets::mint_into(id, &provider, BALANCE)?;
+	}
+	for (i, pair) in route.windows(2).enumerate() {
+		let lp_token = FIRST_LP_TOKEN + i as u128;
+		ensure_asset::<T>(lp_token);
+		let origin = <T as pallet_amm::Config>::CreatePoolOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+		pallet_amm::Pallet::<T>::create_pool(
+			origin,
+			(pair[0], pair[1]),
+			(LIQUIDITY, LIQUIDITY),
+			provider.clone(),
+			lp_token,
+		)?;
+	}
+	Ok(route)
+}
+
+fn trader<T: Config<I>, I: 'static>(route: &[u128]) -> Result<T::AccountId, BenchmarkError> {
+	let trader: T::AccountId = whitelisted_caller();
+	<T as Config<I>>::Assets::mint_into(route[0], &trader, BALANCE)?;
+	Ok(trader)
+}
+
+benchmarks_instance_pallet! {
+	where_clause {
+		where
+			T: pallet_amm::Config,
+			<T as pallet_amm::Config>::Assets: Create<T::AccountId>,
+	}
+
+	swap_exact_tokens_for_tokens {
+		let n in 2 .. <T as Config<I>>::MaxLengthRoute::get();
+		let route = route::<T, I>(n)?;
+		let trader = trader::<T, I>(&route)?;
+		let token_out = route[route.len() - 1];
+	}: _(RawOrigin::Signed(trader.clone()), route, AMOUNT, 0)
+	verify {
+		assert!(!<T as Config<I>>::Assets::balance(token_out, &trader).is_zero());
+	}
+
+	swap_tokens_for_exact_tokens {
+		let n in 2 .. <T as Config<I>>::MaxLengthRoute::get();
+		let route = route::<T, I>(n)?;
+		let trader = trader::<T, I>(&route)?;
+		let token_out = route[route.len() - 1];
+	}: _(RawOrigin::Signed(trader.clone()), route, AMOUNT, BALANCE)
+	verify {
+		assert!(<T as Config<I>>::Assets::balance(token_out, &trader) >= AMOUNT);
+	}
+}
@@ -20,15 +20,16 @@
 
 pub use pallet::*;
 
-// mod benchmarking;
-//
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 // #[cfg(test)]
 // mod mock;
 // #[cfg(test)]
 // mod tests;
-//
-// pub mod weights;
-// pub use weights::WeightInfo;
+
+pub mod weights;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -77,8 +78,8 @@ pub mod pallet {
 		/// Specify all the AMMs we are routing between
 		type AMM: AMM<AccountIdOf<Self>, AssetIdOf<Self, I>, BalanceOf<Self, I>, Self::BlockNumber>;
 
-		// /// Weight information for extrinsics in this pallet.
-		// type AMMRouterWeightInfo: WeightInfo;
+		/// Weight information for extrinsics in this pallet.
+		type AMMRouterWeightInfo: WeightInfo;
 
 		/// How many routes we support at most
 		#[pallet::constant]
@@ -283,7 +284,7 @@ pub mod pallet {
 		/// - `route`: the route user inputs
 		/// - `amount_in`: the amount of trading assets
 		/// - `min_amount_out`: the minimum a trader is willing to receive
-		#[pallet::weight(0)]
+		#[pallet::weight(T::AMMRouterWeightInfo::swap_exact_tokens_for_tokens(route.len() as u32))]
 		#[transactional]
 		pub fn swap_exact_tokens_for_tokens(
 			origin: OriginFor<T>,
@@ -342,7 +343,7 @@ pub mod pallet {
 		/// - `route`: the route user inputs
 		/// - `amount_out`: the amount of trading assets
 		/// - `max_amount_in`: the maximum a trader is willing to input
-		#[pallet::weight(0)]
+		#[pallet::weight(T::AMMRouterWeightInfo::swap_tokens_for_exact_tokens(route.len() as u32))]
 		#[transactional]
 		pub fn swap_tokens_for_exact_tokens(
 			origin: OriginFor<T>,
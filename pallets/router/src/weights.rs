@@ -0,0 +1,74 @@
+//! PLACEHOLDER weights for router, hand-written and not produced by the benchmark CLI.
+//!
+//! Do not ship these. Replace this file with the output of `.maintain/benchmark.sh` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for router.
+pub trait WeightInfo {
+	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight;
+	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight;
+}
+
+/// Weights for router using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Assets Asset (r:3 w:2)
+	// Storage: Assets Account (r:5 w:5)
+	// Storage: Swap Pools (r:2 w:1)
+	/// The range of component `n` is `[2, 10]`.
+	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
+		Weight::from_ref_time(24_000_000)
+			.saturating_add(Weight::from_ref_time(58_000_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	// Storage: Assets Asset (r:3 w:2)
+	// Storage: Assets Account (r:5 w:5)
+	// Storage: Swap Pools (r:2 w:1)
+	/// The range of component `n` is `[2, 10]`.
+	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
+		Weight::from_ref_time(25_000_000)
+			.saturating_add(Weight::from_ref_time(59_000_000).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Assets Asset (r:3 w:2)
+	// Storage: Assets Account (r:5 w:5)
+	// Storage: Swap Pools (r:2 w:1)
+	/// The range of component `n` is `[2, 10]`.
+	fn swap_exact_tokens_for_tokens(n: u32, ) -> Weight {
+		Weight::from_ref_time(24_000_000)
+			.saturating_add(Weight::from_ref_time(58_000_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+	// Storage: Assets Asset (r:3 w:2)
+	// Storage: Assets Account (r:5 w:5)
+	// Storage: Swap Pools (r:2 w:1)
+	/// The range of component `n` is `[2, 10]`.
+	fn swap_tokens_for_exact_tokens(n: u32, ) -> Weight {
+		Weight::from_ref_time(25_000_000)
+			.saturating_add(Weight::from_ref_time(59_000_000).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().reads((7_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(RocksDbWeight::get().writes((6_u64).saturating_mul(n.into())))
+	}
+}
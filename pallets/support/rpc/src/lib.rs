@@ -0,0 +1,61 @@
+//! Helpers shared by the pallet RPC interfaces.
+
+use jsonrpsee::{
+	core::Error as JsonRpseeError,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT};
+
+/// Number of items returned when a request does not set a limit.
+pub const DEFAULT_PAGE_SIZE: u32 = 50;
+/// Most items a single request can return.
+pub const MAX_PAGE_SIZE: u32 = 500;
+
+/// Error code of a failed runtime API call.
+const RUNTIME_ERROR: i32 = 1;
+
+/// Page of results. Pass `next` as the cursor of the request to fetch the following page, it is
+/// empty on the last page.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Page<Item, Cursor> {
+	pub items: Vec<Item>,
+	pub next: Option<Cursor>,
+}
+
+/// Number of items to return for the requested `limit`.
+pub fn page_size(limit: Option<u32>) -> u32 {
+	limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE)
+}
+
+/// Build a page of `limit` items at most, the last key is the cursor of a full page.
+pub fn into_page<Key: Clone, Value, Item: From<(Key, Value)>>(
+	entries: Vec<(Key, Value)>,
+	limit: u32,
+) -> Page<Item, Key> {
+	let next = match entries.last() {
+		Some((key, _)) if entries.len() as u32 == limit => Some(key.clone()),
+		_ => None,
+	};
+	Page { items: entries.into_iter().map(Into::into).collect(), next }
+}
+
+/// Block to query, the best block unless `at` is set.
+pub fn block_at<Block: BlockT, C: HeaderBackend<Block>>(
+	client: &C,
+	at: Option<Block::Hash>,
+) -> BlockId<Block> {
+	BlockId::hash(at.unwrap_or_else(|| client.info().best_hash))
+}
+
+/// Error returned when the runtime API call behind a request fails.
+pub fn runtime_error(error: impl ToString) -> JsonRpseeError {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query the runtime.",
+		Some(error.to_string()),
+	))
+	.into()
+}
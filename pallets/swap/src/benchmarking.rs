@@ -0,0 +1,116 @@
+//! Benchmarking setup for pallet-amm
+
+use super::*;
+
+#[allow(unused)]
+use crate::Pallet as AMM;
+use frame_benchmarking::{account, benchmarks_instance_pallet, whitelisted_caller, BenchmarkError};
+use frame_support::traits::{fungibles::Create, EnsureOrigin};
+use frame_system::RawOrigin;
+use support::AMM as _;
+
+const SEED: u32 = 0;
+const BASE: CurrencyId = 101;
+const QUOTE: CurrencyId = 100;
+const LP_TOKEN: CurrencyId = 1_000;
+const BALANCE: u128 = 1_000_000_000_000_000_000;
+const LIQUIDITY: u128 = 1_000_000_000_000_000;
+
+fn ensure_asset<T: Config<I>, I: 'static>(id: CurrencyId)
+where
+	T::Assets: Create<T::AccountId>,
+{
+	if T::Assets::minimum_balance(id).is_zero() {
+		T::Assets::create(id, AMM::<T, I>::account_id(), true, 1).unwrap();
+	}
+}
+
+/// Account holding `BALANCE` of both pool assets, with the LP token created.
+fn funded<T: Config<I>, I: 'static>() -> Result<T::AccountId, BenchmarkError>
+where
+	T::Assets: Create<T::AccountId>,
+{
+	let who: T::AccountId = whitelisted_caller();
+	for id in [BASE, QUOTE] {
+		ensure_asset::<T, I>(id);
+		T::Assets::mint_into(id, &who, BALANCE)?;
+	}
+	ensure_asset::<T, I>(LP_TOKEN);
+	Ok(who)
+}
+
+fn open_pool<T: Config<I>, I: 'static>(provider: &T::AccountId) -> Result<(), BenchmarkError> {
+	let origin =
+		T::CreatePoolOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	AMM::<T, I>::create_pool(
+		origin,
+		(BASE, QUOTE),
+		(LIQUIDITY, LIQUIDITY),
+		provider.clone(),
+		LP_TOKEN,
+	)?;
+	Ok(())
+}
+
+/// Turns the protocol fee on and trades against the pool, so the next liquidity change has a
+/// fee to mint.
+fn accrue_protocol_fee<T: Config<I>, I: 'static>(
+	provider: &T::AccountId,
+) -> Result<(), BenchmarkError> {
+	ProtocolFee::<T, I>::put(Ratio::from_percent(50));
+	ProtocolFeeReceiver::<T, I>::put(account::<T::AccountId>("receiver", 0, SEED));
+	open_pool::<T, I>(provider)?;
+	AMM::<T, I>::swap(provider, (BASE, QUOTE), LIQUIDITY / 10)?;
+	Ok(())
+}
+
+benchmarks_instance_pallet! {
+	where_clause { where T::Assets: Create<T::AccountId> }
+
+	// Worst case: a protocol fee has accrued since the last liquidity change and is minted.
+	add_liquidity {
+		let caller = funded::<T, I>()?;
+		accrue_protocol_fee::<T, I>(&caller)?;
+		let before = T::Assets::balance(LP_TOKEN, &caller);
+	}: _(RawOrigin::Signed(caller.clone()), (BASE, QUOTE), (LIQUIDITY, LIQUIDITY), (0, 0))
+	verify {
+		assert!(T::Assets::balance(LP_TOKEN, &caller) > before);
+	}
+
+	// Worst case: a protocol fee has accrued since the last liquidity change and is minted.
+	remove_liquidity {
+		let caller = funded::<T, I>()?;
+		accrue_protocol_fee::<T, I>(&caller)?;
+		let before = T::Assets::balance(LP_TOKEN, &caller);
+		let liquidity = before / 2;
+	}: _(RawOrigin::Signed(caller.clone()), (BASE, QUOTE), liquidity)
+	verify {
+		assert_eq!(T::Assets::balance(LP_TOKEN, &caller), before - liquidity);
+	}
+
+	create_pool {
+		let provider = funded::<T, I>()?;
+		let origin = T::CreatePoolOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, (BASE, QUOTE), (LIQUIDITY, LIQUIDITY), provider, LP_TOKEN)
+	verify {
+		assert!(Pools::<T, I>::contains_key(BASE, QUOTE));
+	}
+
+	update_protocol_fee {
+		let origin = T::ProtocolFeeUpdateOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, Ratio::from_percent(50))
+	verify {
+		assert_eq!(ProtocolFee::<T, I>::get(), Ratio::from_percent(50));
+	}
+
+	update_protocol_fee_receiver {
+		let receiver: T::AccountId = account("receiver", 0, SEED);
+		let origin = T::ProtocolFeeUpdateOrigin::try_successful_origin()
+			.map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, receiver.clone())
+	verify {
+		assert_eq!(ProtocolFeeReceiver::<T, I>::get(), Some(receiver));
+	}
+}
@@ -24,9 +24,10 @@ extern crate alloc;
 // mod mock;
 // #[cfg(test)]
 // mod tests;
-//
-// mod benchmarking;
-//pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
 
 use frame_support::{
 	dispatch::DispatchResult,
@@ -48,7 +49,7 @@ use sp_std::{cmp::min, result::Result, vec::Vec};
 use support::{ConvertToBigUint, Pool};
 
 pub use pallet::*;
-//pub use weights::WeightInfo;
+pub use weights::WeightInfo;
 
 use num_traits::{cast::ToPrimitive, CheckedDiv, CheckedMul};
 
@@ -84,7 +85,7 @@ pub mod pallet {
 		type LockAccountId: Get<Self::AccountId>;
 
 		/// Weight information for extrinsics in this pallet.
-		//type AMMWeightInfo: WeightInfo;
+		type AMMWeightInfo: WeightInfo;
 
 		/// Specify which origin is allowed to create new pools.
 		type CreatePoolOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -226,7 +227,7 @@ pub mod pallet {
 		/// - `pool`: Currency pool, in which liquidity will be added
 		/// - `liquidity_amounts`: Liquidity amounts to be added in pool
 		/// - `minimum_amounts`: specifying its "worst case" ratio when pool already exists
-		#[pallet::weight(0)]
+		#[pallet::weight(T::AMMWeightInfo::add_liquidity())]
 		#[transactional]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
@@ -310,7 +311,7 @@ pub mod pallet {
 		///
 		/// - `pair`: Currency pool, in which liquidity will be removed
 		/// - `liquidity`: liquidity to be removed from user's liquidity
-		#[pallet::weight(0)]
+		#[pallet::weight(T::AMMWeightInfo::remove_liquidity())]
 		#[transactional]
 		pub fn remove_liquidity(
 			origin: OriginFor<T>,
@@ -360,7 +361,7 @@ pub mod pallet {
 		/// - `liquidity_amounts`: Liquidity amounts to be added in pool
 		/// - `lptoken_receiver`: Allocate any liquidity tokens to lptoken_receiver
 		/// - `lp_token_id`: Liquidity pool share representative token
-		#[pallet::weight(0)]
+		#[pallet::weight(T::AMMWeightInfo::create_pool())]
 		#[transactional]
 		pub fn create_pool(
 			origin: OriginFor<T>,
@@ -434,7 +435,7 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::AMMWeightInfo::update_protocol_fee())]
 		#[transactional]
 		pub fn update_protocol_fee(
 			origin: OriginFor<T>,
@@ -446,7 +447,7 @@ pub mod pallet {
 			Ok(().into())
 		}
 
-		#[pallet::weight(0)]
+		#[pallet::weight(T::AMMWeightInfo::update_protocol_fee_receiver())]
 		#[transactional]
 		pub fn update_protocol_fee_receiver(
 			origin: OriginFor<T>,
@@ -0,0 +1,107 @@
+//! PLACEHOLDER weights for pallet_amm, hand-written and not produced by the benchmark CLI.
+//!
+//! Do not ship these. Replace this file with the output of `.maintain/benchmark.sh` run on
+//! reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for pallet_amm.
+pub trait WeightInfo {
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn create_pool() -> Weight;
+	fn update_protocol_fee() -> Weight;
+	fn update_protocol_fee_receiver() -> Weight;
+}
+
+/// Weights for pallet_amm using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Swap Pools (r:1 w:1)
+	// Storage: Swap ProtocolFee (r:1 w:0)
+	// Storage: Swap ProtocolFeeReceiver (r:1 w:0)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn add_liquidity() -> Weight {
+		Weight::from_ref_time(96_000_000)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: Swap Pools (r:1 w:1)
+	// Storage: Swap ProtocolFee (r:1 w:0)
+	// Storage: Swap ProtocolFeeReceiver (r:1 w:0)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn remove_liquidity() -> Weight {
+		Weight::from_ref_time(92_000_000)
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: Swap Pools (r:1 w:1)
+	// Storage: Swap ProtocolFee (r:1 w:0)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn create_pool() -> Weight {
+		Weight::from_ref_time(101_000_000)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: Swap ProtocolFee (r:0 w:1)
+	fn update_protocol_fee() -> Weight {
+		Weight::from_ref_time(15_000_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: Swap ProtocolFeeReceiver (r:0 w:1)
+	fn update_protocol_fee_receiver() -> Weight {
+		Weight::from_ref_time(15_000_000)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Swap Pools (r:1 w:1)
+	// Storage: Swap ProtocolFee (r:1 w:0)
+	// Storage: Swap ProtocolFeeReceiver (r:1 w:0)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn add_liquidity() -> Weight {
+		Weight::from_ref_time(96_000_000)
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	// Storage: Swap Pools (r:1 w:1)
+	// Storage: Swap ProtocolFee (r:1 w:0)
+	// Storage: Swap ProtocolFeeReceiver (r:1 w:0)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn remove_liquidity() -> Weight {
+		Weight::from_ref_time(92_000_000)
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	// Storage: Swap Pools (r:1 w:1)
+	// Storage: Swap ProtocolFee (r:1 w:0)
+	// Storage: Assets Asset (r:3 w:3)
+	// Storage: Assets Account (r:6 w:6)
+	fn create_pool() -> Weight {
+		Weight::from_ref_time(101_000_000)
+			.saturating_add(RocksDbWeight::get().reads(11))
+			.saturating_add(RocksDbWeight::get().writes(10))
+	}
+	// Storage: Swap ProtocolFee (r:0 w:1)
+	fn update_protocol_fee() -> Weight {
+		Weight::from_ref_time(15_000_000)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+	// Storage: Swap ProtocolFeeReceiver (r:0 w:1)
+	fn update_protocol_fee_receiver() -> Weight {
+		Weight::from_ref_time(15_000_000)
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+}
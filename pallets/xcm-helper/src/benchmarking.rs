@@ -3,18 +3,22 @@
 use super::*;
 
 #[allow(unused)]
-use crate::Pallet as Template;
+use crate::Pallet as XcmHelper;
 use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
 use frame_system::RawOrigin;
+use xcm::latest::{AssetId, Junction, Junctions, MultiLocation};
 
 benchmarks! {
-	do_something {
-		let s in 0 .. 100;
+	create_asset {
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+		let location = MultiLocation {
+			parents: 1,
+			interior: Junctions::X1(Junction::Parachain(2_000)),
+		};
+	}: _(RawOrigin::Signed(caller), AssetId::Concrete(location.clone()))
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert!(AssetMapping::<T>::iter_values().any(|asset| asset.location == location));
 	}
 }
 
-impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test,);
+impl_benchmark_test_suite!(XcmHelper, crate::mock::new_test_ext(), crate::mock::Test);
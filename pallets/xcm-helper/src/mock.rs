@@ -1,11 +1,16 @@
-use crate as pallet_template;
-use frame_support::{parameter_types, traits::Everything};
+use crate as xcm_helper;
+use frame_support::{
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU32, Everything},
+};
 use frame_system as system;
+use frame_system::EnsureSigned;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
 };
+use xcm::latest::{Junction, Junctions, MultiLocation, NetworkId};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -18,7 +23,9 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		TemplateModule: pallet_template::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		XcmHelper: xcm_helper::{Pallet, Call, Storage, Event<T>},
 	}
 );
 
@@ -45,7 +52,7 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u128>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -54,8 +61,89 @@ impl system::Config for Test {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
-impl pallet_template::Config for Test {
+pub const TOKEN: u128 = 1_000_000_000_000;
+
+parameter_types! {
+	pub const ExistentialDeposit: u128 = 1 * TOKEN;
+	pub const MaxLocks: u32 = 50;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl pallet_balances::Config for Test {
+	type Balance = u128;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = frame_system::Pallet<Test>;
+	type MaxLocks = MaxLocks;
+	type MaxReserves = MaxReserves;
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+	type RuntimeEvent = RuntimeEvent;
+}
+
+parameter_types! {
+	pub const AssetDeposit: u128 = 100;
+	pub const ApprovalDeposit: u128 = 1;
+	pub const StringLimit: u32 = 50;
+	pub const MetadataDepositBase: u128 = 10;
+	pub const MetadataDepositPerByte: u128 = 1;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u128;
+	type RemoveItemsLimit = ConstU32<1000>;
+	type AssetId = u128;
+	type AssetIdParameter = codec::Compact<u128>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<Self::AccountId>>;
+	type ForceOrigin = EnsureSigned<Self::AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = StringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+}
+
+/// Location of the local account `index`.
+pub fn account_location(index: u64) -> MultiLocation {
+	MultiLocation {
+		parents: 0,
+		interior: Junctions::X1(Junction::AccountIndex64 { network: NetworkId::Any, index }),
+	}
+}
+
+/// Converts the location of a local account index back into the account.
+pub struct AccountIndexConvert;
+
+impl xcm_executor::traits::Convert<MultiLocation, u64> for AccountIndexConvert {
+	fn convert(location: MultiLocation) -> Result<u64, MultiLocation> {
+		match location {
+			MultiLocation {
+				parents: 0,
+				interior: Junctions::X1(Junction::AccountIndex64 { index, .. }),
+			} => Ok(index),
+			location => Err(location),
+		}
+	}
+}
+
+parameter_types! {
+	pub const ParachainId: u32 = 1_000;
+}
+
+impl xcm_helper::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
+	type AccountIdConvert = AccountIndexConvert;
+	type Currency = Balances;
+	type AssetManager = Assets;
+	type ParachainId = ParachainId;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
@@ -1,23 +1,46 @@
-use crate::{mock::*, Error};
+use crate::{mock::*, AssetIdConverter, Error};
 use frame_support::{assert_noop, assert_ok};
+use xcm::latest::{AssetId, Fungibility, Junction, Junctions, MultiAsset, MultiLocation};
+use xcm_executor::traits::TransactAsset;
+
+fn sibling(para_id: u32) -> MultiLocation {
+	MultiLocation { parents: 1, interior: Junctions::X1(Junction::Parachain(para_id)) }
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn create_asset_maps_the_local_id_to_its_location() {
 	new_test_ext().execute_with(|| {
-		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(TemplateModule::something(), Some(42));
+		assert_ok!(XcmHelper::create_asset(
+			RuntimeOrigin::signed(1),
+			AssetId::Concrete(sibling(2_000))
+		));
+		let asset_id = XcmHelper::convert_location_to_asset_id(sibling(2_000)).unwrap();
+		assert_eq!(XcmHelper::convert_asset_id_to_location(asset_id), Some(sibling(2_000)));
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn create_asset_rejects_abstract_ids() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			XcmHelper::create_asset(RuntimeOrigin::signed(1), AssetId::Abstract(b"PBT".to_vec())),
+			Error::<Test>::StorageOverflow
 		);
 	});
 }
+
+#[test]
+fn native_asset_is_deposited_to_and_withdrawn_from_balances() {
+	new_test_ext().execute_with(|| {
+		let native = MultiAsset {
+			id: AssetId::Concrete(sibling(ParachainId::get())),
+			fun: Fungibility::Fungible(10 * TOKEN),
+		};
+		assert_ok!(XcmHelper::deposit_asset(&native, &account_location(1)));
+		assert_eq!(Balances::free_balance(1), 10 * TOKEN);
+
+		let withdrawn = MultiAsset { fun: Fungibility::Fungible(4 * TOKEN), ..native };
+		assert_ok!(XcmHelper::withdraw_asset(&withdrawn, &account_location(1)));
+		assert_eq!(Balances::free_balance(1), 6 * TOKEN);
+	});
+}
@@ -15,7 +15,9 @@ use sp_api::impl_runtime_apis;
 use sp_core::{crypto::KeyTypeId, Get, OpaqueMetadata};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
-	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, Verify},
+	traits::{
+		AccountIdConversion, AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, Verify,
+	},
 	transaction_validity::{TransactionSource, TransactionValidity},
 	ApplyExtrinsicResult, MultiSignature,
 };
@@ -28,7 +30,10 @@ use frame_support::{
 	construct_runtime,
 	dispatch::DispatchClass,
 	parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, Everything},
+	traits::{
+		AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, EitherOfDiverse, Everything,
+		NeverEnsureOrigin,
+	},
 	weights::{
 		constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight, WeightToFeeCoefficient,
 		WeightToFeeCoefficients, WeightToFeePolynomial,
@@ -109,6 +114,13 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Storage migrations run on the next runtime upgrade.
+pub type Migrations = (
+	participant_handler::migrations::v1::MigrateToV1<Runtime>,
+	donation_handler::migrations::v1::MigrateToV1<Runtime>,
+	marketplace::migrations::v1::MigrateToV1<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -116,6 +128,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
@@ -519,6 +532,58 @@ impl pallet_identity::Config for Runtime {
 	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const ArbitrationMotionDuration: BlockNumber = 3 * DAYS;
+	pub const ArbitrationMaxProposals: u32 = 100;
+	pub const ArbitrationMaxMembers: u32 = 20;
+}
+
+pub type ArbitrationCollective = pallet_collective::Instance1;
+impl pallet_collective::Config<ArbitrationCollective> for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Proposal = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type MotionDuration = ArbitrationMotionDuration;
+	type MaxProposals = ArbitrationMaxProposals;
+	type MaxMembers = ArbitrationMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = 100 * UNIT;
+	pub const SpendPeriod: BlockNumber = 6 * DAYS;
+	pub const MaxApprovals: u32 = 100;
+	pub TreasuryAccount: AccountId = TreasuryPalletId::get().into_account_truncating();
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = EnsureRoot<AccountId>;
+	type RejectOrigin = EnsureRoot<AccountId>;
+	type RuntimeEvent = RuntimeEvent;
+	type OnSlash = Treasury;
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type ProposalBondMaximum = ();
+	type SpendPeriod = SpendPeriod;
+	type Burn = ();
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = MaxApprovals;
+	type SpendOrigin = NeverEnsureOrigin<Balance>;
+}
+
+/// Root, or a majority of the arbitration council.
+pub type ArbitratorOrigin = EitherOfDiverse<
+	EnsureRoot<AccountId>,
+	pallet_collective::EnsureProportionMoreThan<AccountId, ArbitrationCollective, 1, 2>,
+>;
+
 // Our Pallets
 
 parameter_types! {
@@ -533,10 +598,53 @@ impl participant_handler::Config for Runtime {
 	type SellerStakingAmount = SellerStakingAmount;
 	type Currency = Balances;
 	type GovernanceOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = participant_handler::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
 	pub const DonationPalletId: PalletId = PalletId(*b"py/nopls");
+	pub const DonationCapPeriod: BlockNumber = 30 * DAYS;
+	pub const ClaimHoldBackPeriod: BlockNumber = 7 * DAYS;
+	pub const MarketplacePalletId: PalletId = PalletId(*b"mp/escrw");
+	pub const DeliveryTimeout: BlockNumber = 14 * DAYS;
+	pub const DisputeTimeout: BlockNumber = 14 * DAYS;
+	pub const MinRatingsForSuspension: u32 = 10;
+	pub const SuspensionScoreThreshold: u32 = 200;
+	pub const ReferencePriceTwapPeriod: BlockNumber = HOURS;
+	pub const MaxAuctionsPerBlock: u32 = 50;
+	pub const MaxGroupBuyParticipants: u32 = 100;
+	pub const MaxContractsPerBlock: u32 = 50;
+	pub const SupplyContractNoticePeriod: BlockNumber = 7 * DAYS;
+}
+
+/// Opens the Swap pools the marketplace benchmarks price reference assets with.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct MarketplaceBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl marketplace::BenchmarkHelper for MarketplaceBenchmarkHelper {
+	fn create_price_source(asset: u128) {
+		use frame_support::traits::{
+			fungibles::{Create, Mutate},
+			Currency,
+		};
+
+		let provider = AccountId::from([2u8; 32]);
+		let amount = 1_000_000 * UNIT;
+		let lp_token = asset + 1;
+		Balances::make_free_balance_be(&provider, 2 * amount);
+		AssetHandler::create(asset, provider.clone(), true, 1).unwrap();
+		AssetHandler::mint_into(asset, &provider, amount).unwrap();
+		AssetHandler::create(lp_token, provider.clone(), true, 1).unwrap();
+		Swap::create_pool(
+			RuntimeOrigin::root(),
+			(asset, NativeCurrencyId::get()),
+			(amount, amount),
+			provider,
+			lp_token,
+		)
+		.unwrap();
+	}
 }
 
 impl marketplace::Config for Runtime {
@@ -544,6 +652,25 @@ impl marketplace::Config for Runtime {
 	type TokenHandler = PurposeBasedTokens;
 	type Currency = Balances;
 	type DonationPalletId = DonationPalletId;
+	type MarketplacePalletId = MarketplacePalletId;
+	type DeliveryTimeout = DeliveryTimeout;
+	type ArbitratorOrigin = ArbitratorOrigin;
+	type RecallOrigin = EnsureRoot<AccountId>;
+	type CommissionOrigin = EnsureRoot<AccountId>;
+	type DisputeTimeout = DisputeTimeout;
+	type MinRatingsForSuspension = MinRatingsForSuspension;
+	type SuspensionScoreThreshold = SuspensionScoreThreshold;
+	type AMM = Swap;
+	type NativeCurrencyId = NativeCurrencyId;
+	type TwapPeriod = ReferencePriceTwapPeriod;
+	type MaxAuctionsPerBlock = MaxAuctionsPerBlock;
+	type FeeReceiver = TreasuryAccount;
+	type MaxGroupBuyParticipants = MaxGroupBuyParticipants;
+	type MaxContractsPerBlock = MaxContractsPerBlock;
+	type ContractNoticePeriod = SupplyContractNoticePeriod;
+	type WeightInfo = marketplace::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = MarketplaceBenchmarkHelper;
 }
 
 impl donation_handler::Config for Runtime {
@@ -551,6 +678,9 @@ impl donation_handler::Config for Runtime {
 	type TokenHandler = PurposeBasedTokens;
 	type Currency = Balances;
 	type DonationPalletId = DonationPalletId;
+	type CapPeriod = DonationCapPeriod;
+	type ClaimHoldBackPeriod = ClaimHoldBackPeriod;
+	type WeightInfo = donation_handler::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -563,6 +693,7 @@ impl xcm_helper::Config for Runtime {
 	type Currency = Balances;
 	type AssetManager = Assets;
 	type ParachainId = ParachainId;
+	type WeightInfo = xcm_helper::weights::SubstrateWeight<Runtime>;
 }
 
 // Impl parallel Finance Pallet
@@ -580,6 +711,7 @@ impl pallet_amm::Config for Runtime {
 	type Assets = AssetHandler;
 	type PalletId = SwapPalletId;
 	type LockAccountId = OneAccount;
+	type AMMWeightInfo = pallet_amm::weights::SubstrateWeight<Runtime>;
 	type CreatePoolOrigin = EnsureRoot<Self::AccountId>;
 	type ProtocolFeeUpdateOrigin = EnsureRoot<Self::AccountId>;
 	type LpFee = DefaultLpFee;
@@ -596,6 +728,7 @@ impl router::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletId = RouterPalletId;
 	type AMM = Swap;
+	type AMMRouterWeightInfo = router::weights::SubstrateWeight<Runtime>;
 	type MaxLengthRoute = MaxLengthRoute;
 	type GetNativeCurrencyId = NativeCurrencyId;
 	type Assets = AssetHandler;
@@ -648,11 +781,13 @@ construct_runtime!(
 		Assets: pallet_assets::<Instance1>::{Pallet, Call, Event<T>, Config<T>} = 34,
 		PurposeBasedTokens: pallet_assets::<Instance2>::{Pallet, Call, Event<T>, Config<T>} = 35,
 		Identity: pallet_identity::{Pallet, Call, Event<T>} = 36,
+		ArbitrationCouncil: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>} = 44,
+		Treasury: pallet_treasury::{Pallet, Call, Storage, Config, Event<T>} = 45,
 
 		// Custom Pallets
-		ParticipantHandler: participant_handler::{Pallet, Call, Event<T>} = 37,
-		DonationHandler: donation_handler::{Pallet, Call, Event<T>} = 38,
-		Marketplace: marketplace::{Pallet, Call, Event<T>} = 39,
+		ParticipantHandler: participant_handler::{Pallet, Call, Storage, Event<T>, Config<T>} = 37,
+		DonationHandler: donation_handler::{Pallet, Call, Storage, Event<T>} = 38,
+		Marketplace: marketplace::{Pallet, Call, Storage, Event<T>, Config<T>} = 39,
 		XcmHelper: xcm_helper::{Pallet, Call, Event<T>} = 40,
 		AssetHandler: asset_manager::pallet::{Pallet, Storage, Event<T>} = 41,
 		Swap: pallet_amm::pallet::{Pallet, Call, Storage, Event<T>} = 42,
@@ -673,6 +808,12 @@ mod benches {
 		[pallet_timestamp, Timestamp]
 		[pallet_collator_selection, CollatorSelection]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
+		[participant_handler, ParticipantHandler]
+		[donation_handler, DonationHandler]
+		[marketplace, Marketplace]
+		[pallet_amm, Swap]
+		[router, Router]
+		[xcm_helper, XcmHelper]
 	);
 }
 
@@ -794,6 +935,125 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl donation_handler_runtime_api::DonationApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn donation(
+			donation_id: donation_handler::DonationId,
+		) -> Option<donation_handler::DonationRecord<AccountId, Hash, BlockNumber>> {
+			DonationHandler::get_donation(donation_id)
+		}
+
+		fn donations(
+			from: donation_handler::DonationId,
+			limit: u32,
+		) -> Vec<(donation_handler::DonationId, donation_handler::DonationRecord<AccountId, Hash, BlockNumber>)> {
+			DonationHandler::donations(from, limit)
+		}
+
+		fn ngo_stats(
+			ngo: AccountId,
+			category: participant_handler::Category,
+		) -> donation_handler::NgoCategoryStats {
+			DonationHandler::get_ngo_stats(ngo, category)
+		}
+
+		fn ngo_donor_count(ngo: AccountId) -> u32 {
+			DonationHandler::get_ngo_donor_count(ngo)
+		}
+
+		fn donor_total(donor: AccountId) -> u128 {
+			DonationHandler::get_donor_total(donor)
+		}
+
+		fn seller_stats(
+			seller: AccountId,
+			category: participant_handler::Category,
+		) -> donation_handler::SellerCategoryStats {
+			DonationHandler::get_seller_stats(seller, category)
+		}
+	}
+
+	impl participant_handler_runtime_api::ParticipantApi<Block, AccountId, Hash> for Runtime {
+		fn ngo_applications(
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, participant_handler::NgoInfo<Hash>)> {
+			ParticipantHandler::ngo_applications(start_after, limit)
+		}
+
+		fn active_ngos(
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, participant_handler::NgoInfo<Hash>)> {
+			ParticipantHandler::active_ngos(start_after, limit)
+		}
+
+		fn seller_applications(
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, participant_handler::SellerInfo<Hash>)> {
+			ParticipantHandler::seller_applications(start_after, limit)
+		}
+
+		fn active_sellers(
+			start_after: Option<AccountId>,
+			limit: u32,
+		) -> Vec<(AccountId, participant_handler::SellerInfo<Hash>)> {
+			ParticipantHandler::active_sellers(start_after, limit)
+		}
+	}
+
+	impl marketplace_runtime_api::MarketplaceApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn product(pid: Hash) -> Option<marketplace::ProductInfo<AccountId, Hash>> {
+			Marketplace::get_product_info(pid)
+		}
+
+		fn products_by_category(
+			category: participant_handler::Category,
+			start_after: Option<Hash>,
+			limit: u32,
+		) -> Vec<(Hash, marketplace::ProductInfo<AccountId, Hash>)> {
+			Marketplace::products_by_category(category, start_after, limit)
+		}
+
+		fn products_by_seller(
+			seller: AccountId,
+			start_after: Option<Hash>,
+			limit: u32,
+		) -> Vec<(Hash, marketplace::ProductInfo<AccountId, Hash>)> {
+			Marketplace::products_by_seller(&seller, start_after, limit)
+		}
+
+		fn products_by_buyer(
+			buyer: AccountId,
+			start_after: Option<Hash>,
+			limit: u32,
+		) -> Vec<(Hash, marketplace::ProductInfo<AccountId, Hash>)> {
+			Marketplace::products_by_buyer(&buyer, start_after, limit)
+		}
+
+		fn order(
+			order_id: marketplace::OrderId,
+		) -> Option<marketplace::Order<AccountId, Hash, BlockNumber>> {
+			Marketplace::get_order(order_id)
+		}
+
+		fn orders_by_buyer(
+			buyer: AccountId,
+			start_after: Option<marketplace::OrderId>,
+			limit: u32,
+		) -> Vec<(marketplace::OrderId, marketplace::Order<AccountId, Hash, BlockNumber>)> {
+			Marketplace::orders_by_buyer(&buyer, start_after, limit)
+		}
+
+		fn orders_by_seller(
+			seller: AccountId,
+			start_after: Option<marketplace::OrderId>,
+			limit: u32,
+		) -> Vec<(marketplace::OrderId, marketplace::Order<AccountId, Hash, BlockNumber>)> {
+			Marketplace::orders_by_seller(&seller, start_after, limit)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)